@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_ai::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(AiController::default())
+///     .with_component::<AiController>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct AiController {}
 
 /// Artificial intelligence navigation bounds volume [`Component`].
@@ -26,9 +26,9 @@ pub struct AiController {}
 /// # use qinetic_ai::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(AiNavBoundsVolume::default())
+///     .with_component::<AiNavBoundsVolume>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct AiNavBoundsVolume {}
@@ -39,8 +39,8 @@ pub struct AiPlugin {}
 impl Plugin for AiPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(AiController::default())
-            .with_component(AiNavBoundsVolume::default())
+            .with_component::<AiController>()
+            .with_component::<AiNavBoundsVolume>()
             .with_stage_group(AiStageGroup::default())
             .with_system(AiStage::default(), AiSystem::default());
     }
@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_animation::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(SkeletalMesh::default())
+///     .with_component::<SkeletalMesh>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct SkeletalMesh {}
 
 /// Animation [`Component`].
@@ -26,11 +26,11 @@ pub struct SkeletalMesh {}
 /// # use qinetic_animation::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(AnimationClip::default())
+///     .with_component::<AnimationClip>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct AnimationClip {}
 
 /// Animator [`Component`].
@@ -40,10 +40,7 @@ pub struct AnimationClip {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_animation::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Animator::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Animator>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Animator {}
@@ -40,9 +40,9 @@ pub struct AnimationPlugin {}
 impl Plugin for AnimationPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(SkeletalMesh::default())
-            .with_component(AnimationClip::default())
-            .with_component(Animator::default())
+            .with_component::<SkeletalMesh>()
+            .with_component::<AnimationClip>()
+            .with_component::<Animator>()
             .with_stage_group(AnimationStageGroup::default())
             .with_system(AnimationStage::default(), AnimationSystem::default());
     }
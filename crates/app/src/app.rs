@@ -1,20 +1,27 @@
 //! Application functionality.
 
+use std::any::{type_name, TypeId};
+
 use qinetic_ecs::{
     component::Component,
     event::Event,
+    reflect::{Reflect, TypeRegistry},
     resource::Resource,
+    run_criteria::RunCriteria,
     state::State,
-    system::System,
+    storage::RefMut,
+    system::{System, SystemData, SystemLabel, SystemSet},
     world::World,
 };
 use qinetic_utils::prelude::*;
 
 use crate::{
-    plugin::{Plugin, PluginGroup},
+    graph::{Ambiguity, AmbiguityDetection, ScheduleGraph},
+    plugin::{Plugin, PluginGroup, PluginRegistry},
     runner::Runner,
-    schedule::Schedule,
+    schedule::{Schedule, ScheduleError},
     stage::{Stage, StageGroup, StageLabel},
+    sub_app::{AppLabel, AppLabelId, SubApp, SubAppSlot},
 };
 
 /// A conteiner of application logic.
@@ -47,6 +54,12 @@ pub struct App {
     /// The ECS [`World`], provides access to all ECS data.
     //#[builder(setter(custom))]
     world: World,
+
+    /// [`SubApp`]s in order of addition.
+    sub_apps: Vec<(AppLabelId, SubAppSlot)>,
+
+    /// [`Ambiguity`]s, that are found on [`AppBuilder::build`].
+    ambiguities: Vec<Ambiguity>,
 }
 
 #[derive(SmartDefault)]
@@ -56,11 +69,55 @@ pub struct AppBuilder {
     schedule: Schedule,
 
     world: World,
+
+    sub_apps: Vec<(AppLabelId, SubApp)>,
+
+    ambiguity_detection: AmbiguityDetection,
+
+    /// [`TypeId`]s of already built [`Plugin`]s.
+    built_plugins: Vec<TypeId>,
+
+    /// [`Plugin`]s, that wait for their target [`Plugin`].
+    waiting_plugins: Vec<WaitingPlugin>,
+
+    /// The first error, that is returned by [`AppBuilder::build`].
+    error: Option<AppBuilderError>,
+}
+
+/// [`Plugin`], that is built after or before the target [`Plugin`].
+struct WaitingPlugin {
+    target: TypeId,
+
+    after: bool,
+
+    type_id: TypeId,
+
+    plugin: Box<dyn Plugin>,
 }
 
-#[derive(Clone, Copy, Debug)]
+/// Error of the [`AppBuilder::build`].
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
 pub enum AppBuilderError {
+    #[error("Failed to build App, it's field is unavailable.")]
     UnvaliableField,
+
+    /// The [`Schedule`] is misconfigured, like a [`System`] is added to the unknown [`Stage`].
+    #[error(transparent)]
+    Schedule(#[from] ScheduleError),
+
+    /// The [`Plugin`] is added before the target [`Plugin`], that is already built.
+    #[error("Failed to add Plugin `{plugin}` before `{target}`, it's target is already built.")]
+    PluginOrder {
+        /// The name of the [`Plugin`].
+        plugin: &'static str,
+
+        /// The name of the target [`Plugin`].
+        target: &'static str,
+    },
+
+    /// The [`Schedule`] contains [`Ambiguity`]s with [`AmbiguityDetection::Deny`].
+    #[error("Failed to build App, it's schedule contains {} ambiguities.", .0.len())]
+    Ambiguities(Vec<Ambiguity>),
 }
 
 impl App {
@@ -99,6 +156,9 @@ impl App {
 
     /// Advances the execution of [`App`] by one cycle.
     ///
+    /// [`Event`]s are cleared at the end of the cycle,
+    /// so each [`Event`] is readable only in the cycle it's sent in.
+    ///
     /// # Examples
     /// ```
     /// # use qinetic_app::prelude::*;
@@ -112,11 +172,258 @@ impl App {
     ///
     /// App::builder().with_runner(MyRunner).build().unwrap().run();
     /// ```
-    pub fn update(&mut self) { self.schedule.run(&mut self.world); }
+    ///
+    /// ```
+    /// # use std::sync::{
+    /// #     atomic::{AtomicUsize, Ordering},
+    /// #     Arc,
+    /// # };
+    /// #
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Event)]
+    /// struct Jump;
+    ///
+    /// struct JumpSystem(Arc<AtomicUsize>);
+    ///
+    /// impl System for JumpSystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) { self.0.fetch_add(1, Ordering::Relaxed); }
+    /// }
+    ///
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// let jumps = Arc::new(AtomicUsize::new(0));
+    ///
+    /// let mut app = App::builder()
+    ///     .with_stage(MyStage, SingleStage::default())
+    ///     .with_event(Jump)
+    ///     .with_system_with_run_criteria(MyStage, on_event::<Jump>(), JumpSystem(jumps.clone()))
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// app.world_mut().add_event(Jump);
+    /// app.update();
+    /// app.update();
+    ///
+    /// assert_eq!(jumps.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn update(&mut self) {
+        self.schedule.run(&mut self.world);
+
+        for (_, sub_app) in &mut self.sub_apps {
+            sub_app.update(&mut self.world);
+        }
+
+        self.world.clear_events();
+    }
+
+    /// Returns a immutable [`World`] of the [`App`].
+    #[inline]
+    pub fn world(&self) -> &World { &self.world }
+
+    /// Returns a mutable [`World`] of the [`App`].
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World { &mut self.world }
+
+    /// Returns a mutable [`SubApp`] by `label`, if it's present.
+    ///
+    /// Waits for the [`SubApp`], if it's running on a separate thread.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_ecs::world::World;
+    /// #
+    /// #[derive(AppLabel)]
+    /// struct ServerApp;
+    ///
+    /// let mut app = App::builder()
+    ///     .with_sub_app(
+    ///         ServerApp,
+    ///         SubApp::new(
+    ///             App::builder().build().unwrap(),
+    ///             |_: &mut World, _: &mut World| {},
+    ///         ),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let server_world = app.sub_app_mut(ServerApp).unwrap().world_mut();
+    /// ```
+    pub fn sub_app_mut(&mut self, label: impl AppLabel) -> Option<&mut SubApp> {
+        let label = label.as_label();
+        self.sub_apps
+            .iter_mut()
+            .find(|(l, _)| *l == label)
+            .map(|(_, sub_app)| sub_app.wait())
+    }
+
+    /// Returns a [`ScheduleGraph`] of the [`App`]'s [`Schedule`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// #
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// let mut app = App::builder()
+    ///     .with_stage(MyStage, SingleStage::default())
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// std::fs::write(
+    ///     std::env::temp_dir().join("schedule.dot"),
+    ///     app.graph().to_dot(),
+    /// )
+    /// .unwrap();
+    /// ```
+    #[inline]
+    pub fn graph(&mut self) -> ScheduleGraph { self.schedule.graph() }
+
+    /// Returns [`Ambiguity`]s of the [`Schedule`], that are found on [`AppBuilder::build`]
+    /// with [`AmbiguityDetection::Warn`].
+    #[inline]
+    pub fn ambiguities(&self) -> &[Ambiguity] { &self.ambiguities }
+
+    /// Returns a [`Schedule`] and [`World`] of the [`App`].
+    pub(crate) fn into_parts(self) -> (Schedule, World) { (self.schedule, self.world) }
 }
 
 impl AppBuilder {
-    pub fn build(&mut self) -> Result<App, AppBuilderError> { Ok(App::default()) }
+    /// Returns a [`App`].
+    ///
+    /// [`Plugin`]s, that still wait for their target [`Plugin`], are built first.
+    /// Returns [`AppBuilderError::Schedule`], if a [`System`] is added to the [`Stage`], that isn't present,
+    /// or ordering of [`SystemSet`]s contains a cycle.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// struct MySystem;
+    ///
+    /// impl System for MySystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    /// }
+    ///
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// assert!(matches!(
+    ///     App::builder().with_system(MyStage, MySystem).build(),
+    ///     Err(AppBuilderError::Schedule(
+    ///         ScheduleError::UnknownStage { .. }
+    ///     ))
+    /// ));
+    ///
+    /// #[derive(SystemLabel)]
+    /// struct Physics;
+    ///
+    /// #[derive(SystemLabel)]
+    /// struct Ai;
+    ///
+    /// assert!(matches!(
+    ///     App::builder()
+    ///         .with_stage(MyStage, SingleStage::default())
+    ///         .with_system_set(SystemSet::new(Physics).with_after(Ai))
+    ///         .with_system_set(SystemSet::new(Ai).with_after(Physics))
+    ///         .with_system_in_set(MyStage, Physics, MySystem)
+    ///         .with_system_in_set(MyStage, Ai, MySystem)
+    ///         .build(),
+    ///     Err(AppBuilderError::Schedule(ScheduleError::SetCycle { .. }))
+    /// ));
+    /// ```
+    pub fn build(&mut self) -> Result<App, AppBuilderError> {
+        for waiting in std::mem::take(&mut self.waiting_plugins) {
+            self.build_plugin(waiting.type_id, waiting.plugin);
+        }
+        if let Some(error) = self.error.take() {
+            return Err(error);
+        }
+        self.schedule.check_stages()?;
+        self.schedule.check_sets()?;
+
+        let ambiguities = match self.ambiguity_detection {
+            | AmbiguityDetection::Ignore => Vec::new(),
+            | AmbiguityDetection::Warn => {
+                let ambiguities = self.schedule.graph().ambiguities();
+                for ambiguity in &ambiguities {
+                    log::warn!("{ambiguity}");
+                }
+                ambiguities
+            },
+            | AmbiguityDetection::Deny => {
+                let ambiguities = self.schedule.graph().ambiguities();
+                if !ambiguities.is_empty() {
+                    return Err(AppBuilderError::Ambiguities(ambiguities));
+                }
+                ambiguities
+            },
+        };
+
+        Ok(App {
+            runner: self.runner.take().unwrap_or_else(|| Box::new(RunEmpty)),
+            schedule: std::mem::take(&mut self.schedule),
+            world: std::mem::take(&mut self.world),
+            sub_apps: self
+                .sub_apps
+                .drain(..)
+                .map(|(label, sub_app)| (label, SubAppSlot::new(sub_app)))
+                .collect(),
+            ambiguities,
+        })
+    }
+
+    /// [Builds](Plugin::build) the [`Plugin`] by `type_id`, if it's not built yet,
+    /// along with [`Plugin`]s, that wait for it.
+    fn build_plugin(&mut self, type_id: TypeId, mut plugin: Box<dyn Plugin>) {
+        if self.built_plugins.contains(&type_id) {
+            return;
+        }
+
+        for waiting in self.take_waiting_plugins(type_id, false) {
+            self.build_plugin(waiting.type_id, waiting.plugin);
+        }
+        self.built_plugins.push(type_id);
+        plugin.build(self);
+        for waiting in self.take_waiting_plugins(type_id, true) {
+            self.build_plugin(waiting.type_id, waiting.plugin);
+        }
+    }
+
+    fn take_waiting_plugins(&mut self, target: TypeId, after: bool) -> Vec<WaitingPlugin> {
+        let (taken, rest) = std::mem::take(&mut self.waiting_plugins)
+            .into_iter()
+            .partition(|waiting| waiting.target == target && waiting.after == after);
+        self.waiting_plugins = rest;
+        taken
+    }
+
+    /// Returns a immutable [`World`] of the [`AppBuilder`].
+    #[inline]
+    pub fn world(&self) -> &World { &self.world }
+
+    /// Returns a mutable [`World`] of the [`AppBuilder`], that [`Plugin`]s can configure.
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World { &mut self.world }
+
+    /// Returns a mutable [`TypeRegistry`] of the [`World`], adds it if it's not present.
+    fn type_registry_mut(&mut self) -> RefMut<'_, TypeRegistry> {
+        if !self.world.has_resource::<TypeRegistry>() {
+            self.world.add_resource(TypeRegistry::default());
+        }
+        self.world.get_resource_mut::<TypeRegistry>().unwrap()
+    }
 
     /// Returns a [`AppBuilder`] with [`Runner`].
     ///
@@ -141,6 +448,97 @@ impl AppBuilder {
         self
     }
 
+    /// Returns a [`AppBuilder`], that checks [`Ambiguity`]s of the [`Schedule`] on [`build`](AppBuilder::build).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// #[derive(Default, Resource)]
+    /// struct Score;
+    ///
+    /// struct ScoreSystem;
+    ///
+    /// impl System for ScoreSystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    ///
+    ///     fn access(&self, access: &mut SystemAccess) { access.write_resource::<Score>(); }
+    /// }
+    ///
+    /// struct HudSystem;
+    ///
+    /// impl System for HudSystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    ///
+    ///     fn access(&self, access: &mut SystemAccess) { access.read_resource::<Score>(); }
+    /// }
+    ///
+    /// let build = |detection| {
+    ///     App::builder()
+    ///         .with_stage(MyStage, SingleStage::default())
+    ///         .with_system(MyStage, ScoreSystem)
+    ///         .with_system(MyStage, HudSystem)
+    ///         .with_ambiguity_detection(detection)
+    ///         .build()
+    /// };
+    ///
+    /// assert_eq!(build(AmbiguityDetection::Warn).unwrap().ambiguities().len(), 1);
+    /// assert!(matches!(
+    ///     build(AmbiguityDetection::Deny),
+    ///     Err(AppBuilderError::Ambiguities(ambiguities)) if ambiguities.len() == 1
+    /// ));
+    /// ```
+    #[inline]
+    pub fn with_ambiguity_detection(&mut self, detection: AmbiguityDetection) -> &mut Self {
+        self.ambiguity_detection = detection;
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with added [`SubApp`] by `label`.
+    ///
+    /// If the [`SubApp`] by `label` was already present, it's replace.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_ecs::world::World;
+    /// #
+    /// #[derive(AppLabel)]
+    /// struct RenderApp;
+    ///
+    /// AppBuilder::default()
+    ///     .with_sub_app(
+    ///         RenderApp,
+    ///         SubApp::new(
+    ///             App::builder().build().unwrap(),
+    ///             |_: &mut World, _: &mut World| {},
+    ///         ),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_sub_app(&mut self, label: impl AppLabel, sub_app: SubApp) -> &mut Self {
+        let label = label.as_label();
+        match self.sub_apps.iter_mut().find(|(l, _)| *l == label) {
+            | Some((_, s)) => *s = sub_app,
+            | None => self.sub_apps.push((label, sub_app)),
+        }
+        self
+    }
+
     /// Returns a [`AppBuilder`] with added a single [`Stage`].
     ///
     /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
@@ -160,7 +558,7 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_stage<T: Stage>(&mut self, label: impl StageLabel, stage: T) -> &mut Self {
-        // self.stage_registry.add_stage(label, stage);
+        self.schedule.add_stage(label, stage);
         self
     }
 
@@ -179,12 +577,26 @@ impl AppBuilder {
     /// #[derive(StageLabel)]
     /// struct MyStage2;
     ///
-    /// AppBuilder::default()
+    /// #[derive(StageLabel)]
+    /// struct MyStage3;
+    ///
+    /// let mut app = AppBuilder::default()
     ///     .with_stage(MyStage1, SingleStage::default())
+    ///     .with_stage(MyStage3, SingleStage::default())
     ///     .with_stage_after(MyStage1, MyStage2, SingleStage::default())
     ///     .build()
-    ///     .unwrap()
-    ///     .run();
+    ///     .unwrap();
+    ///
+    /// let graph = app.graph();
+    /// let labels = graph.stages().iter().map(|stage| stage.label());
+    /// assert_eq!(
+    ///     labels.collect::<Vec<_>>(),
+    ///     vec![
+    ///         MyStage1.as_label(),
+    ///         MyStage2.as_label(),
+    ///         MyStage3.as_label()
+    ///     ]
+    /// );
     /// ```
     #[inline]
     pub fn with_stage_after<T: Stage>(
@@ -193,7 +605,7 @@ impl AppBuilder {
         label: impl StageLabel,
         stage: T,
     ) -> &mut Self {
-        // self.stage_registry.add_stage_after(target, label, stage);
+        self.schedule.add_stage_after(target, label, stage);
         self
     }
 
@@ -226,7 +638,7 @@ impl AppBuilder {
         label: impl StageLabel,
         stage: T,
     ) -> &mut Self {
-        // self.stage_registry.add_stage_before(target, label, stage);
+        self.schedule.add_stage_before(target, label, stage);
         self
     }
 
@@ -262,13 +674,13 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_stage_group<T: StageGroup>(&mut self, group: T) -> &mut Self {
-        // self.stage_registry.add_stage_group(group);
+        self.schedule.add_stage_group(group);
         self
     }
 
-    /// Returns a [`AppBuilder`] with added [`Plugin`] at the end.
+    /// Returns a [`AppBuilder`] with added [`Plugin`], that is [built](Plugin::build) immediately.
     ///
-    /// If the [`Plugin`] was already present, it's removed from it's previous place and add at the end.
+    /// If the [`Plugin`] was already present, it's ignored.
     ///
     /// # Examples
     /// ```
@@ -286,13 +698,13 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_plugin<T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        // self.plugin_registry.add_plugin(plugin);
+        self.build_plugin(TypeId::of::<T>(), Box::new(plugin));
         self
     }
 
     /// Returns a [`AppBuilder`] with added [`Plugin`] after `Target` [`Plugin`].
     ///
-    /// If the [`Plugin`] was already present, it's removed from it's previous place and add at the end.
+    /// If the `Target` isn't built yet, the [`Plugin`] waits for it, and it's built on [`AppBuilder::build`] at the latest.
     ///
     /// # Examples
     /// ```
@@ -322,13 +734,25 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_plugin_after<Target: Plugin, T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        // self.plugin_registry.add_plugin_after::<Target, _>(plugin);
+        let (target, type_id) = (TypeId::of::<Target>(), TypeId::of::<T>());
+        match self.built_plugins.contains(&target) {
+            | true => self.build_plugin(type_id, Box::new(plugin)),
+            | false => {
+                self.waiting_plugins.push(WaitingPlugin {
+                    target,
+                    after: true,
+                    type_id,
+                    plugin: Box::new(plugin),
+                })
+            },
+        }
         self
     }
 
     /// Returns a [`AppBuilder`] with added [`Plugin`] before `Target` [`Plugin`].
     ///
-    /// If the [`Plugin`] was already present, it's removed from it's previous place and add at the end.
+    /// The [`Plugin`] waits for the `Target`, and it's built on [`AppBuilder::build`] at the latest.
+    /// If the `Target` is already built, [`AppBuilder::build`] returns [`AppBuilderError::PluginOrder`].
     ///
     /// # Examples
     /// ```
@@ -351,20 +775,36 @@ impl AppBuilder {
     /// }
     ///
     /// AppBuilder::default()
-    ///     .with_plugin(MyPlugin1)
-    ///     .with_plugin_before::<MyPlugin1, _>(MyPlugin2)
+    ///     .with_plugin_before::<MyPlugin2, _>(MyPlugin1)
+    ///     .with_plugin(MyPlugin2)
     ///     .build()
     ///     .unwrap();
     /// ```
     #[inline]
     pub fn with_plugin_before<Target: Plugin, T: Plugin>(&mut self, plugin: T) -> &mut Self {
-        // self.plugin_registry.add_plugin_before::<Target, _>(plugin);
+        let (target, type_id) = (TypeId::of::<Target>(), TypeId::of::<T>());
+        match self.built_plugins.contains(&target) {
+            | true => {
+                self.error.get_or_insert(AppBuilderError::PluginOrder {
+                    plugin: type_name::<T>(),
+                    target: type_name::<Target>(),
+                });
+            },
+            | false => {
+                self.waiting_plugins.push(WaitingPlugin {
+                    target,
+                    after: false,
+                    type_id,
+                    plugin: Box::new(plugin),
+                })
+            },
+        }
         self
     }
 
-    /// Returns a [`PluginRegistry`] with added [`Plugin`]s of the [`PluginGroup`] at the end.
+    /// Returns a [`AppBuilder`] with added [`Plugin`]s of the [`PluginGroup`], that are built in order of the group.
     ///
-    /// If the [`Plugin`] of the [`PluginGroup`] was already present, it's removed from its previous place and add at the end.
+    /// If the [`Plugin`] of the [`PluginGroup`] was already present, it's ignored.
     ///
     /// # Examples
     /// ```
@@ -401,12 +841,17 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_plugin_group<T: PluginGroup>(&mut self, group: T) -> &mut Self {
-        // self.plugin_registry.add_plugin_group(group);
+        let mut registry = PluginRegistry::default();
+        registry.add_plugin_group(group);
+        for (type_id, plugin) in registry.take_plugins() {
+            self.build_plugin(type_id, plugin);
+        }
         self
     }
 
-    /// Returns a [`AppBuilder`] with added [`Component`].
+    /// Returns a [`AppBuilder`] with registered [`Component`] by `T`.
     ///
+    /// The [`Component`] is registered in [`TypeRegistry`] for reflection.
     /// If the [`Component`], was already present, it's replace.
     ///
     /// # Examples
@@ -414,25 +859,32 @@ impl AppBuilder {
     /// # use qinetic_app::prelude::*;
     /// use qinetic_ecs::prelude::*;
     ///
-    /// #[derive(Default, Component)]
+    /// #[derive(Default, Component, Reflect)]
     /// struct MyComponent {
     ///     // Something to do
     /// }
     ///
-    /// AppBuilder::default()
-    ///     .with_component(MyComponent::default())
+    /// let app = AppBuilder::default()
+    ///     .with_component::<MyComponent>()
     ///     .build()
     ///     .unwrap();
+    /// #
+    /// # assert!(app
+    /// #     .world()
+    /// #     .get_resource::<TypeRegistry>()
+    /// #     .unwrap()
+    /// #     .contains::<MyComponent>());
     /// ```
     #[inline]
-    pub fn with_component<T: Component>(&mut self, component: T) -> &mut Self {
-        // self.world_builder.with_component(component);
+    pub fn with_component<T: Component + Reflect + Default>(&mut self) -> &mut Self {
+        self.world.register_component::<T>();
+        self.type_registry_mut().register_component::<T>();
         self
     }
 
-    /// Returns a [`AppBuilder`] with added [`Event`].
+    /// Returns a [`AppBuilder`] with registered [`Event`].
     ///
-    /// If the [`Event`], was already present, it's replace.
+    /// The `event` isn't sent, it's type is registered only.
     ///
     /// # Examples
     /// ```
@@ -450,8 +902,8 @@ impl AppBuilder {
     ///     .unwrap();
     /// ```
     #[inline]
-    pub fn with_event<T: Event>(&mut self, event: T) -> &mut Self {
-        // self.world_builder.with_event(event);
+    pub fn with_event<T: Event>(&mut self, _event: T) -> &mut Self {
+        self.world.register_event::<T>();
         self
     }
 
@@ -476,7 +928,61 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_resource<T: Resource>(&mut self, resource: T) -> &mut Self {
-        // self.world_builder.with_resource(resource);
+        self.world.add_resource(resource);
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with added [`Resource`], that is registered in [`TypeRegistry`] for reflection.
+    ///
+    /// If the [`Resource`], was already present, it's replace.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// #[derive(Default, Resource, Reflect)]
+    /// struct MyResource {
+    ///     value: f32,
+    /// }
+    ///
+    /// AppBuilder::default()
+    ///     .with_reflect_resource(MyResource::default())
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_reflect_resource<T: Resource + Reflect + Default>(
+        &mut self,
+        resource: T,
+    ) -> &mut Self {
+        self.world.add_resource(resource);
+        self.type_registry_mut().register_resource::<T>();
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with registered [`Reflect`] type in [`TypeRegistry`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// #[derive(Default, Reflect)]
+    /// enum Difficulty {
+    ///     #[default]
+    ///     Normal,
+    ///     Hard,
+    /// }
+    ///
+    /// AppBuilder::default()
+    ///     .with_reflect::<Difficulty>()
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_reflect<T: Reflect>(&mut self) -> &mut Self {
+        self.type_registry_mut().register::<T>();
         self
     }
 
@@ -502,7 +1008,7 @@ impl AppBuilder {
     /// ```
     #[inline]
     pub fn with_state<T: State>(&mut self, state: T) -> &mut Self {
-        // self.world.add_state(state);
+        self.world.add_state(state);
         self
     }
 
@@ -529,13 +1035,144 @@ impl AppBuilder {
     /// struct MyStage;
     ///
     /// AppBuilder::default()
+    ///     .with_stage(MyStage, SingleStage::default())
     ///     .with_system(MyStage, MySystem)
     ///     .build()
     ///     .unwrap();
     /// ```
     #[inline]
-    pub fn with_system<T: System>(&mut self, stage: impl StageLabel, system: T) -> &mut Self {
-        // self.world_builder.with_system(system);
+    pub fn with_system<T: System>(&mut self, stage: impl StageLabel, system: T) -> &mut Self
+    where T::Data: SystemData {
+        self.schedule
+            .push_system(stage.as_label(), Box::new(system), None, None);
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with add a single [`System`], that runs only if [`RunCriteria`] passes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// #[derive(Default, Resource)]
+    /// struct DebugOverlay;
+    ///
+    /// struct DebugOverlaySystem;
+    ///
+    /// impl System for DebugOverlaySystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    /// }
+    ///
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// AppBuilder::default()
+    ///     .with_stage(MyStage, SingleStage::default())
+    ///     .with_system_with_run_criteria(
+    ///         MyStage,
+    ///         resource_exists::<DebugOverlay>(),
+    ///         DebugOverlaySystem,
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_system_with_run_criteria<T: System>(
+        &mut self,
+        stage: impl StageLabel,
+        run_criteria: impl RunCriteria,
+        system: T,
+    ) -> &mut Self
+    where
+        T::Data: SystemData,
+    {
+        self.schedule.push_system(
+            stage.as_label(),
+            Box::new(system),
+            None,
+            Some(Box::new(run_criteria)),
+        );
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with added [`SystemSet`].
+    ///
+    /// If the [`SystemSet`] by the same label, was already present, it's replace.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::time::Duration;
+    /// #
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// #[derive(SystemLabel)]
+    /// struct Ai;
+    ///
+    /// AppBuilder::default()
+    ///     .with_system_set(SystemSet::new(Ai).with_run_criteria(on_timer(Duration::from_millis(100))))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_system_set(&mut self, set: SystemSet) -> &mut Self {
+        self.schedule.add_system_set(set);
+        self
+    }
+
+    /// Returns a [`AppBuilder`] with add a single [`System`] as a member of the [`SystemSet`] by `set`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::time::Duration;
+    /// #
+    /// # use qinetic_app::prelude::*;
+    /// use qinetic_ecs::prelude::*;
+    ///
+    /// #[derive(SystemLabel)]
+    /// struct Ai;
+    ///
+    /// struct AiSystem;
+    ///
+    /// impl System for AiSystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    /// }
+    ///
+    /// #[derive(StageLabel)]
+    /// struct MyStage;
+    ///
+    /// AppBuilder::default()
+    ///     .with_stage(MyStage, SingleStage::default())
+    ///     .with_system_set(SystemSet::new(Ai).with_run_criteria(on_timer(Duration::from_millis(100))))
+    ///     .with_system_in_set(MyStage, Ai, AiSystem)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_system_in_set<T: System>(
+        &mut self,
+        stage: impl StageLabel,
+        set: impl SystemLabel,
+        system: T,
+    ) -> &mut Self
+    where
+        T::Data: SystemData,
+    {
+        self.schedule.push_system(
+            stage.as_label(),
+            Box::new(system),
+            Some(set.as_label()),
+            None,
+        );
         self
     }
 }
@@ -0,0 +1,404 @@
+//! Application schedule graph functionality.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use qinetic_ecs::{
+    access::{AccessId, SystemAccess},
+    system::{SystemLabel, SystemLabelId},
+};
+use qinetic_utils::prelude::*;
+
+use crate::stage::{StageLabel, StageLabelId};
+
+/// Snapshot of the resolved [`Schedule`](crate::schedule::Schedule).
+///
+/// Contains [`Stage`](crate::stage::Stage)s in execution order, their [`System`](qinetic_ecs::system::System)s in execution order,
+/// ordering edges between [`System`](qinetic_ecs::system::System)s and [`SystemAccess`] of each one.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(StageLabel)]
+/// struct MyStage;
+///
+/// #[derive(Default, Resource)]
+/// struct Score;
+///
+/// struct ScoreSystem;
+///
+/// impl System for ScoreSystem {
+///     type Data = ();
+///
+///     fn run(&mut self, data: Self::Data) {
+///         // Something to do
+///     }
+///
+///     fn access(&self, access: &mut SystemAccess) { access.write_resource::<Score>(); }
+/// }
+///
+/// struct HudSystem;
+///
+/// impl System for HudSystem {
+///     type Data = ();
+///
+///     fn run(&mut self, data: Self::Data) {
+///         // Something to do
+///     }
+///
+///     fn access(&self, access: &mut SystemAccess) { access.read_resource::<Score>(); }
+/// }
+///
+/// let mut schedule = Schedule::default();
+/// schedule
+///     .add_stage(MyStage, SingleStage::default())
+///     .add_system(MyStage, ScoreSystem)
+///     .unwrap()
+///     .add_system(MyStage, HudSystem)
+///     .unwrap();
+///
+/// let graph = schedule.graph();
+///
+/// assert!(graph.to_dot().starts_with("digraph Schedule {"));
+/// assert!(graph
+///     .to_json()
+///     .starts_with("{\"stages\":[{\"label\":\"MyStage\""));
+/// assert_eq!(graph.ambiguities().len(), 1);
+/// ```
+#[derive(SmartDefault, Clone, Debug, Getters)]
+#[getset(get = "pub")]
+pub struct ScheduleGraph {
+    /// [`StageNode`]s in execution order.
+    stages: Vec<StageNode>,
+}
+
+/// [`Stage`](crate::stage::Stage) of the [`ScheduleGraph`].
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct StageNode {
+    /// The label of the [`Stage`](crate::stage::Stage).
+    #[getset(get_copy = "pub")]
+    label: StageLabelId,
+
+    /// [`SystemNode`]s in execution order.
+    #[getset(get = "pub")]
+    systems: Vec<SystemNode>,
+
+    /// Ordering edges between [`SystemNode`]s by their indices, the first runs before the second.
+    #[getset(get = "pub")]
+    edges: Vec<(usize, usize)>,
+}
+
+/// [`System`](qinetic_ecs::system::System) of the [`ScheduleGraph`].
+#[derive(Clone, Debug, Getters, CopyGetters)]
+pub struct SystemNode {
+    /// The name of the [`System`](qinetic_ecs::system::System).
+    #[getset(get_copy = "pub")]
+    name: &'static str,
+
+    /// The label of [`SystemSet`](qinetic_ecs::system::SystemSet), that [`System`](qinetic_ecs::system::System) belongs to.
+    #[getset(get_copy = "pub")]
+    set: Option<SystemLabelId>,
+
+    /// The [`SystemAccess`] of the [`System`](qinetic_ecs::system::System).
+    #[getset(get = "pub")]
+    access: SystemAccess,
+}
+
+/// Pair of [`System`](qinetic_ecs::system::System)s with conflicting access, but without explicit order.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+pub struct Ambiguity {
+    /// The label of the [`Stage`](crate::stage::Stage) both run in.
+    #[getset(get_copy = "pub")]
+    stage: StageLabelId,
+
+    /// The name of the first [`System`](qinetic_ecs::system::System).
+    #[getset(get_copy = "pub")]
+    first: &'static str,
+
+    /// The name of the second [`System`](qinetic_ecs::system::System).
+    #[getset(get_copy = "pub")]
+    second: &'static str,
+
+    /// The types, that both access and at least one writes.
+    #[getset(get = "pub")]
+    conflicts: Vec<AccessId>,
+}
+
+/// Defines what to do with [`Ambiguity`]s on [`AppBuilder::build`](crate::app::AppBuilder::build).
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AmbiguityDetection {
+    /// Don't check [`Ambiguity`]s.
+    #[default]
+    Ignore,
+
+    /// Log a warning per [`Ambiguity`] on build and keep them for [`App::ambiguities`](crate::app::App::ambiguities).
+    Warn,
+
+    /// Fail with [`AppBuilderError::Ambiguities`](crate::app::AppBuilderError::Ambiguities), if there is any [`Ambiguity`].
+    Deny,
+}
+
+impl StageNode {
+    pub(crate) fn new(
+        label: StageLabelId,
+        systems: Vec<SystemNode>,
+        edges: Vec<(usize, usize)>,
+    ) -> Self {
+        Self {
+            label,
+            systems,
+            edges,
+        }
+    }
+
+    /// Returns `true`, if [`SystemNode`] by index `from` runs before `to` by ordering edges.
+    pub fn is_ordered(&self, from: usize, to: usize) -> bool {
+        let mut reached = vec![false; self.systems.len()];
+        let mut pending = vec![from];
+
+        while let Some(i) = pending.pop() {
+            for &(_, next) in self.edges.iter().filter(|(prev, _)| *prev == i) {
+                if next == to {
+                    return true;
+                }
+                if !reached[next] {
+                    reached[next] = true;
+                    pending.push(next);
+                }
+            }
+        }
+
+        false
+    }
+}
+
+impl SystemNode {
+    pub(crate) fn new(
+        name: &'static str,
+        set: Option<SystemLabelId>,
+        access: SystemAccess,
+    ) -> Self {
+        Self { name, set, access }
+    }
+}
+
+impl ScheduleGraph {
+    pub(crate) fn new(stages: Vec<StageNode>) -> Self { Self { stages } }
+
+    /// Returns pairs of [`System`](qinetic_ecs::system::System)s in the same [`Stage`](crate::stage::Stage),
+    /// that have conflicting access, but no explicit order.
+    pub fn ambiguities(&self) -> Vec<Ambiguity> {
+        let mut ambiguities = Vec::new();
+
+        for stage in &self.stages {
+            for (i, first) in stage.systems.iter().enumerate() {
+                for (j, second) in stage.systems.iter().enumerate().skip(i + 1) {
+                    let conflicts = first.access.conflicts(&second.access);
+                    if conflicts.is_empty() || stage.is_ordered(i, j) || stage.is_ordered(j, i) {
+                        continue;
+                    }
+
+                    ambiguities.push(Ambiguity {
+                        stage: stage.label,
+                        first: first.name,
+                        second: second.name,
+                        conflicts,
+                    });
+                }
+            }
+        }
+
+        ambiguities
+    }
+
+    /// Returns the [`ScheduleGraph`] in the Graphviz `DOT` format.
+    ///
+    /// [`Stage`](crate::stage::Stage)s are clusters, [`Resource`](qinetic_ecs::resource::Resource)s and
+    /// [`Component`](qinetic_ecs::component::Component)s are separate nodes, reads are blue and writes are red edges.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph Schedule {\n    compound=true;\n");
+        let mut types = Vec::<(&str, AccessId)>::new();
+
+        for (s, stage) in self.stages.iter().enumerate() {
+            let _ = writeln!(
+                dot,
+                "    subgraph \"cluster_{s}\" {{\n        label=\"{}\";\n        \"stage_{s}\" [label=\"{}\", shape=box, style=filled];",
+                escape(stage.label.as_str()),
+                escape(stage.label.as_str()),
+            );
+
+            for (i, system) in stage.systems.iter().enumerate() {
+                let label = match system.set {
+                    | Some(set) => format!("{}\\n[{}]", escape(system.name), escape(set.as_str())),
+                    | None => escape(system.name),
+                };
+                let _ = writeln!(dot, "        \"system_{s}_{i}\" [label=\"{label}\"];");
+            }
+            dot.push_str("    }\n");
+
+            if s > 0 {
+                let _ = writeln!(
+                    dot,
+                    "    \"stage_{}\" -> \"stage_{s}\" [style=bold];",
+                    s - 1
+                );
+            }
+
+            for (i, system) in stage.systems.iter().enumerate() {
+                if !stage.edges.iter().any(|(_, to)| *to == i) {
+                    let _ = writeln!(
+                        dot,
+                        "    \"stage_{s}\" -> \"system_{s}_{i}\" [style=dashed];"
+                    );
+                }
+
+                let access = &system.access;
+                for (kind, ids, write) in [
+                    ("resource", access.resource_reads(), false),
+                    ("resource", access.resource_writes(), true),
+                    ("component", access.component_reads(), false),
+                    ("component", access.component_writes(), true),
+                ] {
+                    for id in ids {
+                        let t = match types.iter().position(|t| *t == (kind, *id)) {
+                            | Some(t) => t,
+                            | None => {
+                                types.push((kind, *id));
+                                types.len() - 1
+                            },
+                        };
+
+                        let _ = if write {
+                            writeln!(dot, "    \"system_{s}_{i}\" -> \"type_{t}\" [color=red];")
+                        } else {
+                            writeln!(dot, "    \"type_{t}\" -> \"system_{s}_{i}\" [color=blue];")
+                        };
+                    }
+                }
+            }
+
+            for (from, to) in &stage.edges {
+                let _ = writeln!(dot, "    \"system_{s}_{from}\" -> \"system_{s}_{to}\";");
+            }
+        }
+
+        for (t, (kind, id)) in types.iter().enumerate() {
+            let shape = if *kind == "resource" {
+                "cylinder"
+            } else {
+                "component"
+            };
+            let _ = writeln!(
+                dot,
+                "    \"type_{t}\" [label=\"{}\", shape={shape}];",
+                escape(id.name())
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Returns the [`ScheduleGraph`] in the `JSON` format.
+    pub fn to_json(&self) -> String {
+        fn names(ids: &[AccessId]) -> String {
+            let names = ids
+                .iter()
+                .map(|id| format!("\"{}\"", escape(id.name())))
+                .collect::<Vec<_>>();
+            format!("[{}]", names.join(","))
+        }
+
+        let stages = self
+            .stages
+            .iter()
+            .map(|stage| {
+                let systems = stage
+                    .systems
+                    .iter()
+                    .map(|system| {
+                        let set = system
+                            .set
+                            .map_or("null".into(), |set| format!("\"{}\"", escape(set.as_str())));
+                        let access = &system.access;
+                        format!(
+                            "{{\"name\":\"{}\",\"set\":{set},\"reads\":{{\"resources\":{},\"components\":{}}},\"writes\":{{\"resources\":{},\"components\":{}}}}}",
+                            escape(system.name),
+                            names(access.resource_reads()),
+                            names(access.component_reads()),
+                            names(access.resource_writes()),
+                            names(access.component_writes()),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let edges = stage
+                    .edges
+                    .iter()
+                    .map(|(from, to)| format!("[{from},{to}]"))
+                    .collect::<Vec<_>>();
+                format!(
+                    "{{\"label\":\"{}\",\"systems\":[{}],\"edges\":[{}]}}",
+                    escape(stage.label.as_str()),
+                    systems.join(","),
+                    edges.join(","),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let ambiguities = self
+            .ambiguities()
+            .iter()
+            .map(|ambiguity| {
+                format!(
+                    "{{\"stage\":\"{}\",\"first\":\"{}\",\"second\":\"{}\",\"conflicts\":{}}}",
+                    escape(ambiguity.stage.as_str()),
+                    escape(ambiguity.first),
+                    escape(ambiguity.second),
+                    names(&ambiguity.conflicts),
+                )
+            })
+            .collect::<Vec<_>>();
+
+        format!(
+            "{{\"stages\":[{}],\"ambiguities\":[{}]}}",
+            stages.join(","),
+            ambiguities.join(",")
+        )
+    }
+}
+
+impl Display for Ambiguity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let conflicts = self
+            .conflicts
+            .iter()
+            .map(|id| id.name())
+            .collect::<Vec<_>>();
+        write!(
+            f,
+            "Systems `{}` and `{}` in stage `{}` have conflicting access to [{}], but no explicit order.",
+            self.first,
+            self.second,
+            self.stage.as_str(),
+            conflicts.join(", ")
+        )
+    }
+}
+
+/// Escapes `str` for quoted strings of `DOT` and `JSON`.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            | '"' => escaped.push_str("\\\""),
+            | '\\' => escaped.push_str("\\\\"),
+            | '\n' => escaped.push_str("\\n"),
+            | c if c.is_control() => {
+                let _ = write!(escaped, "\\u{:04x}", c as u32);
+            },
+            | c => escaped.push(c),
+        }
+    }
+    escaped
+}
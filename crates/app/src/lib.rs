@@ -8,10 +8,12 @@
 )]
 
 pub mod app;
+pub mod graph;
 pub mod plugin;
 pub mod runner;
 pub mod schedule;
 pub mod stage;
+pub mod sub_app;
 
 pub mod prelude {
     //! Main application functionality.
@@ -20,5 +22,5 @@ pub mod prelude {
     pub use qinetic_app_macros::*;
 
     #[doc(hidden)]
-    pub use crate::{app::*, plugin::*, runner::*, schedule::*, stage::*};
+    pub use crate::{app::*, graph::*, plugin::*, runner::*, schedule::*, stage::*, sub_app::*};
 }
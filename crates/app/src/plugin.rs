@@ -93,7 +93,8 @@ pub trait PluginGroup {
 ///     }
 /// }
 ///
-/// let mut plugin_registry = PluginRegistry::default()
+/// let mut plugin_registry = PluginRegistry::default();
+/// plugin_registry
 ///     .add_plugin(MyPlugin1)
 ///     .add_plugin_after::<MyPlugin1, _>(MyPlugin2)
 ///     .add_plugin_before::<MyPlugin2, _>(MyPlugin3);
@@ -123,13 +124,15 @@ impl PluginRegistry {
     /// struct MyPlugin;
     ///
     /// impl Plugin for MyPlugin {
-    ///     fn build(&mut self, app_builder: &mut AppBuilder) {/* Something to do */}
+    ///     fn build(&mut self, app_builder: &mut AppBuilder) { // Something to do
+    ///     }
     /// }
     ///
-    /// let mut plugin_registry = PluginRegistry::default().add_plugin(MyPlugin);
+    /// let mut plugin_registry = PluginRegistry::default();
+    /// plugin_registry.add_plugin(MyPlugin);
     ///
     /// # assert!(plugin_registry.has_plugin::<MyPlugin>());
-    // ```
+    /// ```
     pub fn add_plugin<T: Plugin>(&mut self, plugin: T) -> &mut Self {
         let i = self.order.len();
         self.order.push(TypeId::of::<T>());
@@ -159,7 +162,8 @@ impl PluginRegistry {
     ///     }
     /// }
     ///
-    /// let mut plugin_registry = PluginRegistry::default()
+    /// let mut plugin_registry = PluginRegistry::default();
+    /// plugin_registry
     ///     .add_plugin(MyPlugin1)
     ///     .add_plugin_after::<MyPlugin1, _>(MyPlugin2);
     ///
@@ -204,7 +208,8 @@ impl PluginRegistry {
     ///     }
     /// }
     ///
-    /// let mut plugin_registry = PluginRegistry::default()
+    /// let mut plugin_registry = PluginRegistry::default();
+    /// plugin_registry
     ///     .add_plugin(MyPlugin1)
     ///     .add_plugin_before::<MyPlugin1, _>(MyPlugin2);
     ///
@@ -247,7 +252,8 @@ impl PluginRegistry {
     ///     }
     /// }
     ///
-    /// let mut plugin_registry = PluginRegistry::default()
+    /// let mut plugin_registry = PluginRegistry::default();
+    /// plugin_registry
     ///     .add_plugin(MyPlugin1)
     ///     .add_plugin_before::<MyPlugin1, _>(MyPlugin2);
     ///
@@ -284,7 +290,8 @@ impl PluginRegistry {
     ///         registry.add_plugin(MyPlugin1).add_plugin(MyPlugin2);
     ///     }
     /// }
-    /// let mut plugin_registry = PluginRegistry::default().add_plugin_group(MyPluginGroup);
+    /// let mut plugin_registry = PluginRegistry::default();
+    /// plugin_registry.add_plugin_group(MyPluginGroup);
     ///
     /// # assert!(plugin_registry.has_plugin::<MyPlugin1>());
     /// # assert!(plugin_registry.has_plugin::<MyPlugin2>());
@@ -329,6 +336,15 @@ impl PluginRegistry {
         }
     }
 
+    /// Removes all [`Plugin`]s and returns them in order with their [`TypeId`]s.
+    pub(crate) fn take_plugins(&mut self) -> Vec<(TypeId, Box<dyn Plugin>)> {
+        let mut plugins = std::mem::take(&mut self.plugins);
+        self.order
+            .drain(..)
+            .filter_map(|type_id| plugins.remove(&type_id).map(|plugin| (type_id, plugin)))
+            .collect()
+    }
+
     /// Finds the index of a `Target` [`Plugin`].
     fn index_of<Target: Plugin>(&mut self) -> Option<usize> {
         self.order.iter().position(|&p| p == TypeId::of::<Target>())
@@ -1,30 +1,461 @@
 //! Application stage schedule functionality.
 
-use std::{any::TypeId, collections::HashMap};
+use std::collections::HashMap;
 
-use qinetic_ecs::{system::System, world::World};
+use qinetic_ecs::{
+    run_criteria::RunCriteria,
+    system::{DynSystem, System, SystemData, SystemLabel, SystemLabelId, SystemSet},
+    world::World,
+};
 use qinetic_utils::prelude::*;
 
 use crate::{
-    prelude::StageLabel,
-    stage::{Stage, StageGroup},
+    graph::{ScheduleGraph, StageNode, SystemNode},
+    stage::{Stage, StageGroup, StageLabel, StageLabelId, StageRegistry},
 };
 
-#[derive(SmartDefault, Clone)]
+/// Error of the [`Schedule`] configuration.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum ScheduleError {
+    /// The [`System`] is added to the [`Stage`], that was never added.
+    #[error("Failed to add System `{system}`, it's stage {stage:?} does not present.")]
+    UnknownStage {
+        /// The label of the [`Stage`].
+        stage: StageLabelId,
+
+        /// The name of the [`System`].
+        system: &'static str,
+    },
+
+    /// Ordering of [`SystemSet`]s of the [`Stage`] contains a cycle.
+    #[error(
+        "Failed to order SystemSet {set:?} in stage {stage:?}, it's ordering contains a cycle."
+    )]
+    SetCycle {
+        /// The label of the [`Stage`].
+        stage: StageLabelId,
+
+        /// The label of the [`SystemSet`] in the cycle.
+        set: SystemLabelId,
+    },
+}
+
+/// Container of [`Stage`]s and their [`System`]s in a linear order.
+///
+/// [`System`]s of the [`Stage`] run after the [`Stage`] itself, ordered by their [`SystemSet`]s.
+/// A [`System`] can be added only to a present [`Stage`], see [`ScheduleError::UnknownStage`].
+///
+/// # Examples
+/// ```
+/// # use std::sync::{
+/// #     atomic::{AtomicUsize, Ordering},
+/// #     Arc,
+/// # };
+/// #
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(StageLabel)]
+/// struct MyStage;
+///
+/// #[derive(StageLabel)]
+/// struct UnknownStage;
+///
+/// #[derive(SystemLabel)]
+/// struct Debug;
+///
+/// struct MySystem(Arc<AtomicUsize>);
+///
+/// impl System for MySystem {
+///     type Data = ();
+///
+///     fn run(&mut self, data: Self::Data) { self.0.fetch_add(1, Ordering::Relaxed); }
+/// }
+///
+/// let runs = Arc::new(AtomicUsize::new(0));
+///
+/// let mut schedule = Schedule::default();
+/// schedule
+///     .add_stage(MyStage, SingleStage::default())
+///     .add_system_set(SystemSet::new(Debug).with_run_criteria(|_: &World| false))
+///     .add_system_in_set(MyStage, Debug, MySystem(runs.clone()))
+///     .unwrap()
+///     .add_system(MyStage, MySystem(runs.clone()))
+///     .unwrap();
+///
+/// schedule.run(&mut World::default());
+///
+/// assert_eq!(runs.load(Ordering::Relaxed), 1);
+/// assert!(schedule.add_system(UnknownStage, MySystem(runs)).is_err());
+/// ```
+#[derive(SmartDefault)]
 pub struct Schedule {
-    stages: HashMap<TypeId, Box<dyn Stage>>,
-    order: Vec<TypeId>,
+    /// [`Stage`]s and their requested places.
+    stages: StageRegistry,
+
+    /// Resolved linear order of [`Stage`]s.
+    order: Vec<StageLabelId>,
+
+    /// [`System`]s by [`StageLabelId`] of the [`Stage`] they run in.
+    systems: HashMap<StageLabelId, Vec<ScheduledSystem>>,
+
+    /// [`SystemSet`]s by [`SystemLabelId`].
+    sets: HashMap<SystemLabelId, SystemSet>,
+
+    /// `true`, if [`Stage`]s and [`System`]s should be reordered before the next run.
+    dirty: bool,
+}
+
+/// [`System`] with its [`SystemSet`] and own [`RunCriteria`].
+struct ScheduledSystem {
+    system: Box<dyn DynSystem>,
+
+    set: Option<SystemLabelId>,
+
+    run_criteria: Option<Box<dyn RunCriteria>>,
 }
 
 impl Schedule {
+    /// Adds a [`Stage`] by `label` at the end.
+    ///
+    /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
+    #[inline]
+    pub fn add_stage<T: Stage>(&mut self, label: impl StageLabel, stage: T) -> &mut Self {
+        self.stages.add_stage(label, stage);
+        self.dirty = true;
+        self
+    }
+
+    /// Adds a [`Stage`] by `label` after `target` [`Stage`].
+    ///
+    /// The `target` can be added later, see [`StageRegistry`].
+    #[inline]
+    pub fn add_stage_after<T: Stage>(
+        &mut self,
+        target: impl StageLabel,
+        label: impl StageLabel,
+        stage: T,
+    ) -> &mut Self {
+        self.stages.add_stage_after(target, label, stage);
+        self.dirty = true;
+        self
+    }
+
+    /// Adds a [`Stage`] by `label` before `target` [`Stage`].
+    ///
+    /// The `target` can be added later, see [`StageRegistry`].
+    #[inline]
+    pub fn add_stage_before<T: Stage>(
+        &mut self,
+        target: impl StageLabel,
+        label: impl StageLabel,
+        stage: T,
+    ) -> &mut Self {
+        self.stages.add_stage_before(target, label, stage);
+        self.dirty = true;
+        self
+    }
+
+    /// Adds [`Stage`]s of the [`StageGroup`].
+    #[inline]
+    pub fn add_stage_group<T: StageGroup>(&mut self, group: T) -> &mut Self {
+        self.stages.add_stage_group(group);
+        self.dirty = true;
+        self
+    }
+
+    /// Adds a [`System`] to the [`Stage`] by `stage`.
+    ///
+    /// Returns [`ScheduleError::UnknownStage`], if the [`Stage`] isn't present.
+    #[inline]
+    pub fn add_system<T: System>(
+        &mut self,
+        stage: impl StageLabel,
+        system: T,
+    ) -> Result<&mut Self, ScheduleError>
+    where
+        T::Data: SystemData,
+    {
+        self.check_stage(stage.as_label(), Box::new(system), None, None)
+    }
+
+    /// Adds a [`System`] to the [`Stage`] by `stage`, that runs only if `run_criteria` passes.
+    ///
+    /// Returns [`ScheduleError::UnknownStage`], if the [`Stage`] isn't present.
+    #[inline]
+    pub fn add_system_with_run_criteria<T: System>(
+        &mut self,
+        stage: impl StageLabel,
+        run_criteria: impl RunCriteria,
+        system: T,
+    ) -> Result<&mut Self, ScheduleError>
+    where
+        T::Data: SystemData,
+    {
+        self.check_stage(
+            stage.as_label(),
+            Box::new(system),
+            None,
+            Some(Box::new(run_criteria)),
+        )
+    }
+
+    /// Adds a [`System`] to the [`Stage`] by `stage` as a member of the [`SystemSet`] by `set`.
+    ///
+    /// The [`SystemSet`] can be added before or after its members.
+    /// Returns [`ScheduleError::UnknownStage`], if the [`Stage`] isn't present.
+    #[inline]
+    pub fn add_system_in_set<T: System>(
+        &mut self,
+        stage: impl StageLabel,
+        set: impl SystemLabel,
+        system: T,
+    ) -> Result<&mut Self, ScheduleError>
+    where
+        T::Data: SystemData,
+    {
+        self.check_stage(
+            stage.as_label(),
+            Box::new(system),
+            Some(set.as_label()),
+            None,
+        )
+    }
+
+    /// Adds a [`SystemSet`].
+    ///
+    /// If the [`SystemSet`] by the same label, was already present, it's replace.
     #[inline]
-    pub fn add_stage<T: Stage>(&mut self, stage: T) -> &mut Self { self }
+    pub fn add_system_set(&mut self, set: SystemSet) -> &mut Self {
+        self.sets.insert(set.label(), set);
+        self.dirty = true;
+        self
+    }
 
+    /// Returns `true`, if [`Stage`] by `label` present.
     #[inline]
-    pub fn add_stage_group<T: StageGroup>(&mut self, group: T) -> &mut Self { self }
+    pub fn has_stage(&self, label: impl StageLabel) -> bool { self.stages.has_stage(label) }
 
+    /// Returns `true`, if [`SystemSet`] by `label` present.
     #[inline]
-    pub fn add_system<T: System>(&mut self, stage: impl StageLabel, system: T) -> &mut Self { self }
+    pub fn has_system_set(&self, label: impl SystemLabel) -> bool {
+        self.sets.contains_key(&label.as_label())
+    }
+
+    /// Runs [`Stage`]s and their [`System`]s in order.
+    ///
+    /// [`RunCriteria`] of each [`SystemSet`] are checked once per run.
+    ///
+    /// # Panics
+    /// Panics, if ordering of [`SystemSet`]s contains a cycle, see [`ScheduleError::SetCycle`].
+    pub fn run(&mut self, world: &mut World) {
+        if let Err(error) = self.sort_systems_if_dirty() {
+            panic!("{error}");
+        }
+
+        let mut should_run_sets = HashMap::<SystemLabelId, bool>::new();
+
+        for label in &self.order {
+            if let Some(stage) = self.stages.get_stage_mut(*label) {
+                stage.run(world);
+            }
+
+            let Some(systems) = self.systems.get_mut(label) else {
+                continue;
+            };
+
+            for scheduled in systems {
+                if let Some(set) = scheduled.set {
+                    let sets = &mut self.sets;
+                    let should_run = *should_run_sets.entry(set).or_insert_with(|| {
+                        sets.get_mut(&set).is_none_or(|set| set.should_run(world))
+                    });
+                    if !should_run {
+                        continue;
+                    }
+                }
+
+                if let Some(run_criteria) = &mut scheduled.run_criteria {
+                    if !run_criteria.should_run(world) {
+                        continue;
+                    }
+                }
+
+                scheduled.system.run_world(world);
+            }
+        }
+    }
+
+    /// Returns a [`ScheduleGraph`] of [`Stage`]s and their [`System`]s in the resolved order.
+    ///
+    /// # Panics
+    /// Panics, if ordering of [`SystemSet`]s contains a cycle, see [`ScheduleError::SetCycle`].
+    pub fn graph(&mut self) -> ScheduleGraph {
+        if let Err(error) = self.sort_systems_if_dirty() {
+            panic!("{error}");
+        }
+
+        let stages = self
+            .order
+            .iter()
+            .map(|label| {
+                let systems = self.systems.get(label).map_or(&[][..], Vec::as_slice);
+                let mut edges = Vec::new();
+
+                for (i, system) in systems.iter().enumerate() {
+                    for (j, other) in systems.iter().enumerate().skip(i + 1) {
+                        if self.is_after(other, system) {
+                            edges.push((i, j));
+                        }
+                    }
+                }
+
+                let systems = systems
+                    .iter()
+                    .map(|scheduled| {
+                        SystemNode::new(
+                            scheduled.system.name(),
+                            scheduled.set,
+                            scheduled.system.collect_access(),
+                        )
+                    })
+                    .collect();
+
+                StageNode::new(*label, systems, edges)
+            })
+            .collect();
+
+        ScheduleGraph::new(stages)
+    }
+
+    /// Adds a [`System`] without checking its [`Stage`], so it can be added before the [`Stage`] itself.
+    ///
+    /// Such [`System`]s must be checked by [`Schedule::check_stages`] before the first run.
+    pub(crate) fn push_system(
+        &mut self,
+        stage: StageLabelId,
+        system: Box<dyn DynSystem>,
+        set: Option<SystemLabelId>,
+        run_criteria: Option<Box<dyn RunCriteria>>,
+    ) -> &mut Self {
+        self.systems
+            .entry(stage)
+            .or_default()
+            .push(ScheduledSystem {
+                system,
+                set,
+                run_criteria,
+            });
+        self.dirty = true;
+        self
+    }
+
+    /// Returns [`ScheduleError::UnknownStage`] of the first [`System`], that's [`Stage`] isn't present.
+    pub(crate) fn check_stages(&self) -> Result<(), ScheduleError> {
+        for (stage, systems) in &self.systems {
+            if let Some(scheduled) = systems.first() {
+                if !self.stages.has_stage(*stage) {
+                    return Err(ScheduleError::UnknownStage {
+                        stage: *stage,
+                        system: scheduled.system.name(),
+                    });
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns [`ScheduleError::SetCycle`], if ordering of [`SystemSet`]s contains a cycle.
+    #[inline]
+    pub(crate) fn check_sets(&mut self) -> Result<(), ScheduleError> {
+        self.sort_systems_if_dirty()
+    }
+
+    fn check_stage(
+        &mut self,
+        stage: StageLabelId,
+        system: Box<dyn DynSystem>,
+        set: Option<SystemLabelId>,
+        run_criteria: Option<Box<dyn RunCriteria>>,
+    ) -> Result<&mut Self, ScheduleError> {
+        if !self.stages.has_stage(stage) {
+            return Err(ScheduleError::UnknownStage {
+                stage,
+                system: system.name(),
+            });
+        }
+        Ok(self.push_system(stage, system, set, run_criteria))
+    }
+
+    /// Resolves the linear order of [`Stage`]s,
+    /// and stable topological sort of [`System`]s within each [`Stage`] by ordering of their [`SystemSet`]s.
+    ///
+    /// If ordering of [`SystemSet`]s contains a cycle, [`System`]s of the [`Stage`] keep their order.
+    fn sort_systems_if_dirty(&mut self) -> Result<(), ScheduleError> {
+        if !self.dirty {
+            return Ok(());
+        }
+
+        self.order = self.stages.order();
+
+        let sets = &self.sets;
+        let is_after = |system: &ScheduledSystem, other: &ScheduledSystem| {
+            Self::is_set_after(sets, system, other)
+        };
+
+        for (stage, systems) in self.systems.iter_mut() {
+            let mut pending = std::mem::take(systems);
+
+            while !pending.is_empty() {
+                let Some(i) = (0..pending.len()).find(|&i| {
+                    !pending
+                        .iter()
+                        .enumerate()
+                        .any(|(j, other)| i != j && is_after(&pending[i], other))
+                }) else {
+                    let set = Self::find_cycle(&pending, is_after);
+                    systems.append(&mut pending);
+                    return Err(ScheduleError::SetCycle { stage: *stage, set });
+                };
+                systems.push(pending.remove(i));
+            }
+        }
+
+        self.dirty = false;
+        Ok(())
+    }
+
+    /// Returns a [`SystemSet`] in the cycle of `pending` [`System`]s, each of them runs after another one.
+    fn find_cycle(
+        pending: &[ScheduledSystem],
+        is_after: impl Fn(&ScheduledSystem, &ScheduledSystem) -> bool,
+    ) -> SystemLabelId {
+        let mut visited = vec![false; pending.len()];
+        let mut i = 0;
+        while !visited[i] {
+            visited[i] = true;
+            i = (0..pending.len())
+                .find(|&j| i != j && is_after(&pending[i], &pending[j]))
+                .unwrap_or(i);
+        }
+        pending[i].set.unwrap()
+    }
+
+    fn is_after(&self, system: &ScheduledSystem, other: &ScheduledSystem) -> bool {
+        Self::is_set_after(&self.sets, system, other)
+    }
 
-    pub fn run(&self, world: &mut World) {}
+    fn is_set_after(
+        sets: &HashMap<SystemLabelId, SystemSet>,
+        system: &ScheduledSystem,
+        other: &ScheduledSystem,
+    ) -> bool {
+        match (
+            system.set.and_then(|s| sets.get(&s)),
+            other.set.and_then(|s| sets.get(&s)),
+        ) {
+            | (Some(system), Some(other)) => system.is_after(other),
+            | _ => false,
+        }
+    }
 }
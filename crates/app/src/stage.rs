@@ -1,13 +1,11 @@
 //! Application stage functionality.
 
-use std::{
-    any::{type_name, TypeId},
-    collections::HashMap,
-};
+use std::collections::HashMap;
 
 use qinetic_ecs::world::World;
 use qinetic_utils::prelude::*;
 
+#[allow(unused_imports)]
 use crate::schedule::*;
 
 /// [`Schedule`]'s step of execution cycle.
@@ -17,6 +15,7 @@ use crate::schedule::*;
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_ecs::world::World;
 /// #
+/// #[derive(Clone)]
 /// struct MyStage;
 ///
 /// impl Stage for MyStage {
@@ -45,29 +44,20 @@ qinetic_utils::define_label!(
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
-/// # use qinetic_ecs::world::World;
 /// #
+/// #[derive(StageLabel)]
 /// struct MyStage1;
 ///
-/// impl Stage for MyStage1 {
-///     fn run(&mut self, world: &mut World) {
-///         // Something to do
-///     }
-/// }
-///
+/// #[derive(StageLabel)]
 /// struct MyStage2;
 ///
-/// impl Stage for MyStage2 {
-///     fn run(&mut self, world: &mut World) {
-///         // Something to do
-///     }
-/// }
-///
 /// struct MyStageGroup;
 ///
 /// impl StageGroup for MyStageGroup {
 ///     fn configure(&mut self, registry: &mut StageRegistry) {
-///         registry.add_stage(MyStage1).add_stage(MyStage2);
+///         registry
+///             .add_stage(MyStage1, SingleStage::default())
+///             .add_stage(MyStage2, SingleStage::default());
 ///     }
 /// }
 /// ```
@@ -76,280 +66,250 @@ pub trait StageGroup {
     fn configure(&mut self, registry: &mut StageRegistry);
 }
 
+/// Requested place of the [`Stage`] in the [`StageRegistry`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum StagePosition {
+    /// At the end.
+    End,
+
+    /// Right after the [`Stage`] by label.
+    After(StageLabelId),
+
+    /// Right before the [`Stage`] by label.
+    Before(StageLabelId),
+}
+
 /// Facilities addition and remove [`Stage`]s.
 ///
+/// [`Stage`]s can be added after or before a `target`, that is added later, like by another [`StageGroup`],
+/// so the linear order is resolved by [`StageRegistry::order`].
+/// If the `target` isn't present at all, the [`Stage`] is placed at the end.
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
-/// # use qinetic_ecs::world::World;
 /// #
+/// #[derive(StageLabel)]
 /// struct MyStage1;
 ///
-/// impl Stage for MyStage1 {
-///     fn run(&mut self, world: &mut World) { // Something to do
-///     }
-/// }
-///
+/// #[derive(StageLabel)]
 /// struct MyStage2;
 ///
-/// impl Stage for MyStage2 {
-///     fn run(&mut self, world: &mut World) { // Something to do
-///     }
-/// }
-///
+/// #[derive(StageLabel)]
 /// struct MyStage3;
 ///
-/// impl Stage for MyStage3 {
-///     fn run(&mut self, world: &mut World) { // Something to do
-///     }
-/// }
-///
-/// let mut stage_registry = StageRegistry::default()
-///     .add_stage(MyStage1)
-///     .add_stage_after(MyStage1, MyStage2)
-///     .add_stage_before(MyStage2, MyStage3);
+/// let mut stage_registry = StageRegistry::default();
+/// stage_registry
+///     .add_stage_after(MyStage1, MyStage2, SingleStage::default())
+///     .add_stage(MyStage1, SingleStage::default())
+///     .add_stage_before(MyStage2, MyStage3, SingleStage::default());
 ///
-/// # assert!(stage_registry.has_stage::<MyStage1>());
-/// # assert!(stage_registry.has_stage::<MyStage2>());
-/// # assert!(stage_registry.has_stage::<MyStage3>());
+/// assert_eq!(
+///     stage_registry.order(),
+///     vec![
+///         MyStage1.as_label(),
+///         MyStage3.as_label(),
+///         MyStage2.as_label()
+///     ]
+/// );
 /// ```
 #[derive(SmartDefault)]
 pub struct StageRegistry {
-    /// [`Stage`]s by [`TypeId`].
-    stages: HashMap<TypeId, Box<dyn Stage>>,
+    /// [`Stage`]s by [`StageLabelId`].
+    stages: HashMap<StageLabelId, Box<dyn Stage>>,
 
-    /// Linear order of [`Stage`]s.
-    order: Vec<TypeId>,
+    /// Requested places of [`Stage`]s in order of addition.
+    positions: Vec<(StageLabelId, StagePosition)>,
 }
 
 impl StageRegistry {
-    /// Returns a [`StageRegistry`] with added [`Stage`] at the end.
+    /// Returns a [`StageRegistry`] with added [`Stage`] by `label` at the end.
     ///
     /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
     ///
     /// # Examples
     /// ```
     /// # use qinetic_app::prelude::*;
-    /// # use qinetic_ecs::world::World;
     /// #
+    /// #[derive(StageLabel)]
     /// struct MyStage;
     ///
-    /// impl Stage for MyStage {
-    ///     fn run(&mut self, world: &mut World) {/* Something to do */}
-    /// }
-    ///
-    /// let mut stage_registry =
-    ///     StageRegistry::default()
-    ///         .add_stage(MyStage);
+    /// let mut stage_registry = StageRegistry::default();
+    /// stage_registry.add_stage(MyStage, SingleStage::default());
     ///
-    /// # assert!(stage_registry.has_stage::<MyStage>());
-    // ```
+    /// assert!(stage_registry.has_stage(MyStage));
+    /// ```
+    #[inline]
     pub fn add_stage<T: Stage>(&mut self, label: impl StageLabel, stage: T) -> &mut Self {
-        let i = self.order.len();
-        self.order.push(TypeId::of::<T>());
-        self.upsert(stage, i);
-        self
+        self.insert(label.as_label(), Box::new(stage), StagePosition::End)
     }
 
-    /// Returns a [`StageRegistry`] with added [`Stage`] after `Target` [`Stage`].
+    /// Returns a [`StageRegistry`] with added [`Stage`] by `label` after `target` [`Stage`].
     ///
-    /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
+    /// If the [`Stage`] was already present, it's removed from it's previous place.
     ///
     /// # Examples
     /// ```
     /// # use qinetic_app::prelude::*;
-    /// # use qinetic_ecs::world::World;
     /// #
+    /// #[derive(StageLabel)]
     /// struct MyStage1;
     ///
-    /// impl Stage for MyStage1 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
+    /// #[derive(StageLabel)]
     /// struct MyStage2;
     ///
-    /// impl Stage for MyStage2 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
+    /// #[derive(StageLabel)]
+    /// struct MyStage3;
     ///
-    /// let mut stage_registry = StageRegistry::default()
-    ///     .add_stage(MyStage1)
-    ///     .add_stage_after(MyStage1, MyStage2);
+    /// let mut stage_registry = StageRegistry::default();
+    /// stage_registry
+    ///     .add_stage(MyStage1, SingleStage::default())
+    ///     .add_stage(MyStage2, SingleStage::default())
+    ///     .add_stage_after(MyStage1, MyStage3, SingleStage::default());
     ///
-    /// # assert!(stage_registry.has_stage::<MyStage1>());
-    /// # assert!(stage_registry.has_stage::<MyStage2>());
+    /// assert_eq!(stage_registry.order()[1], MyStage3.as_label());
     /// ```
+    #[inline]
     pub fn add_stage_after<T: Stage>(
         &mut self,
         target: impl StageLabel,
         label: impl StageLabel,
         stage: T,
     ) -> &mut Self {
-        // let i = self.index_of::<Target>();
-        // let i = match i {
-        // Some(i) => i + 1,
-        // None => panic!(
-        // "Failed to add Stage after, it's does not present in registry: {}.",
-        // type_name::<Target>()
-        // ),
-        // };
-        // self.order.insert(i, TypeId::of::<T>());
-        // self.upsert(stage, i);
-        self
+        let position = StagePosition::After(target.as_label());
+        self.insert(label.as_label(), Box::new(stage), position)
     }
 
-    /// Returns a [`StageRegistry`] with added [`Stage`] before `Target` [`Stage`].
+    /// Returns a [`StageRegistry`] with added [`Stage`] by `label` before `target` [`Stage`].
     ///
-    /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
+    /// If the [`Stage`] was already present, it's removed from it's previous place.
     ///
     /// # Examples
     /// ```
     /// # use qinetic_app::prelude::*;
-    /// # use qinetic_ecs::world::World;
     /// #
+    /// #[derive(StageLabel)]
     /// struct MyStage1;
     ///
-    /// impl Stage for MyStage1 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
+    /// #[derive(StageLabel)]
     /// struct MyStage2;
     ///
-    /// impl Stage for MyStage2 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
-    /// let mut stage_registry = StageRegistry::default()
-    ///     .add_stage(MyStage1)
-    ///     .add_stage_before(MyStage1, MyStage2);
+    /// let mut stage_registry = StageRegistry::default();
+    /// stage_registry
+    ///     .add_stage(MyStage1, SingleStage::default())
+    ///     .add_stage_before(MyStage1, MyStage2, SingleStage::default());
     ///
-    /// # assert!(stage_registry.has_stage::<MyStage1>());
-    /// # assert!(stage_registry.has_stage::<MyStage2>());
+    /// assert_eq!(stage_registry.order()[0], MyStage2.as_label());
     /// ```
+    #[inline]
     pub fn add_stage_before<T: Stage>(
         &mut self,
         target: impl StageLabel,
         label: impl StageLabel,
         stage: T,
     ) -> &mut Self {
-        // let i = self.index_of::<Target>();
-        // let i = match i {
-        // Some(i) => i + 1,
-        // None => panic!(
-        // "Failed to add Stage after, it's does not present in registry: {}.",
-        // type_name::<Target>()
-        // ),
-        // };
-        // self.order.insert(i, TypeId::of::<T>());
-        // self.upsert(stage, i);
-        self
+        let position = StagePosition::Before(target.as_label());
+        self.insert(label.as_label(), Box::new(stage), position)
     }
 
-    /// Returns `true`, if [`Stage`] by `T`, present in [`StageRegistry`].
+    /// Returns a [`StageRegistry`] with added [`Stage`]s of the [`StageGroup`].
     ///
-    /// # Examples
-    /// ```
-    /// # use qinetic_app::prelude::*;
-    /// # use qinetic_ecs::world::World;
-    /// #
-    /// struct MyStage1;
-    ///
-    /// impl Stage for MyStage1 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
-    /// struct MyStage2;
-    ///
-    /// impl Stage for MyStage2 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
-    /// let mut stage_registry = StageRegistry::default()
-    ///     .add_stage(MyStage1)
-    ///     .add_stage_before(MyStage1, MyStage2);
-    ///
-    /// assert!(stage_registry.has_stage::<MyStage1>());
-    /// assert!(stage_registry.has_stage::<MyStage2>());
-    /// ```
-    pub fn has_stage<T: Stage>(&mut self) -> bool { self.index_of::<T>().is_some() }
-
-    /// Returns a [`StageRegistry`] with added [`Stage`]s of the [`StageGroup`] at the end.
-    ///
-    /// If the [`Stage`] of the [`StageGroup`] was already present, it's removed from its previous place and add at the end.
+    /// If the [`Stage`] of the [`StageGroup`] was already present, it's removed from its previous place.
     ///
     /// # Examples
     /// ```
     /// # use qinetic_app::prelude::*;
-    /// # use qinetic_ecs::world::World;
     /// #
+    /// #[derive(StageLabel)]
     /// struct MyStage1;
     ///
-    /// impl Stage for MyStage1 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
+    /// #[derive(StageLabel)]
     /// struct MyStage2;
     ///
-    /// impl Stage for MyStage2 {
-    ///     fn run(&mut self, world: &mut World) { // Something to do
-    ///     }
-    /// }
-    ///
     /// struct MyStageGroup;
     ///
     /// impl StageGroup for MyStageGroup {
     ///     fn configure(&mut self, registry: &mut StageRegistry) {
-    ///         registry.add_stage(MyStage1).add_stage(MyStage2);
+    ///         registry
+    ///             .add_stage(MyStage1, SingleStage::default())
+    ///             .add_stage(MyStage2, SingleStage::default());
     ///     }
     /// }
-    /// let mut stage_registry = StageRegistry::default().add_stage_group(MyStageGroup);
     ///
-    /// # assert!(stage_registry.has_stage::<MyStage1>());
-    /// # assert!(stage_registry.has_stage::<MyStage2>());
+    /// let mut stage_registry = StageRegistry::default();
+    /// stage_registry.add_stage_group(MyStageGroup);
+    ///
+    /// assert!(stage_registry.has_stage(MyStage1));
+    /// assert!(stage_registry.has_stage(MyStage2));
     /// ```
+    #[inline]
     pub fn add_stage_group<T: StageGroup>(&mut self, mut group: T) -> &mut Self {
         group.configure(self);
         self
     }
 
-    ///
-    pub fn build(&mut self) -> Schedule {
-        let mut schedule = Schedule::default();
-
-        for p in &self.order {
-            if let Some(e) = self.stages.get(p) {}
-        }
-
-        schedule
+    /// Returns `true`, if [`Stage`] by `label` present.
+    #[inline]
+    pub fn has_stage(&self, label: impl StageLabel) -> bool {
+        self.stages.contains_key(&label.as_label())
     }
 
-    /// Finds the index of a `Target` [`Stage`].
-    fn index_of<Target: Stage>(&mut self) -> Option<usize> {
-        self.order.iter().position(|&p| p == TypeId::of::<Target>())
+    /// Returns a mutable [`Stage`] by `label`, if it's present.
+    #[inline]
+    pub fn get_stage_mut(&mut self, label: impl StageLabel) -> Option<&mut dyn Stage> {
+        self.stages
+            .get_mut(&label.as_label())
+            .map(|stage| &mut **stage)
     }
 
-    /// Insert the new [`Stage`] as enabled, and removes its previous ordering
-    ///
-    /// If the [`Stage`] was already present, it's removed from it's previous place and add at the end.
-    fn upsert<T: Stage>(&mut self, stage: T, index: usize) {
-        self.stages.insert(TypeId::of::<T>(), Box::new(stage));
+    /// Returns labels of [`Stage`]s in the resolved linear order.
+    ///
+    /// [`Stage`]s are placed in order of addition,
+    /// several [`Stage`]s after the same `target` keep their order of addition.
+    pub fn order(&self) -> Vec<StageLabelId> {
+        let mut order = Vec::<StageLabelId>::with_capacity(self.positions.len());
+        let mut last_after = HashMap::<StageLabelId, StageLabelId>::new();
+        let mut pending = self.positions.clone();
 
-        if let Some(r) = self
-            .order
-            .iter()
-            .enumerate()
-            .find(|(i, p)| *i != index && **p == TypeId::of::<T>())
-            .map(|(i, _)| i)
-        {
-            self.order.remove(r);
+        loop {
+            let len = pending.len();
+            pending.retain(|&(label, position)| {
+                let index = match position {
+                    | StagePosition::End => Some(order.len()),
+                    | StagePosition::After(target) => {
+                        let anchor = last_after.get(&target).copied().unwrap_or(target);
+                        order.iter().position(|l| *l == anchor).map(|i| i + 1)
+                    },
+                    | StagePosition::Before(target) => order.iter().position(|l| *l == target),
+                };
+                let Some(index) = index else {
+                    return true;
+                };
+                if let StagePosition::After(target) = position {
+                    last_after.insert(target, label);
+                }
+                order.insert(index, label);
+                false
+            });
+            if pending.is_empty() || pending.len() == len {
+                break;
+            }
         }
+
+        order.extend(pending.into_iter().map(|(label, _)| label));
+        order
+    }
+
+    fn insert(
+        &mut self,
+        label: StageLabelId,
+        stage: Box<dyn Stage>,
+        position: StagePosition,
+    ) -> &mut Self {
+        self.stages.insert(label, stage);
+        self.positions.retain(|(l, _)| *l != label);
+        self.positions.push((label, position));
+        self
     }
 }
 
@@ -0,0 +1,242 @@
+//! Application sub-app functionality.
+
+use std::{
+    sync::mpsc::{channel, Receiver, Sender},
+    thread,
+};
+
+use qinetic_ecs::world::World;
+
+use crate::{app::App, schedule::Schedule};
+
+qinetic_utils::define_label!(
+    /// A strongly-typed class of labels used to identify [`SubApp`].
+    AppLabel,
+    /// Strongly-typed identifier for a [`AppLabel`].
+    AppLabelId,
+);
+
+/// Copies data from the main [`World`] to the [`SubApp`]'s [`World`] before each run.
+///
+/// Implemented for any `FnMut(&mut World, &mut World)` closure, that takes the main [`World`] first.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_ecs::world::World;
+/// #
+/// struct MyExtract;
+///
+/// impl Extract for MyExtract {
+///     fn extract(&mut self, main_world: &mut World, world: &mut World) {
+///         // Something to do
+///     }
+/// }
+/// ```
+pub trait Extract: Send + 'static {
+    /// Copies data from the `main_world` to the `world` of the [`SubApp`].
+    fn extract(&mut self, main_world: &mut World, world: &mut World);
+}
+
+impl<F> Extract for F
+where F: FnMut(&mut World, &mut World) + Send + 'static
+{
+    #[inline]
+    fn extract(&mut self, main_world: &mut World, world: &mut World) { self(main_world, world) }
+}
+
+/// Application with own [`World`] and [`Schedule`], that runs beside the main [`App`].
+///
+/// On each [`App::update`], the [`Extract`] step copies data from the main [`World`],
+/// and then the [`SubApp`]'s [`Schedule`] runs.
+///
+/// A pipelined [`SubApp`] runs its [`Schedule`] on a separate thread, so the frame `N` of the [`SubApp`]
+/// runs while the main [`App`] advances to the frame `N + 1`.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_ecs::world::World;
+/// #
+/// #[derive(AppLabel)]
+/// struct RenderApp;
+///
+/// #[derive(StageLabel)]
+/// struct RenderStage;
+///
+/// let render_app = App::builder()
+///     .with_stage(RenderStage, SingleStage::default())
+///     .build()
+///     .unwrap();
+///
+/// let mut app = App::builder()
+///     .with_sub_app(
+///         RenderApp,
+///         SubApp::new(
+///             render_app,
+///             |main_world: &mut World, render_world: &mut World| {
+///                 // Something to do
+///             },
+///         )
+///         .with_pipelined(true),
+///     )
+///     .build()
+///     .unwrap();
+///
+/// app.update();
+/// # assert!(app.sub_app_mut(RenderApp).is_some());
+/// ```
+pub struct SubApp {
+    /// The ECS [`World`] of the [`SubApp`].
+    world: World,
+
+    /// Container of [`Stage`](crate::stage::Stage)s of the [`SubApp`].
+    schedule: Schedule,
+
+    /// The step, that copies data from the main [`World`].
+    extract: Box<dyn Extract>,
+
+    /// Runs [`Schedule`] on a separate thread, if `true`.
+    pipelined: bool,
+}
+
+impl SubApp {
+    /// Returns a [`SubApp`] with [`World`] and [`Schedule`] of the `app`.
+    ///
+    /// The [`Runner`](crate::runner::Runner) of the `app` is not used.
+    pub fn new(app: App, extract: impl Extract) -> Self {
+        let (schedule, world) = app.into_parts();
+        Self {
+            world,
+            schedule,
+            extract: Box::new(extract),
+            pipelined: false,
+        }
+    }
+
+    /// Returns a [`SubApp`], that runs its [`Schedule`] on a separate thread.
+    #[inline]
+    pub fn with_pipelined(mut self, pipelined: bool) -> Self {
+        self.pipelined = pipelined;
+        self
+    }
+
+    /// Returns `true`, if the [`SubApp`] runs its [`Schedule`] on a separate thread.
+    #[inline]
+    pub fn pipelined(&self) -> bool { self.pipelined }
+
+    /// Returns a immutable [`World`] of the [`SubApp`].
+    #[inline]
+    pub fn world(&self) -> &World { &self.world }
+
+    /// Returns a mutable [`World`] of the [`SubApp`].
+    #[inline]
+    pub fn world_mut(&mut self) -> &mut World { &mut self.world }
+
+    /// Returns a mutable [`Schedule`] of the [`SubApp`].
+    #[inline]
+    pub fn schedule_mut(&mut self) -> &mut Schedule { &mut self.schedule }
+
+    /// Copies data from the `main_world` by [`Extract`] step.
+    #[inline]
+    pub fn extract(&mut self, main_world: &mut World) {
+        self.extract.extract(main_world, &mut self.world);
+    }
+
+    /// Runs [`Schedule`] of the [`SubApp`] once, and clears [`Event`](qinetic_ecs::event::Event)s of it's [`World`].
+    #[inline]
+    pub fn run(&mut self) {
+        self.schedule.run(&mut self.world);
+        self.world.clear_events();
+    }
+}
+
+/// [`SubApp`] owned by the [`App`], that may run on a separate thread.
+pub(crate) struct SubAppSlot {
+    /// The [`SubApp`], if it's not running on the `worker`.
+    sub_app: Option<SubApp>,
+
+    /// The thread of pipelined [`SubApp`].
+    worker: Option<SubAppWorker>,
+}
+
+impl SubAppSlot {
+    pub(crate) fn new(sub_app: SubApp) -> Self {
+        Self {
+            sub_app: Some(sub_app),
+            worker: None,
+        }
+    }
+
+    /// Extracts data from the `main_world` and runs the [`SubApp`].
+    ///
+    /// Waits for the previous run of pipelined [`SubApp`] first.
+    pub(crate) fn update(&mut self, main_world: &mut World) {
+        let sub_app = self.wait();
+        sub_app.extract(main_world);
+
+        if !sub_app.pipelined {
+            sub_app.run();
+            return;
+        }
+
+        if let Some(sub_app) = self.sub_app.take() {
+            self.worker
+                .get_or_insert_with(SubAppWorker::spawn)
+                .send(sub_app);
+        }
+    }
+
+    /// Waits for the [`SubApp`], if it's running, and returns it.
+    pub(crate) fn wait(&mut self) -> &mut SubApp {
+        if self.sub_app.is_none() {
+            let worker = self
+                .worker
+                .as_ref()
+                .expect("Failed to wait SubApp, it's does not present.");
+            self.sub_app = Some(worker.recv());
+        }
+
+        self.sub_app.as_mut().unwrap()
+    }
+}
+
+/// Thread, that runs pipelined [`SubApp`]s.
+pub(crate) struct SubAppWorker {
+    sender: Sender<SubApp>,
+
+    receiver: Receiver<SubApp>,
+}
+
+impl SubAppWorker {
+    fn spawn() -> Self {
+        let (sender, worker_receiver) = channel::<SubApp>();
+        let (worker_sender, receiver) = channel::<SubApp>();
+
+        thread::Builder::new()
+            .name("qinetic sub-app".into())
+            .spawn(move || {
+                for mut sub_app in worker_receiver {
+                    sub_app.run();
+                    if worker_sender.send(sub_app).is_err() {
+                        break;
+                    }
+                }
+            })
+            .expect("Failed to spawn SubApp thread.");
+
+        Self { sender, receiver }
+    }
+
+    fn send(&self, sub_app: SubApp) {
+        self.sender
+            .send(sub_app)
+            .expect("Failed to send SubApp, it's thread was stopped.");
+    }
+
+    fn recv(&self) -> SubApp {
+        self.receiver
+            .recv()
+            .expect("Failed to receive SubApp, it's thread was panicked.")
+    }
+}
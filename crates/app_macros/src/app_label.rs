@@ -0,0 +1,13 @@
+//! `SubApp` derive.
+
+use proc_macro::TokenStream;
+use quote::format_ident;
+use syn::{parse_macro_input, DeriveInput};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    let mut trait_path = crate::path();
+    trait_path.segments.push(format_ident!("sub_app").into());
+    trait_path.segments.push(format_ident!("AppLabel").into());
+    qinetic_utils::label::derive_label(input, &trait_path, "app_label")
+}
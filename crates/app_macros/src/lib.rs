@@ -9,12 +9,17 @@
 
 extern crate proc_macro;
 
+mod app_label;
 mod stage_label;
 
 use proc_macro::TokenStream;
 use qinetic_utils::manifest::Manifest;
 use syn::Path;
 
+/// Generates an impl for [`AppLabel`] trait.
+#[proc_macro_derive(AppLabel)]
+pub fn derive_app_label(input: TokenStream) -> TokenStream { app_label::derive(input) }
+
 /// Generates an impl for [`StageLabel`] trait.
 #[proc_macro_derive(StageLabel)]
 pub fn derive_stage(input: TokenStream) -> TokenStream { stage_label::derive(input) }
@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_ar::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(ArController::default())
+///     .with_component::<ArController>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct ArController {}
 
 /// Augmented reality tracker [`Component`].
@@ -26,9 +26,9 @@ pub struct ArController {}
 /// # use qinetic_ar::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(ArTracker::default())
+///     .with_component::<ArTracker>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct ArTracker {}
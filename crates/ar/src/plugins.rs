@@ -39,8 +39,8 @@ pub struct ArPlugin {}
 impl Plugin for ArPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(ArController::default())
-            .with_component(ArTracker::default())
+            .with_component::<ArController>()
+            .with_component::<ArTracker>()
             .with_stage_group(ArStageGroup::default())
             .with_system(ArStage::default(), ArSystem::default());
     }
@@ -0,0 +1,458 @@
+//! Asset archive functionality.
+//!
+//! Archive layout, all integers are little-endian:
+//! * magic: `[u8; 4]`, [`ARCHIVE_MAGIC`]
+//! * version: `u32`, [`ARCHIVE_VERSION`]
+//! * entry count: `u32`
+//! * index offset: `u64`
+//! * entry data, one after another
+//! * index, for each entry:
+//!   * path length: `u32`, path: UTF-8 with `/` separators
+//!   * offset: `u64`, size: `u64`, length: `u64`
+//!   * compression: `u8`, [`ArchiveCompression`]
+//!   * hash: `[u8; 32]`, SHA-256 of the uncompressed data
+//!
+//! Hashes detect corrupted data, but not tampering, since the index isn't signed
+//! and can be rewritten with the data.
+
+use std::{
+    collections::BTreeMap,
+    fs::{self, File},
+    io::{Read, Seek, SeekFrom, Write},
+    path::{Component, Path, PathBuf},
+    sync::{Mutex, PoisonError},
+};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use qinetic_utils::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::AssetError,
+    io::{walk_dir, AssetIo},
+};
+
+/// Magic bytes of the archive.
+pub const ARCHIVE_MAGIC: [u8; 4] = *b"QPAK";
+
+/// Current version of the archive layout.
+pub const ARCHIVE_VERSION: u32 = 1;
+
+/// Size of the archive header in bytes.
+const HEADER_SIZE: u64 = 20;
+
+/// Compression of the [`ArchiveEntry`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ArchiveCompression {
+    /// The data is stored as is.
+    None,
+
+    /// The data is compressed with deflate, if it's smaller.
+    #[default]
+    Deflate,
+}
+
+impl ArchiveCompression {
+    fn from_byte(byte: u8) -> Option<Self> {
+        match byte {
+            | 0 => Some(Self::None),
+            | 1 => Some(Self::Deflate),
+            | _ => None,
+        }
+    }
+
+    fn to_byte(self) -> u8 {
+        match self {
+            | Self::None => 0,
+            | Self::Deflate => 1,
+        }
+    }
+}
+
+/// Entry of the [`Archive`] index.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+pub struct ArchiveEntry {
+    /// The path of the file with `/` separators.
+    #[getset(get = "pub")]
+    path: String,
+
+    /// The offset of the data in the archive.
+    #[getset(get_copy = "pub")]
+    offset: u64,
+
+    /// The size of the stored data.
+    #[getset(get_copy = "pub")]
+    size: u64,
+
+    /// The length of the uncompressed data.
+    #[getset(get_copy = "pub")]
+    length: u64,
+
+    /// The compression of the stored data.
+    #[getset(get_copy = "pub")]
+    compression: ArchiveCompression,
+
+    /// The SHA-256 hash of the uncompressed data.
+    #[getset(get = "pub")]
+    hash: [u8; 32],
+}
+
+/// Returns a archive path of `path` with `/` separators, if it's relative and normal.
+fn archive_path(path: &Path) -> Option<String> {
+    let mut parts = Vec::new();
+    for component in path.components() {
+        match component {
+            | Component::Normal(part) => parts.push(part.to_str()?),
+            | Component::CurDir => {},
+            | _ => return None,
+        }
+    }
+    Some(parts.join("/"))
+}
+
+fn invalid(reason: &str) -> AssetError {
+    AssetError::InvalidArchive {
+        reason: reason.into(),
+    }
+}
+
+/// Builder of the [`Archive`], that packs files into one file with an index.
+///
+/// It can be used from a build script, or by the `qinetic_pack` command line tool.
+///
+/// # Examples
+/// ```
+/// # use std::path::Path;
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let bytes = ArchiveBuilder::default()
+///     .with_file("textures/brick.bin", vec![0; 1024])
+///     .with_file("title.txt", b"Hello".to_vec())
+///     .build();
+///
+/// let archive = Archive::from_bytes(bytes).unwrap();
+///
+/// assert_eq!(archive.read(Path::new("title.txt")).unwrap(), b"Hello");
+/// assert_eq!(archive.entry("textures/brick.bin").unwrap().length(), 1024);
+/// assert!(archive.entry("textures/brick.bin").unwrap().size() < 1024);
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct ArchiveBuilder {
+    compression: ArchiveCompression,
+    files: BTreeMap<String, Vec<u8>>,
+}
+
+impl ArchiveBuilder {
+    /// Returns a [`ArchiveBuilder`] with `compression` of entries.
+    #[inline]
+    pub fn with_compression(mut self, compression: ArchiveCompression) -> Self {
+        self.compression = compression;
+        self
+    }
+
+    /// Returns a [`ArchiveBuilder`] with added file by `path`.
+    /// If the file by `path`, was already present, it's replace.
+    pub fn with_file(mut self, path: impl AsRef<Path>, bytes: Vec<u8>) -> Self {
+        if let Some(path) = archive_path(path.as_ref()) {
+            self.files.insert(path, bytes);
+        }
+        self
+    }
+
+    /// Returns a [`ArchiveBuilder`] with added files of the `dir` directory recursively.
+    pub fn with_dir(mut self, dir: impl AsRef<Path>) -> Result<Self, AssetError> {
+        let dir = dir.as_ref();
+        for path in walk_dir(dir)? {
+            let bytes = fs::read(&path).map_err(|error| {
+                AssetError::Io {
+                    path: path.clone(),
+                    message: error.to_string(),
+                }
+            })?;
+            let relative = path.strip_prefix(dir).unwrap_or(&path);
+            self = self.with_file(relative, bytes);
+        }
+        Ok(self)
+    }
+
+    /// Returns bytes of the [`Archive`].
+    pub fn build(&self) -> Vec<u8> {
+        let mut data: Vec<u8> = Vec::new();
+        let mut index: Vec<u8> = Vec::new();
+
+        for (path, bytes) in &self.files {
+            let compressed = match self.compression {
+                | ArchiveCompression::None => None,
+                | ArchiveCompression::Deflate => {
+                    let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+                    encoder.write_all(bytes).ok();
+                    encoder.finish().ok().filter(|c| c.len() < bytes.len())
+                },
+            };
+            let (compression, stored) = match &compressed {
+                | Some(compressed) => (ArchiveCompression::Deflate, compressed),
+                | None => (ArchiveCompression::None, bytes),
+            };
+
+            index.extend((path.len() as u32).to_le_bytes());
+            index.extend(path.as_bytes());
+            index.extend((HEADER_SIZE + data.len() as u64).to_le_bytes());
+            index.extend((stored.len() as u64).to_le_bytes());
+            index.extend((bytes.len() as u64).to_le_bytes());
+            index.push(compression.to_byte());
+            index.extend(Sha256::digest(bytes));
+            data.extend(stored);
+        }
+
+        let mut archive = Vec::with_capacity(HEADER_SIZE as usize + data.len() + index.len());
+        archive.extend(ARCHIVE_MAGIC);
+        archive.extend(ARCHIVE_VERSION.to_le_bytes());
+        archive.extend((self.files.len() as u32).to_le_bytes());
+        archive.extend((HEADER_SIZE + data.len() as u64).to_le_bytes());
+        archive.extend(data);
+        archive.extend(index);
+        archive
+    }
+
+    /// Writes the [`Archive`] to the file by `path`.
+    pub fn write(&self, path: impl AsRef<Path>) -> Result<(), AssetError> {
+        fs::write(path.as_ref(), self.build()).map_err(|error| {
+            AssetError::Io {
+                path: path.as_ref().to_path_buf(),
+                message: error.to_string(),
+            }
+        })
+    }
+}
+
+/// Source of the [`Archive`] data.
+#[derive(Debug)]
+enum ArchiveSource {
+    Memory(Vec<u8>),
+    File(Mutex<File>, u64),
+}
+
+/// Packed archive of assets, that is read as [`AssetIo`].
+///
+/// Entries are read on demand and verified by their hashes.
+/// Offsets and sizes of the index are checked against the archive length, before anything is allocated.
+/// Hashes detect corrupted data, but not tampering, so archives should come from a trusted source.
+///
+/// # Examples
+/// ```
+/// # use std::path::Path;
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let path = std::env::temp_dir().join("qinetic_asset_archive.qpak");
+/// ArchiveBuilder::default()
+///     .with_file("title.txt", b"Hello".to_vec())
+///     .write(&path)
+///     .unwrap();
+///
+/// let server = AssetServer::with_io(Archive::open(&path).unwrap());
+/// let handle = server.load::<String>("title.txt");
+///
+/// let mut bytes = std::fs::read(&path).unwrap();
+/// bytes[20] ^= 0xFF;
+///
+/// let tampered = Archive::from_bytes(bytes).unwrap();
+/// assert!(matches!(
+///     tampered.read(Path::new("title.txt")),
+///     Err(AssetError::HashMismatch { .. })
+/// ));
+///
+/// // The size of the entry, that exceeds the archive.
+/// let mut bytes = std::fs::read(&path).unwrap();
+/// let index = u64::from_le_bytes(bytes[12..20].try_into().unwrap()) as usize;
+/// let size = index + 4 + "title.txt".len() + 8;
+/// bytes[size..size + 8].copy_from_slice(&u64::MAX.to_le_bytes());
+///
+/// assert!(matches!(
+///     Archive::from_bytes(bytes),
+///     Err(AssetError::InvalidArchive { .. })
+/// ));
+/// ```
+#[derive(Debug)]
+pub struct Archive {
+    source: ArchiveSource,
+    entries: BTreeMap<String, ArchiveEntry>,
+}
+
+impl Archive {
+    /// Returns a [`Archive`] of `bytes`.
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<Self, AssetError> {
+        let source = ArchiveSource::Memory(bytes);
+        let entries = Self::read_index(&source)?;
+        Ok(Self { source, entries })
+    }
+
+    /// Opens a [`Archive`] by `path`, entries are read from the file on demand.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, AssetError> {
+        let io_error = |error: std::io::Error| {
+            AssetError::Io {
+                path: path.as_ref().to_path_buf(),
+                message: error.to_string(),
+            }
+        };
+        let file = File::open(path.as_ref()).map_err(io_error)?;
+        let len = file.metadata().map_err(io_error)?.len();
+        let source = ArchiveSource::File(Mutex::new(file), len);
+        let entries = Self::read_index(&source)?;
+        Ok(Self { source, entries })
+    }
+
+    /// Returns a [`ArchiveEntry`] by `path`, if it's present.
+    pub fn entry(&self, path: impl AsRef<Path>) -> Option<&ArchiveEntry> {
+        self.entries.get(&archive_path(path.as_ref())?)
+    }
+
+    /// Returns all [`ArchiveEntry`]s ordered by path.
+    #[inline]
+    pub fn entries(&self) -> impl Iterator<Item = &ArchiveEntry> { self.entries.values() }
+
+    fn read_index(source: &ArchiveSource) -> Result<BTreeMap<String, ArchiveEntry>, AssetError> {
+        let header = source.read_at(0, HEADER_SIZE)?;
+        if header[..4] != ARCHIVE_MAGIC {
+            return Err(invalid("wrong magic"));
+        }
+        let version = u32::from_le_bytes(header[4..8].try_into().unwrap());
+        if version != ARCHIVE_VERSION {
+            return Err(invalid("unsupported version"));
+        }
+        let count = u32::from_le_bytes(header[8..12].try_into().unwrap());
+        let index_offset = u64::from_le_bytes(header[12..20].try_into().unwrap());
+        if !(HEADER_SIZE..=source.len()).contains(&index_offset) {
+            return Err(invalid("index offset"));
+        }
+        let index = source.read_to_end(index_offset)?;
+
+        let mut reader = index.as_slice();
+        let mut take = |len: usize| -> Result<&[u8], AssetError> {
+            if reader.len() < len {
+                return Err(invalid("truncated index"));
+            }
+            let (bytes, rest) = reader.split_at(len);
+            reader = rest;
+            Ok(bytes)
+        };
+        let mut entries = BTreeMap::new();
+        for _ in 0..count {
+            let len = u32::from_le_bytes(take(4)?.try_into().unwrap()) as usize;
+            let path = String::from_utf8(take(len)?.to_vec()).map_err(|_| invalid("path"))?;
+            let offset = u64::from_le_bytes(take(8)?.try_into().unwrap());
+            let size = u64::from_le_bytes(take(8)?.try_into().unwrap());
+            let length = u64::from_le_bytes(take(8)?.try_into().unwrap());
+            let compression =
+                ArchiveCompression::from_byte(take(1)?[0]).ok_or_else(|| invalid("compression"))?;
+            let hash = take(32)?.try_into().unwrap();
+            if offset < HEADER_SIZE
+                || offset
+                    .checked_add(size)
+                    .is_none_or(|end| end > index_offset)
+            {
+                return Err(invalid("entry offset"));
+            }
+            if compression == ArchiveCompression::None && length != size {
+                return Err(invalid("entry length"));
+            }
+            entries.insert(
+                path.clone(),
+                ArchiveEntry {
+                    path,
+                    offset,
+                    size,
+                    length,
+                    compression,
+                    hash,
+                },
+            );
+        }
+        Ok(entries)
+    }
+}
+
+impl ArchiveSource {
+    /// Returns a length of the archive in bytes.
+    fn len(&self) -> u64 {
+        match self {
+            | Self::Memory(bytes) => bytes.len() as u64,
+            | Self::File(_, len) => *len,
+        }
+    }
+
+    /// Returns `size` bytes by `offset`, the range is checked against the length before allocation.
+    fn read_at(&self, offset: u64, size: u64) -> Result<Vec<u8>, AssetError> {
+        let end = offset.checked_add(size).ok_or_else(|| invalid("offset"))?;
+        if end > self.len() {
+            return Err(invalid("truncated data"));
+        }
+        match self {
+            | Self::Memory(bytes) => {
+                bytes
+                    .get(offset as usize..end as usize)
+                    .map(<[u8]>::to_vec)
+                    .ok_or_else(|| invalid("truncated data"))
+            },
+            | Self::File(file, _) => {
+                let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+                let mut bytes = vec![0; size as usize];
+                file.seek(SeekFrom::Start(offset))
+                    .and_then(|_| file.read_exact(&mut bytes))
+                    .map_err(|_| invalid("truncated data"))?;
+                Ok(bytes)
+            },
+        }
+    }
+
+    fn read_to_end(&self, offset: u64) -> Result<Vec<u8>, AssetError> {
+        match self {
+            | Self::Memory(bytes) => {
+                bytes
+                    .get(offset as usize..)
+                    .map(<[u8]>::to_vec)
+                    .ok_or_else(|| invalid("truncated index"))
+            },
+            | Self::File(file, _) => {
+                let mut file = file.lock().unwrap_or_else(PoisonError::into_inner);
+                let mut bytes = Vec::new();
+                file.seek(SeekFrom::Start(offset))
+                    .and_then(|_| file.read_to_end(&mut bytes))
+                    .map_err(|_| invalid("truncated index"))?;
+                Ok(bytes)
+            },
+        }
+    }
+}
+
+impl AssetIo for Archive {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError> {
+        let entry = self.entry(path).ok_or_else(|| {
+            AssetError::NotFound {
+                path: path.to_path_buf(),
+            }
+        })?;
+        let stored = self.source.read_at(entry.offset, entry.size)?;
+        let bytes = match entry.compression {
+            | ArchiveCompression::None => stored,
+            | ArchiveCompression::Deflate => {
+                let mut bytes = Vec::new();
+                DeflateDecoder::new(stored.as_slice())
+                    .take(entry.length.saturating_add(1))
+                    .read_to_end(&mut bytes)
+                    .map_err(|_| invalid("corrupted data"))?;
+                bytes
+            },
+        };
+        if bytes.len() as u64 != entry.length || Sha256::digest(&bytes)[..] != entry.hash {
+            return Err(AssetError::HashMismatch {
+                path: PathBuf::from(&entry.path),
+            });
+        }
+        Ok(bytes)
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool { self.entry(path).is_some() }
+}
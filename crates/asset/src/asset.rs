@@ -0,0 +1,23 @@
+//! Asset functionality.
+
+use std::any::Any;
+
+/// Asset, that can be loaded by the [`AssetServer`](crate::server::AssetServer).
+///
+/// Assets are decoded from files by [`AssetLoader`](crate::loader::AssetLoader)s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// struct Config {
+///     lines: Vec<String>,
+/// }
+///
+/// impl Asset for Config {}
+/// ```
+pub trait Asset: Any + Send + Sync + 'static {}
+
+impl Asset for Vec<u8> {}
+
+impl Asset for String {}
@@ -0,0 +1,49 @@
+//! Packs an asset directory into an [`Archive`](qinetic_asset::archive::Archive).
+//!
+//! Usage: `qinetic_pack <DIR> <ARCHIVE> [--store]`
+//!
+//! `--store` disables compression of entries.
+
+use std::{env, process::ExitCode};
+
+use qinetic_asset::prelude::*;
+
+fn main() -> ExitCode {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let store = args.iter().any(|arg| arg == "--store");
+    let paths = args
+        .iter()
+        .filter(|arg| !arg.starts_with("--"))
+        .collect::<Vec<_>>();
+
+    let [dir, archive] = paths[..] else {
+        eprintln!("Usage: qinetic_pack <DIR> <ARCHIVE> [--store]");
+        return ExitCode::FAILURE;
+    };
+
+    let compression = match store {
+        | true => ArchiveCompression::None,
+        | false => ArchiveCompression::Deflate,
+    };
+    let result = ArchiveBuilder::default()
+        .with_compression(compression)
+        .with_dir(dir)
+        .and_then(|builder| builder.write(archive));
+
+    match result.and_then(|_| Archive::open(archive)) {
+        | Ok(archive) => {
+            let entries = archive.entries().collect::<Vec<_>>();
+            let length = entries.iter().map(|entry| entry.length()).sum::<u64>();
+            let size = entries.iter().map(|entry| entry.size()).sum::<u64>();
+            println!(
+                "Packed {} files, {length} bytes to {size} bytes.",
+                entries.len()
+            );
+            ExitCode::SUCCESS
+        },
+        | Err(error) => {
+            eprintln!("error: {error}");
+            ExitCode::FAILURE
+        },
+    }
+}
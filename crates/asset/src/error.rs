@@ -0,0 +1,80 @@
+//! Error functionality.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Error of the [`Asset`](crate::asset::Asset) loading.
+///
+/// The error is cloneable, so it can be stored in [`LoadState::Failed`](crate::server::LoadState::Failed).
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum AssetError {
+    /// The file can't be read.
+    #[error("Failed to read asset {}, it's {message}.", path.display())]
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+
+        /// The message of the I/O error.
+        message: String,
+    },
+
+    /// The file isn't present in the [`AssetIo`](crate::io::AssetIo).
+    #[error("Failed to read asset {}, it's not found.", path.display())]
+    NotFound {
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The [`Archive`](crate::archive::Archive) is invalid.
+    #[error("Failed to read archive, it's invalid: {reason}.")]
+    InvalidArchive {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The hash of the [`Archive`](crate::archive::Archive) entry doesn't match it's data.
+    #[error("Failed to read asset {}, it's hash doesn't match.", path.display())]
+    HashMismatch {
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The file has no [`AssetLoader`](crate::loader::AssetLoader) for it's extension and asset type.
+    #[error("Failed to load asset {}, it's have no loader for the extension.", path.display())]
+    NoLoader {
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The dependency of the asset failed to load.
+    #[error("Failed to load asset, it's dependency {} failed: {reason}", path.display())]
+    Dependency {
+        /// The path of the dependency.
+        path: PathBuf,
+
+        /// The error message of the dependency.
+        reason: String,
+    },
+
+    /// The asset and it's dependencies wait for each other.
+    #[error("Failed to load asset {}, it's dependencies form a cycle.", path.display())]
+    DependencyCycle {
+        /// The path of the asset.
+        path: PathBuf,
+    },
+
+    /// The root directory can't be watched for changes.
+    #[error("Failed to watch assets, it's {reason}.")]
+    Watch {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The bytes can't be decoded to the asset.
+    #[error("Failed to decode asset, it's invalid: {reason}.")]
+    InvalidData {
+        /// The reason of the failure.
+        reason: String,
+    },
+}
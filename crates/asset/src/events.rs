@@ -0,0 +1,61 @@
+//! Asset [`Event`]s functionality.
+
+use qinetic_ecs::prelude::*;
+
+use crate::handle::HandleId;
+
+/// Asset [`Event`], that is sent by the [`AssetServer`](crate::server::AssetServer).
+///
+/// Events of the last update are returned by [`AssetServer::events`](crate::server::AssetServer::events).
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let server = AssetServer::new("assets");
+/// let handle = server.add(String::from("Hello"));
+/// server.update();
+///
+/// assert_eq!(
+///     server.events::<String>(),
+///     vec![AssetEvent::Created { id: handle.id() }]
+/// );
+///
+/// drop(handle);
+/// server.update();
+///
+/// assert!(matches!(
+///     server.events::<String>()[..],
+///     [AssetEvent::Removed { .. }]
+/// ));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Event)]
+pub enum AssetEvent {
+    /// The [`Event`] that sent whenever an asset is loaded or added.
+    Created {
+        /// The identificator of the asset.
+        id: HandleId,
+    },
+
+    /// The [`Event`] that sent whenever an asset is replaced.
+    Modified {
+        /// The identificator of the asset.
+        id: HandleId,
+    },
+
+    /// The [`Event`] that sent whenever an asset is dropped with the last handle.
+    Removed {
+        /// The identificator of the asset.
+        id: HandleId,
+    },
+}
+
+impl AssetEvent {
+    /// Returns a [`HandleId`] of the asset.
+    #[inline]
+    pub fn id(&self) -> HandleId {
+        match self {
+            | Self::Created { id } | Self::Modified { id } | Self::Removed { id } => *id,
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Handle functionality.
+
+use std::{
+    any::{type_name, TypeId},
+    fmt,
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    sync::{mpsc::Sender, Arc},
+};
+
+use qinetic_utils::prelude::*;
+
+use crate::asset::Asset;
+
+/// Identificator for [`Handle`], that is unique across all [`Asset`] types of the [`AssetServer`](crate::server::AssetServer).
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, CopyGetters)]
+pub struct HandleId {
+    #[getset(get_copy = "pub")]
+    id: usize,
+
+    type_id: TypeId,
+}
+
+impl HandleId {
+    #[inline]
+    pub(crate) fn new<T: Asset>(id: usize) -> Self {
+        Self {
+            id,
+            type_id: TypeId::of::<T>(),
+        }
+    }
+
+    #[inline]
+    pub(crate) fn asset_type(&self) -> TypeId { self.type_id }
+
+    /// Returns `true`, if the [`HandleId`] identifies [`Asset`] of type `T`.
+    #[inline]
+    pub fn is<T: Asset>(&self) -> bool { self.type_id == TypeId::of::<T>() }
+}
+
+/// Strong reference, that notifies the [`AssetServer`](crate::server::AssetServer) when the last [`Handle`] is dropped.
+pub(crate) struct StrongHandle {
+    pub(crate) id: HandleId,
+    pub(crate) sender: Sender<HandleId>,
+}
+
+impl Drop for StrongHandle {
+    fn drop(&mut self) { self.sender.send(self.id).ok(); }
+}
+
+/// Typed, reference-counted handle of [`Asset`].
+///
+/// The [`Asset`] is dropped by the [`AssetServer`](crate::server::AssetServer), when the last [`Handle`] goes away.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let server = AssetServer::new("assets");
+/// let handle = server.add(String::from("Hello"));
+///
+/// assert_eq!(server.get(&handle).as_deref(), Some(&String::from("Hello")));
+/// assert_eq!(handle.clone(), handle);
+/// ```
+pub struct Handle<T: Asset> {
+    inner: Arc<StrongHandle>,
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Asset> Handle<T> {
+    #[inline]
+    pub(crate) fn new(inner: Arc<StrongHandle>) -> Self {
+        Self {
+            inner,
+            marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub(crate) fn inner(&self) -> &Arc<StrongHandle> { &self.inner }
+
+    /// Returns a [`HandleId`] of the [`Handle`].
+    #[inline]
+    pub fn id(&self) -> HandleId { self.inner.id }
+}
+
+impl<T: Asset> Clone for Handle<T> {
+    #[inline]
+    fn clone(&self) -> Self { Self::new(self.inner.clone()) }
+}
+
+impl<T: Asset> fmt::Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct(&format!("Handle<{}>", type_name::<T>()))
+            .field("id", &self.id().id())
+            .finish()
+    }
+}
+
+impl<T: Asset> PartialEq for Handle<T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool { self.id() == other.id() }
+}
+
+impl<T: Asset> Eq for Handle<T> {}
+
+impl<T: Asset> Hash for Handle<T> {
+    #[inline]
+    fn hash<H: Hasher>(&self, state: &mut H) { self.id().hash(state); }
+}
+
+impl<T: Asset> From<&Handle<T>> for HandleId {
+    #[inline]
+    fn from(handle: &Handle<T>) -> Self { handle.id() }
+}
@@ -0,0 +1,216 @@
+//! Asset I/O functionality.
+
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    sync::{Arc, PoisonError, RwLock},
+};
+
+use qinetic_utils::prelude::*;
+
+use crate::error::AssetError;
+
+/// Virtual filesystem, that the [`AssetServer`](crate::server::AssetServer) reads assets from.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let memory = MemoryIo::default();
+/// memory.insert("greeting.txt", b"Hello".to_vec());
+///
+/// let server = AssetServer::with_io(memory);
+/// let handle = server.load::<String>("greeting.txt");
+/// ```
+pub trait AssetIo: Send + Sync + 'static {
+    /// Reads bytes of the file by `path` relative to the root.
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError>;
+
+    /// Returns `true`, if the file by `path` relative to the root present.
+    fn exists(&self, path: &Path) -> bool;
+
+    /// Returns a directory on disk, that can be watched for changes, if it's present.
+    #[inline]
+    fn root(&self) -> Option<&Path> { None }
+}
+
+impl<T: AssetIo + ?Sized> AssetIo for Arc<T> {
+    #[inline]
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError> { (**self).read(path) }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool { (**self).exists(path) }
+
+    #[inline]
+    fn root(&self) -> Option<&Path> { (**self).root() }
+}
+
+/// [`AssetIo`] of the directory on disk.
+#[derive(Clone, Debug)]
+pub struct FileIo {
+    root: PathBuf,
+}
+
+impl FileIo {
+    /// Returns a [`FileIo`] of the `root` directory.
+    #[inline]
+    pub fn new(root: impl Into<PathBuf>) -> Self { Self { root: root.into() } }
+}
+
+impl AssetIo for FileIo {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError> {
+        fs::read(self.root.join(path)).map_err(|error| {
+            AssetError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool { self.root.join(path).is_file() }
+
+    #[inline]
+    fn root(&self) -> Option<&Path> { Some(&self.root) }
+}
+
+/// [`AssetIo`] of files in memory, that can be changed at runtime.
+#[derive(SmartDefault, Debug)]
+pub struct MemoryIo {
+    files: RwLock<HashMap<PathBuf, Vec<u8>>>,
+}
+
+impl MemoryIo {
+    /// Inserts a file by `path`.
+    /// If the file by `path`, was already present, it's replace.
+    pub fn insert(&self, path: impl Into<PathBuf>, bytes: Vec<u8>) {
+        self.files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .insert(path.into(), bytes);
+    }
+
+    /// Removes a file by `path` and returns it's bytes, if it's present.
+    pub fn remove(&self, path: impl AsRef<Path>) -> Option<Vec<u8>> {
+        self.files
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .remove(path.as_ref())
+    }
+}
+
+impl AssetIo for MemoryIo {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError> {
+        self.files
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(path)
+            .cloned()
+            .ok_or_else(|| {
+                AssetError::NotFound {
+                    path: path.to_path_buf(),
+                }
+            })
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool {
+        self.files
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .contains_key(path)
+    }
+}
+
+/// [`AssetIo`], that overlays layers in priority order.
+///
+/// A file is read from the layer with the highest priority, that contains it,
+/// so mods and patches can override files of the base game.
+/// Layers with the same priority are ordered by addition, the later one is preferred.
+///
+/// # Examples
+/// ```
+/// # use std::path::Path;
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let base = MemoryIo::default();
+/// base.insert("title.txt", b"Base".to_vec());
+/// base.insert("credits.txt", b"Authors".to_vec());
+///
+/// let patch = MemoryIo::default();
+/// patch.insert("title.txt", b"Mod".to_vec());
+///
+/// let io = OverlayIo::default()
+///     .with_layer(0, base)
+///     .with_layer(10, patch);
+///
+/// assert_eq!(io.read(Path::new("title.txt")).unwrap(), b"Mod");
+/// assert_eq!(io.read(Path::new("credits.txt")).unwrap(), b"Authors");
+/// ```
+#[derive(SmartDefault, Clone, Derivative)]
+#[derivative(Debug)]
+pub struct OverlayIo {
+    #[derivative(Debug = "ignore")]
+    layers: Vec<(i32, Arc<dyn AssetIo>)>,
+}
+
+impl OverlayIo {
+    /// Returns a [`OverlayIo`] with added `io` layer with `priority`.
+    pub fn with_layer(mut self, priority: i32, io: impl AssetIo) -> Self {
+        let index = self.layers.partition_point(|(p, _)| *p > priority);
+        self.layers.insert(index, (priority, Arc::new(io)));
+        self
+    }
+
+    /// Returns a layer with the highest priority, that contains the file by `path`.
+    fn layer(&self, path: &Path) -> Option<&Arc<dyn AssetIo>> {
+        self.layers
+            .iter()
+            .map(|(_, io)| io)
+            .find(|io| io.exists(path))
+    }
+}
+
+impl AssetIo for OverlayIo {
+    fn read(&self, path: &Path) -> Result<Vec<u8>, AssetError> {
+        match self.layer(path) {
+            | Some(io) => io.read(path),
+            | None => {
+                Err(AssetError::NotFound {
+                    path: path.to_path_buf(),
+                })
+            },
+        }
+    }
+
+    #[inline]
+    fn exists(&self, path: &Path) -> bool { self.layer(path).is_some() }
+
+    #[inline]
+    fn root(&self) -> Option<&Path> { self.layers.iter().find_map(|(_, io)| io.root()) }
+}
+
+/// Returns paths of files of the `dir` directory recursively, sorted by path.
+pub(crate) fn walk_dir(dir: &Path) -> Result<Vec<PathBuf>, AssetError> {
+    let mut files = Vec::new();
+    let mut dirs = vec![dir.to_path_buf()];
+    while let Some(current) = dirs.pop() {
+        let io_error = |error: std::io::Error| {
+            AssetError::Io {
+                path: current.clone(),
+                message: error.to_string(),
+            }
+        };
+        for entry in fs::read_dir(&current).map_err(io_error)? {
+            let path = entry.map_err(io_error)?.path();
+            match path.is_dir() {
+                | true => dirs.push(path),
+                | false => files.push(path),
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
@@ -23,19 +23,41 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod archive;
+pub mod asset;
+pub mod error;
+pub mod events;
+pub mod handle;
+pub mod io;
+pub mod loader;
+pub mod meta;
 pub mod plugins;
+pub mod processor;
 pub mod resources;
+pub mod server;
 pub mod stages;
 pub mod systems;
+#[cfg(all(feature = "watch", debug_assertions))]
+mod watch;
 
 pub mod prelude {
     //! Main asset functionality.
 
     #[doc(hidden)]
     pub use crate::{
-        plugins::AssetPlugin,
+        archive::{Archive, ArchiveBuilder, ArchiveCompression, ArchiveEntry},
+        asset::Asset,
+        error::AssetError,
+        events::AssetEvent,
+        handle::{Handle, HandleId},
+        io::{AssetIo, FileIo, MemoryIo, OverlayIo},
+        loader::{AssetLoader, BytesLoader, LoadContext, TextLoader},
+        meta::AssetMeta,
+        plugins::{AssetAppBuilderExt, AssetPlugin},
+        processor::{AssetPipeline, AssetProcessor, ProcessContext, ProcessReport},
         resources::{AssetResource, AssetResourceBuilder, AssetResourceBuilderError},
+        server::{AssetServer, LoadState},
         stages::{AssetStage, AssetStageGroup},
-        systems::AssetSystem,
+        systems::{AssetSystem, AssetUpdate},
     };
 }
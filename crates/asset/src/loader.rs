@@ -0,0 +1,231 @@
+//! Asset loader functionality.
+
+use std::{
+    any::{Any, TypeId},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use qinetic_utils::prelude::*;
+
+use crate::{
+    asset::Asset,
+    error::AssetError,
+    handle::{Handle, StrongHandle},
+    server::AssetServer,
+};
+
+/// Loader, that decodes [`Asset`] from bytes of the file with one of declared extensions.
+///
+/// Loaders are registered by [`AssetPlugin::with_loader`](crate::plugins::AssetPlugin::with_loader),
+/// [`AssetAppBuilderExt::with_asset_loader`](crate::plugins::AssetAppBuilderExt::with_asset_loader)
+/// or [`AssetServer::add_loader`], and selected by the extension and the requested asset type.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// struct Config {
+///     lines: Vec<String>,
+/// }
+///
+/// impl Asset for Config {}
+///
+/// struct ConfigLoader;
+///
+/// impl AssetLoader for ConfigLoader {
+///     type Asset = Config;
+///
+///     fn extensions(&self) -> &[&str] { &["cfg", "config.txt"] }
+///
+///     fn load(&self, bytes: Vec<u8>, context: &mut LoadContext) -> Result<Config, AssetError> {
+///         let text = String::from_utf8(bytes).map_err(|error| {
+///             AssetError::InvalidData {
+///                 reason: error.to_string(),
+///             }
+///         })?;
+///         Ok(Config {
+///             lines: text.lines().map(String::from).collect(),
+///         })
+///     }
+/// }
+///
+/// let server = AssetServer::new("assets");
+/// server.add_loader(ConfigLoader);
+/// ```
+pub trait AssetLoader: Send + Sync + 'static {
+    /// The [`Asset`], that the loader produces.
+    type Asset: Asset;
+
+    /// Returns file extensions without leading dot, that the loader supports.
+    ///
+    /// Extensions can contain dots, like `scene.ron`, the longest matching one is preferred.
+    fn extensions(&self) -> &[&str];
+
+    /// Decodes the [`Asset`] from `bytes` of the file.
+    ///
+    /// Dependent assets can be loaded by the `context`.
+    fn load(&self, bytes: Vec<u8>, context: &mut LoadContext) -> Result<Self::Asset, AssetError>;
+}
+
+/// [`AssetLoader`] of raw bytes, that is registered by default.
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct BytesLoader;
+
+impl AssetLoader for BytesLoader {
+    type Asset = Vec<u8>;
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { &["bin"] }
+
+    #[inline]
+    fn load(&self, bytes: Vec<u8>, _context: &mut LoadContext) -> Result<Vec<u8>, AssetError> {
+        Ok(bytes)
+    }
+}
+
+/// [`AssetLoader`] of UTF-8 text, that is registered by default.
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct TextLoader;
+
+impl AssetLoader for TextLoader {
+    type Asset = String;
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { &["txt"] }
+
+    fn load(&self, bytes: Vec<u8>, _context: &mut LoadContext) -> Result<String, AssetError> {
+        String::from_utf8(bytes).map_err(|error| {
+            AssetError::InvalidData {
+                reason: error.to_string(),
+            }
+        })
+    }
+}
+
+/// Type-erased [`AssetLoader`].
+pub(crate) trait DynAssetLoader: Send + Sync + 'static {
+    /// Returns a [`TypeId`] of the [`AssetLoader::Asset`].
+    fn asset_type(&self) -> TypeId;
+
+    /// Returns file extensions of the [`AssetLoader`].
+    fn extensions(&self) -> &[&str];
+
+    /// Decodes the [`AssetLoader::Asset`] and erases it's type.
+    fn load(
+        &self,
+        bytes: Vec<u8>,
+        context: &mut LoadContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, AssetError>;
+}
+
+impl<T: AssetLoader> DynAssetLoader for T {
+    #[inline]
+    fn asset_type(&self) -> TypeId { TypeId::of::<T::Asset>() }
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { AssetLoader::extensions(self) }
+
+    fn load(
+        &self,
+        bytes: Vec<u8>,
+        context: &mut LoadContext,
+    ) -> Result<Arc<dyn Any + Send + Sync>, AssetError> {
+        AssetLoader::load(self, bytes, context).map(|asset| Arc::new(asset) as _)
+    }
+}
+
+/// Returns a length of the longest of `extensions`, that `path` ends with.
+pub(crate) fn match_extension(extensions: &[&str], path: &Path) -> Option<usize> {
+    let name = path.file_name()?.to_str()?.to_lowercase();
+    extensions
+        .iter()
+        .filter(|extension| {
+            name.strip_suffix(&extension.to_lowercase())
+                .is_some_and(|stem| stem.ends_with('.'))
+        })
+        .map(|extension| extension.len())
+        .max()
+}
+
+/// Context of the [`AssetLoader::load`], that loads dependent assets.
+///
+/// The asset stays in [`LoadState::Loading`](crate::server::LoadState::Loading),
+/// until all of it's dependencies are loaded, and fails if one of them fails.
+///
+/// # Examples
+/// ```
+/// # use std::{fs, thread, time::Duration};
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// struct Material {
+///     texture: Handle<Vec<u8>>,
+/// }
+///
+/// impl Asset for Material {}
+///
+/// struct MaterialLoader;
+///
+/// impl AssetLoader for MaterialLoader {
+///     type Asset = Material;
+///
+///     fn extensions(&self) -> &[&str] { &["material"] }
+///
+///     fn load(&self, bytes: Vec<u8>, context: &mut LoadContext) -> Result<Material, AssetError> {
+///         let texture = String::from_utf8_lossy(&bytes).trim().to_string();
+///         Ok(Material {
+///             texture: context.load(texture),
+///         })
+///     }
+/// }
+///
+/// let root = std::env::temp_dir().join("qinetic_asset_loader");
+/// fs::create_dir_all(&root).unwrap();
+/// fs::write(root.join("brick.material"), "brick.bin").unwrap();
+/// fs::write(root.join("brick.bin"), [1, 2, 3]).unwrap();
+///
+/// let server = AssetServer::new(&root);
+/// server.add_loader(MaterialLoader);
+///
+/// let material = server.load::<Material>("brick.material");
+/// while server.load_state(&material) == LoadState::Loading {
+///     thread::sleep(Duration::from_millis(1));
+///     server.update();
+/// }
+///
+/// let texture = server.get(&material).unwrap().texture.clone();
+/// assert_eq!(server.load_state(&texture), LoadState::Loaded);
+/// assert_eq!(server.dependencies(&material), vec![texture.id()]);
+/// ```
+#[derive(Getters)]
+pub struct LoadContext {
+    /// The path of the loading asset relative to the root.
+    #[getset(get = "pub")]
+    path: PathBuf,
+
+    server: AssetServer,
+
+    dependencies: Vec<Arc<StrongHandle>>,
+}
+
+impl LoadContext {
+    #[inline]
+    pub(crate) fn new(path: PathBuf, server: AssetServer) -> Self {
+        Self {
+            path,
+            server,
+            dependencies: Vec::new(),
+        }
+    }
+
+    /// Loads a dependent [`Asset`] by `path` relative to the root and returns it's [`Handle`].
+    pub fn load<T: Asset>(&mut self, path: impl AsRef<Path>) -> Handle<T> {
+        let handle = self.server.load::<T>(path);
+        self.dependencies.push(handle.inner().clone());
+        handle
+    }
+
+    #[inline]
+    pub(crate) fn into_dependencies(self) -> Vec<Arc<StrongHandle>> { self.dependencies }
+}
@@ -0,0 +1,101 @@
+//! Asset meta file functionality.
+
+use std::{
+    collections::BTreeMap,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use qinetic_utils::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::error::AssetError;
+
+/// Extension of the meta file, that is appended to the path of the source asset.
+pub const META_EXTENSION: &str = "meta";
+
+/// Import settings of the source asset, that are stored in the meta file next to it.
+///
+/// The meta file of `textures/brick.png` is `textures/brick.png.meta` in RON format:
+/// ```ron
+/// (
+///     settings: {
+///         "mipmaps": "true",
+///     },
+/// )
+/// ```
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let meta = AssetMeta::default().with_setting("mipmaps", true);
+/// let meta = AssetMeta::from_bytes(meta.to_string().as_bytes()).unwrap();
+///
+/// assert_eq!(meta.setting::<bool>("mipmaps"), Some(true));
+/// assert_eq!(meta.setting::<u32>("mipmaps"), None);
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AssetMeta {
+    /// Settings of the [`AssetProcessor`](crate::processor::AssetProcessor) by name.
+    #[serde(default)]
+    settings: BTreeMap<String, String>,
+}
+
+impl AssetMeta {
+    /// Returns a [`AssetMeta`] with the `name` setting.
+    /// If the setting was already present, it's replace.
+    pub fn with_setting(mut self, name: impl Into<String>, value: impl ToString) -> Self {
+        self.settings.insert(name.into(), value.to_string());
+        self
+    }
+
+    /// Returns a value of the `name` setting, if it's present and parses to `T`.
+    pub fn setting<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.settings.get(name).and_then(|value| value.parse().ok())
+    }
+
+    /// Returns an iterator over names and values of the settings.
+    pub fn settings(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.settings
+            .iter()
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+    }
+
+    /// Returns a [`AssetMeta`] with settings of `defaults`, that are absent.
+    pub(crate) fn with_defaults(mut self, defaults: &AssetMeta) -> Self {
+        for (name, value) in &defaults.settings {
+            self.settings
+                .entry(name.clone())
+                .or_insert_with(|| value.clone());
+        }
+        self
+    }
+
+    /// Decodes the [`AssetMeta`] from `bytes` of the meta file.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, AssetError> {
+        ron::de::from_bytes(bytes).map_err(|error| {
+            AssetError::InvalidData {
+                reason: error.to_string(),
+            }
+        })
+    }
+
+    /// Returns a path of the meta file of the source asset by `path`.
+    pub fn path(path: impl AsRef<Path>) -> PathBuf {
+        let mut name = OsString::from(path.as_ref().as_os_str());
+        name.push(".");
+        name.push(META_EXTENSION);
+        PathBuf::from(name)
+    }
+}
+
+impl std::fmt::Display for AssetMeta {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let text = ron::ser::to_string_pretty(self, PrettyConfig::default())
+            .map_err(|_| std::fmt::Error)?;
+        writeln!(f, "{text}")
+    }
+}
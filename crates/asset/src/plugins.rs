@@ -1,12 +1,20 @@
 //! Asset [`Plugin`]s functionality.
 
+use std::sync::Arc;
+
 use qinetic_app::prelude::*;
 #[allow(unused_imports)]
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
+#[allow(unused_imports)]
+use crate::events::AssetEvent;
 use crate::{
+    archive::Archive,
+    io::{AssetIo, FileIo, OverlayIo},
+    loader::{AssetLoader, DynAssetLoader},
     resources::AssetResource,
+    server::AssetServer,
     stages::{AssetStage, AssetStageGroup},
     systems::AssetSystem,
 };
@@ -18,6 +26,10 @@ use crate::{
 ///
 /// [`Resource`]s:
 /// * [`AssetResource`]
+/// * [`AssetServer`]
+///
+/// [`Event`]s:
+/// * [`AssetEvent`]
 ///
 /// [`System`]s:
 /// * [`AssetSystem`]
@@ -32,14 +44,165 @@ use crate::{
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug)]
-pub struct AssetPlugin {}
+#[derive(SmartDefault, Clone, Derivative)]
+#[derivative(Debug)]
+pub struct AssetPlugin {
+    resource: AssetResource,
+
+    #[derivative(Debug = "ignore")]
+    loaders: Vec<Arc<dyn DynAssetLoader>>,
+
+    #[derivative(Debug = "ignore")]
+    io: Option<Arc<dyn AssetIo>>,
+}
+
+impl AssetPlugin {
+    /// Returns a [`AssetPlugin`] with [`AssetResource`], that configures the [`AssetServer`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// App::builder()
+    ///     .with_plugin(
+    ///         AssetPlugin::default().with_resource(
+    ///             AssetResourceBuilder::default()
+    ///                 .with_root("content".into())
+    ///                 .build()
+    ///                 .unwrap(),
+    ///         ),
+    ///     )
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_resource(mut self, resource: AssetResource) -> Self {
+        self.resource = resource;
+        self
+    }
+
+    /// Returns a [`AssetPlugin`] with [`AssetIo`], that replaces the root directory and archives of [`AssetResource`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// App::builder()
+    ///     .with_plugin(AssetPlugin::default().with_io(MemoryIo::default()))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_io(mut self, io: impl AssetIo) -> Self {
+        self.io = Some(Arc::new(io));
+        self
+    }
+
+    /// Returns a [`AssetIo`] of the root directory and archives, if [`AssetIo`] isn't set.
+    fn io(&self) -> Arc<dyn AssetIo> {
+        if let Some(io) = &self.io {
+            return io.clone();
+        }
+        if self.resource.archives().is_empty() {
+            return Arc::new(FileIo::new(self.resource.root()));
+        }
+
+        let mut io = OverlayIo::default().with_layer(0, FileIo::new(self.resource.root()));
+        for (priority, path) in self.resource.archives().iter().enumerate() {
+            match Archive::open(path) {
+                | Ok(archive) => io = io.with_layer(priority as i32 + 1, archive),
+                | Err(error) => eprintln!("warning: {error}"),
+            }
+        }
+        Arc::new(io)
+    }
+
+    /// Returns a [`AssetPlugin`] with added [`AssetLoader`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// App::builder()
+    ///     .with_plugin(AssetPlugin::default().with_loader(TextLoader))
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    #[inline]
+    pub fn with_loader<T: AssetLoader>(mut self, loader: T) -> Self {
+        self.loaders.push(Arc::new(loader));
+        self
+    }
+}
 
 impl Plugin for AssetPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
+        let server = AssetServer::with_io(self.io());
+        if let Some(existing) = app_builder.world().get_resource::<AssetServer>() {
+            existing
+                .loaders()
+                .into_iter()
+                .for_each(|loader| server.add_dyn_loader(loader));
+        }
+        self.loaders
+            .iter()
+            .for_each(|loader| server.add_dyn_loader(loader.clone()));
+
+        #[cfg(feature = "watch")]
+        if *self.resource.hot_reload() {
+            if let Err(error) = server.watch() {
+                eprintln!("warning: {error}");
+            }
+        }
+
         app_builder
-            .with_resource(AssetResource::default())
+            .with_reflect_resource(self.resource.clone())
+            .with_resource(server)
             .with_stage_group(AssetStageGroup::default())
             .with_system(AssetStage::default(), AssetSystem::default());
     }
 }
+
+/// Extension of the [`AppBuilder`], that registers [`AssetLoader`]s of other [`Plugin`]s.
+pub trait AssetAppBuilderExt {
+    /// Returns a [`AppBuilder`] with added [`AssetLoader`] to the [`AssetServer`].
+    ///
+    /// If the [`AssetServer`] isn't present, it's added with `default` root,
+    /// and it's loaders are moved to the [`AssetServer`] of the [`AssetPlugin`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_app::prelude::*;
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// struct MyPlugin;
+    ///
+    /// impl Plugin for MyPlugin {
+    ///     fn build(&mut self, app_builder: &mut AppBuilder) {
+    ///         app_builder.with_asset_loader(TextLoader);
+    ///     }
+    /// }
+    ///
+    /// App::builder()
+    ///     .with_plugin(AssetPlugin::default())
+    ///     .with_plugin(MyPlugin)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    fn with_asset_loader<T: AssetLoader>(&mut self, loader: T) -> &mut Self;
+}
+
+impl AssetAppBuilderExt for AppBuilder {
+    fn with_asset_loader<T: AssetLoader>(&mut self, loader: T) -> &mut Self {
+        if !self.world().has_resource::<AssetServer>() {
+            self.with_resource(AssetServer::default());
+        }
+        if let Some(server) = self.world().get_resource::<AssetServer>() {
+            server.add_loader(loader);
+        }
+        self
+    }
+}
@@ -0,0 +1,396 @@
+//! Asset processing functionality.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fs,
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use qinetic_utils::prelude::*;
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::AssetError,
+    io::walk_dir,
+    loader::match_extension,
+    meta::{AssetMeta, META_EXTENSION},
+};
+
+/// Name of the content-hash cache file in the output directory.
+pub const CACHE_FILE: &str = ".qinetic_cache.ron";
+
+/// Processor, that turns the source asset into it's runtime-optimized form,
+/// like mipmapped textures, deduplicated meshes or pre-parsed fonts.
+///
+/// Processors are registered by [`AssetPipeline::with_processor`],
+/// and selected by the extension of the source asset.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// #
+/// struct ShoutProcessor;
+///
+/// impl AssetProcessor for ShoutProcessor {
+///     fn extensions(&self) -> &[&str] { &["txt"] }
+///
+///     fn default_meta(&self) -> AssetMeta { AssetMeta::default().with_setting("repeat", 1) }
+///
+///     fn process(&self, bytes: Vec<u8>, context: &ProcessContext) -> Result<Vec<u8>, AssetError> {
+///         let repeat = context.meta().setting::<usize>("repeat").unwrap_or(1);
+///         Ok(bytes.to_ascii_uppercase().repeat(repeat))
+///     }
+/// }
+/// ```
+pub trait AssetProcessor: Send + Sync + 'static {
+    /// Returns file extensions without leading dot, that the processor supports.
+    ///
+    /// Extensions can contain dots, like `scene.ron`, the longest matching one is preferred.
+    fn extensions(&self) -> &[&str];
+
+    /// Returns a name of the processor, that is a part of the content hash.
+    #[inline]
+    fn name(&self) -> &str { std::any::type_name::<Self>() }
+
+    /// Returns a version of the processor, that is a part of the content hash.
+    ///
+    /// Increasing it reprocesses all assets of the processor.
+    #[inline]
+    fn version(&self) -> u32 { 0 }
+
+    /// Returns a [`AssetMeta`] with default import settings, that are used, if the source asset has no meta file.
+    ///
+    /// The meta file with them can be written by [`AssetPipeline::write_meta`].
+    #[inline]
+    fn default_meta(&self) -> AssetMeta { AssetMeta::default() }
+
+    /// Processes `bytes` of the source asset to bytes of the runtime asset.
+    fn process(&self, bytes: Vec<u8>, context: &ProcessContext) -> Result<Vec<u8>, AssetError>;
+}
+
+/// Context of the [`AssetProcessor::process`].
+#[derive(Getters, Clone, Debug)]
+pub struct ProcessContext {
+    /// The path of the source asset relative to the source directory.
+    #[getset(get = "pub")]
+    path: PathBuf,
+
+    /// The import settings of the source asset.
+    #[getset(get = "pub")]
+    meta: AssetMeta,
+}
+
+/// Report of the [`AssetPipeline::run`].
+#[derive(SmartDefault, Getters, Clone, Debug)]
+pub struct ProcessReport {
+    /// The paths of processed assets.
+    #[getset(get = "pub")]
+    processed: Vec<PathBuf>,
+
+    /// The paths of assets, that are skipped, because they are unchanged.
+    #[getset(get = "pub")]
+    skipped: Vec<PathBuf>,
+
+    /// The paths of assets, that are failed to process, with their errors.
+    #[getset(get = "pub")]
+    failed: Vec<(PathBuf, AssetError)>,
+
+    /// The paths of outputs, that are removed, because their source assets are deleted.
+    #[getset(get = "pub")]
+    removed: Vec<PathBuf>,
+}
+
+impl ProcessReport {
+    /// Returns `true`, if no asset is failed to process.
+    #[inline]
+    pub fn is_success(&self) -> bool { self.failed.is_empty() }
+}
+
+/// Pipeline, that processes the source directory into the output directory
+/// by registered [`AssetProcessor`]s.
+///
+/// Assets without a processor are copied as is.
+/// The content hash of each asset, it's meta file and it's processor is cached in [`CACHE_FILE`],
+/// so unchanged assets are skipped on the next run, and outputs of deleted assets are removed.
+/// The source directory is only read, meta files are written by [`AssetPipeline::write_meta`].
+///
+/// The pipeline doesn't need a window or GPU, so it can run from a build script, a test or CI.
+///
+/// # Examples
+/// ```
+/// # use std::fs;
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// struct ShoutProcessor;
+///
+/// impl AssetProcessor for ShoutProcessor {
+///     fn extensions(&self) -> &[&str] { &["txt"] }
+///
+///     fn process(
+///         &self,
+///         bytes: Vec<u8>,
+///         _context: &ProcessContext,
+///     ) -> Result<Vec<u8>, AssetError> {
+///         Ok(bytes.to_ascii_uppercase())
+///     }
+/// }
+///
+/// let root = std::env::temp_dir().join("qinetic_asset_processor");
+/// # let _ = fs::remove_dir_all(&root);
+/// fs::create_dir_all(root.join("source")).unwrap();
+/// fs::write(root.join("source/title.txt"), "Hello").unwrap();
+/// fs::write(root.join("source/data.bin"), [1, 2, 3]).unwrap();
+///
+/// let pipeline =
+///     AssetPipeline::new(root.join("source"), root.join("output")).with_processor(ShoutProcessor);
+///
+/// let report = pipeline.run().unwrap();
+/// assert_eq!(report.processed().len(), 2);
+/// assert_eq!(fs::read(root.join("output/title.txt")).unwrap(), b"HELLO");
+/// assert!(!root.join("source/title.txt.meta").exists());
+///
+/// let report = pipeline.run().unwrap();
+/// assert!(report.processed().is_empty());
+/// assert_eq!(report.skipped().len(), 2);
+///
+/// fs::remove_file(root.join("source/data.bin")).unwrap();
+///
+/// let report = pipeline.run().unwrap();
+/// assert_eq!(
+///     report.removed(),
+///     &vec![std::path::PathBuf::from("data.bin")]
+/// );
+/// assert!(!root.join("output/data.bin").exists());
+/// ```
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub struct AssetPipeline {
+    source: PathBuf,
+    output: PathBuf,
+
+    #[derivative(Debug = "ignore")]
+    processors: Vec<Arc<dyn AssetProcessor>>,
+}
+
+impl AssetPipeline {
+    /// Returns a [`AssetPipeline`] from the `source` directory to the `output` directory.
+    pub fn new(source: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            source: source.into(),
+            output: output.into(),
+            processors: Vec::new(),
+        }
+    }
+
+    /// Returns a [`AssetPipeline`] with added `processor`.
+    pub fn with_processor(mut self, processor: impl AssetProcessor) -> Self {
+        self.processors.push(Arc::new(processor));
+        self
+    }
+
+    /// Returns a [`AssetProcessor`] with the longest extension, that `path` ends with.
+    fn processor(&self, path: &Path) -> Option<&Arc<dyn AssetProcessor>> {
+        self.processors
+            .iter()
+            .filter_map(|processor| {
+                Some((match_extension(processor.extensions(), path)?, processor))
+            })
+            .max_by_key(|(length, _)| *length)
+            .map(|(_, processor)| processor)
+    }
+
+    /// Processes changed assets of the source directory and returns a [`ProcessReport`].
+    ///
+    /// Failures of single assets are collected to the report, so others are still processed.
+    pub fn run(&self) -> Result<ProcessReport, AssetError> {
+        let cache_path = self.output.join(CACHE_FILE);
+        let cache = fs::read(&cache_path)
+            .ok()
+            .and_then(|bytes| ron::de::from_bytes::<BTreeMap<String, String>>(&bytes).ok())
+            .unwrap_or_default();
+
+        let mut report = ProcessReport::default();
+        let mut hashes = BTreeMap::new();
+        let mut keys = BTreeSet::new();
+        for source in self.sources()? {
+            let path = source
+                .strip_prefix(&self.source)
+                .unwrap_or(&source)
+                .to_path_buf();
+            let key = path.to_string_lossy().replace('\\', "/");
+            keys.insert(key.clone());
+
+            let hash = match self.hash(&source, &path) {
+                | Ok(hash) => hash,
+                | Err(error) => {
+                    report.failed.push((path, error));
+                    continue;
+                },
+            };
+            let output = self.output.join(&path);
+            if cache.get(&key) == Some(&hash) && output.is_file() {
+                hashes.insert(key, hash);
+                report.skipped.push(path);
+                continue;
+            }
+
+            match self.process(&source, &path, &output) {
+                | Ok(()) => {
+                    hashes.insert(key, hash);
+                    report.processed.push(path);
+                },
+                | Err(error) => report.failed.push((path, error)),
+            }
+        }
+
+        for key in cache.keys().filter(|key| !keys.contains(*key)) {
+            let path = PathBuf::from(key);
+            let output = self.output.join(&path);
+            if output.is_file() {
+                fs::remove_file(&output).map_err(|error| io_error(&path, error))?;
+                report.removed.push(path);
+            }
+        }
+
+        let text = ron::ser::to_string_pretty(&hashes, Default::default()).map_err(|error| {
+            AssetError::InvalidData {
+                reason: error.to_string(),
+            }
+        })?;
+        fs::create_dir_all(&self.output)
+            .and_then(|_| fs::write(&cache_path, text))
+            .map_err(|error| {
+                AssetError::Io {
+                    path: cache_path,
+                    message: error.to_string(),
+                }
+            })?;
+        Ok(report)
+    }
+
+    /// Writes meta files with [`AssetProcessor::default_meta`] next to the source assets,
+    /// that have a processor, but no meta file, and returns their paths.
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::fs;
+    /// #
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// struct RepeatProcessor;
+    ///
+    /// impl AssetProcessor for RepeatProcessor {
+    ///     fn extensions(&self) -> &[&str] { &["txt"] }
+    ///
+    ///     fn default_meta(&self) -> AssetMeta { AssetMeta::default().with_setting("repeat", 2) }
+    ///
+    ///     fn process(&self, bytes: Vec<u8>, context: &ProcessContext) -> Result<Vec<u8>, AssetError> {
+    ///         Ok(bytes.repeat(context.meta().setting::<usize>("repeat").unwrap_or(1)))
+    ///     }
+    /// }
+    ///
+    /// let root = std::env::temp_dir().join("qinetic_asset_meta");
+    /// # let _ = fs::remove_dir_all(&root);
+    /// fs::create_dir_all(root.join("source")).unwrap();
+    /// fs::write(root.join("source/title.txt"), "Hi").unwrap();
+    ///
+    /// let pipeline = AssetPipeline::new(root.join("source"), root.join("output"))
+    ///     .with_processor(RepeatProcessor);
+    ///
+    /// assert_eq!(
+    ///     pipeline.write_meta().unwrap(),
+    ///     vec![root.join("source/title.txt.meta")]
+    /// );
+    /// assert!(pipeline.write_meta().unwrap().is_empty());
+    ///
+    /// pipeline.run().unwrap();
+    /// assert_eq!(fs::read(root.join("output/title.txt")).unwrap(), b"HiHi");
+    /// ```
+    pub fn write_meta(&self) -> Result<Vec<PathBuf>, AssetError> {
+        let mut written = Vec::new();
+        for source in self.sources()? {
+            let path = source.strip_prefix(&self.source).unwrap_or(&source);
+            let Some(processor) = self.processor(path) else {
+                continue;
+            };
+            let meta_path = AssetMeta::path(&source);
+            if meta_path.is_file() {
+                continue;
+            }
+            fs::write(&meta_path, processor.default_meta().to_string())
+                .map_err(|error| io_error(&meta_path, error))?;
+            written.push(meta_path);
+        }
+        Ok(written)
+    }
+
+    /// Returns paths of the source assets without meta files.
+    fn sources(&self) -> Result<Vec<PathBuf>, AssetError> {
+        Ok(walk_dir(&self.source)?
+            .into_iter()
+            .filter(|source| {
+                source
+                    .extension()
+                    .is_none_or(|extension| extension != META_EXTENSION)
+            })
+            .collect())
+    }
+
+    /// Returns a [`AssetMeta`] of the `source` asset,
+    /// or the default one of the `processor`, if it has no meta file.
+    fn meta(&self, source: &Path, processor: &dyn AssetProcessor) -> Result<AssetMeta, AssetError> {
+        let meta_path = AssetMeta::path(source);
+        let defaults = processor.default_meta();
+        match meta_path.is_file() {
+            | true => {
+                let bytes = fs::read(&meta_path).map_err(|error| io_error(&meta_path, error))?;
+                Ok(AssetMeta::from_bytes(&bytes)?.with_defaults(&defaults))
+            },
+            | false => Ok(defaults),
+        }
+    }
+
+    /// Returns a hex SHA-256 of the `source` asset, it's meta file and it's processor.
+    fn hash(&self, source: &Path, path: &Path) -> Result<String, AssetError> {
+        let mut hasher = Sha256::new();
+        hasher.update(fs::read(source).map_err(|error| io_error(path, error))?);
+        if let Some(processor) = self.processor(path) {
+            hasher.update(processor.name());
+            hasher.update(processor.version().to_le_bytes());
+            hasher.update(self.meta(source, &**processor)?.to_string());
+        }
+        Ok(hasher
+            .finalize()
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect())
+    }
+
+    /// Processes the `source` asset by `path` to the `output` file.
+    fn process(&self, source: &Path, path: &Path, output: &Path) -> Result<(), AssetError> {
+        let bytes = fs::read(source).map_err(|error| io_error(path, error))?;
+        let bytes = match self.processor(path) {
+            | Some(processor) => {
+                let context = ProcessContext {
+                    path: path.to_path_buf(),
+                    meta: self.meta(source, &**processor)?,
+                };
+                processor.process(bytes, &context)?
+            },
+            | None => bytes,
+        };
+        if let Some(parent) = output.parent() {
+            fs::create_dir_all(parent).map_err(|error| io_error(path, error))?;
+        }
+        fs::write(output, bytes).map_err(|error| io_error(path, error))
+    }
+}
+
+fn io_error(path: &Path, error: std::io::Error) -> AssetError {
+    AssetError::Io {
+        path: path.to_path_buf(),
+        message: error.to_string(),
+    }
+}
@@ -5,6 +5,8 @@ use qinetic_utils::prelude::*;
 
 /// Asset [`Resource`].
 ///
+/// Configures the [`AssetServer`](crate::server::AssetServer), that is added by the [`AssetPlugin`](crate::plugins::AssetPlugin).
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
@@ -15,7 +17,7 @@ use qinetic_utils::prelude::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource)]
+#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -23,4 +25,15 @@ use qinetic_utils::prelude::*;
     default,
     derive(Debug, PartialEq, Eq)
 )]
-pub struct AssetResource {}
+pub struct AssetResource {
+    /// The root directory, that assets are loaded relative to.
+    #[default = "assets"]
+    root: String,
+
+    /// The paths of archives, that overlay the root directory, the later one has higher priority.
+    archives: Vec<String>,
+
+    /// Whether changed files are reloaded, available only in debug builds with `watch` feature.
+    #[default(cfg!(debug_assertions))]
+    hot_reload: bool,
+}
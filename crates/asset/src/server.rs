@@ -0,0 +1,707 @@
+//! Asset server functionality.
+
+use std::{
+    any::{Any, TypeId},
+    collections::{HashMap, HashSet},
+    num::NonZeroUsize,
+    path::{Path, PathBuf},
+    sync::{
+        atomic::{AtomicUsize, Ordering},
+        mpsc::{self, Receiver, Sender},
+        Arc,
+        Mutex,
+        MutexGuard,
+        OnceLock,
+        PoisonError,
+        RwLock,
+        Weak,
+    },
+    thread,
+    time::Duration,
+};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+
+#[cfg(all(feature = "watch", debug_assertions))]
+use crate::watch::AssetWatcher;
+use crate::{
+    asset::Asset,
+    error::AssetError,
+    events::AssetEvent,
+    handle::{Handle, HandleId, StrongHandle},
+    io::{AssetIo, FileIo},
+    loader::{match_extension, AssetLoader, BytesLoader, DynAssetLoader, LoadContext, TextLoader},
+};
+
+/// Load state of the asset.
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq)]
+pub enum LoadState {
+    /// The asset isn't known by the [`AssetServer`], or it's dropped.
+    #[default]
+    NotLoaded,
+
+    /// The asset or it's dependencies are loading on a background thread.
+    Loading,
+
+    /// The asset is loaded and can be accessed.
+    Loaded,
+
+    /// The asset loading failed.
+    Failed(AssetError),
+}
+
+/// Server, that loads [`Asset`]s from the [`AssetIo`] on background threads.
+///
+/// Loads are queued to a pool of at most [`AssetServer::MAX_LOAD_THREADS`] threads, that is shared by all clones.
+/// The same path can be loaded as different asset types, each of them has it's own [`Handle`].
+///
+/// [`AssetServer::new`] reads files from the root directory, [`AssetServer::with_io`] reads them from
+/// archives, memory, or overlay of them.
+///
+/// Files are decoded by the [`AssetLoader`], that is registered for the file extension and the requested asset type.
+/// [`BytesLoader`] and [`TextLoader`] are registered by default.
+///
+/// The [`AssetServer`] is cheap to clone, all clones share the same assets.
+/// Loaded assets, dropped [`Handle`]s and [`AssetEvent`]s are processed by [`AssetServer::update`],
+/// that is run each frame by the [`AssetSystem`](crate::systems::AssetSystem).
+///
+/// # Examples
+/// ```
+/// # use std::{fs, thread, time::Duration};
+/// #
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let root = std::env::temp_dir().join("qinetic_asset_server");
+/// fs::create_dir_all(&root).unwrap();
+/// fs::write(root.join("greeting.txt"), "Hello").unwrap();
+///
+/// let server = AssetServer::new(&root);
+/// let handle = server.load::<String>("greeting.txt");
+/// assert_eq!(server.load::<String>("greeting.txt"), handle);
+///
+/// while server.load_state(&handle) == LoadState::Loading {
+///     thread::sleep(Duration::from_millis(1));
+///     server.update();
+/// }
+///
+/// assert_eq!(server.load_state(&handle), LoadState::Loaded);
+/// assert_eq!(server.get(&handle).as_deref(), Some(&String::from("Hello")));
+///
+/// let missing = server.load::<String>("missing.txt");
+/// while server.load_state(&missing) == LoadState::Loading {
+///     thread::sleep(Duration::from_millis(1));
+///     server.update();
+/// }
+///
+/// assert!(matches!(
+///     server.load_state(&missing),
+///     LoadState::Failed(AssetError::Io { .. })
+/// ));
+/// ```
+#[derive(SmartDefault, Clone, Resource)]
+pub struct AssetServer {
+    #[default(Arc::new(AssetServerInner::new(Arc::new(FileIo::new("assets")))))]
+    inner: Arc<AssetServerInner>,
+}
+
+struct AssetServerInner {
+    io: Arc<dyn AssetIo>,
+    next_id: AtomicUsize,
+    drop_sender: Sender<HandleId>,
+    load_sender: Sender<LoadMessage>,
+    tasks: OnceLock<Sender<LoadTask>>,
+    loaders: RwLock<Vec<Arc<dyn DynAssetLoader>>>,
+    state: Mutex<AssetServerState>,
+}
+
+struct AssetServerState {
+    drop_receiver: Receiver<HandleId>,
+    load_receiver: Receiver<LoadMessage>,
+    paths: HashMap<(PathBuf, TypeId), HandleId>,
+    entries: HashMap<HandleId, AssetEntry>,
+    pending_events: Vec<AssetEvent>,
+    events: Vec<AssetEvent>,
+    #[cfg(all(feature = "watch", debug_assertions))]
+    watcher: Option<AssetWatcher>,
+}
+
+struct AssetEntry {
+    path: Option<PathBuf>,
+    state: LoadState,
+    asset: Option<Arc<dyn Any + Send + Sync>>,
+    pending: Option<Arc<dyn Any + Send + Sync>>,
+    dependencies: Vec<Arc<StrongHandle>>,
+    handle: Weak<StrongHandle>,
+}
+
+impl AssetEntry {
+    fn new(path: Option<PathBuf>, state: LoadState, handle: Weak<StrongHandle>) -> Self {
+        Self {
+            path,
+            state,
+            asset: None,
+            pending: None,
+            dependencies: Vec::new(),
+            handle,
+        }
+    }
+}
+
+/// Task, that loads a asset on a thread of the [`AssetServer`].
+type LoadTask = Box<dyn FnOnce() + Send>;
+
+/// Loaded asset and strong handles of it's dependencies.
+type Loaded = (Arc<dyn Any + Send + Sync>, Vec<Arc<StrongHandle>>);
+
+struct LoadMessage {
+    id: HandleId,
+    result: Result<Loaded, AssetError>,
+}
+
+impl AssetServerInner {
+    fn new(io: Arc<dyn AssetIo>) -> Self {
+        let (drop_sender, drop_receiver) = mpsc::channel();
+        let (load_sender, load_receiver) = mpsc::channel();
+        Self {
+            io,
+            next_id: AtomicUsize::new(0),
+            drop_sender,
+            load_sender,
+            tasks: OnceLock::new(),
+            loaders: RwLock::new(vec![Arc::new(BytesLoader), Arc::new(TextLoader)]),
+            state: Mutex::new(AssetServerState {
+                drop_receiver,
+                load_receiver,
+                paths: HashMap::new(),
+                entries: HashMap::new(),
+                pending_events: Vec::new(),
+                events: Vec::new(),
+                #[cfg(all(feature = "watch", debug_assertions))]
+                watcher: None,
+            }),
+        }
+    }
+}
+
+impl AssetServer {
+    /// The maximum count of threads, that load assets.
+    pub const MAX_LOAD_THREADS: usize = 4;
+    /// The delay, that changes of the same file are merged within by [`AssetServer::watch`].
+    pub const WATCH_DELAY: Duration = Duration::from_millis(200);
+
+    /// Returns a [`AssetServer`], that loads assets relative to `root` directory.
+    #[inline]
+    pub fn new(root: impl Into<PathBuf>) -> Self { Self::with_io(FileIo::new(root)) }
+
+    /// Returns a [`AssetServer`], that loads assets from `io`.
+    #[inline]
+    pub fn with_io(io: impl AssetIo) -> Self {
+        Self {
+            inner: Arc::new(AssetServerInner::new(Arc::new(io))),
+        }
+    }
+
+    /// Returns a [`AssetIo`] of the [`AssetServer`].
+    #[inline]
+    pub fn io(&self) -> &dyn AssetIo { &*self.inner.io }
+
+    /// Adds a [`AssetLoader`].
+    /// Loaders, that are added later, are preferred for the same extension and asset type.
+    pub fn add_loader<T: AssetLoader>(&self, loader: T) { self.add_dyn_loader(Arc::new(loader)); }
+
+    #[inline]
+    pub(crate) fn add_dyn_loader(&self, loader: Arc<dyn DynAssetLoader>) {
+        self.inner
+            .loaders
+            .write()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(loader);
+    }
+
+    #[inline]
+    pub(crate) fn loaders(&self) -> Vec<Arc<dyn DynAssetLoader>> {
+        self.inner
+            .loaders
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    /// Returns a [`AssetLoader`] of `T`, that supports `path`.
+    fn loader_for(&self, asset_type: TypeId, path: &Path) -> Option<Arc<dyn DynAssetLoader>> {
+        self.loaders()
+            .into_iter()
+            .rev()
+            .filter(|loader| loader.asset_type() == asset_type)
+            .filter_map(|loader| Some((match_extension(loader.extensions(), path)?, loader)))
+            .max_by_key(|(len, _)| *len)
+            .map(|(_, loader)| loader)
+    }
+
+    /// Loads a [`Asset`] by `path` relative to the root on a background thread and returns it's [`Handle`].
+    ///
+    /// If the asset by `path` and `T` was already loaded and it's still alive, returns the same [`Handle`].
+    /// If the asset and it's dependencies wait for each other, it's failed with [`AssetError::DependencyCycle`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::{fs, thread, time::Duration};
+    /// #
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// struct Node {
+    ///     next: Handle<Node>,
+    /// }
+    ///
+    /// impl Asset for Node {}
+    ///
+    /// struct NodeLoader;
+    ///
+    /// impl AssetLoader for NodeLoader {
+    ///     type Asset = Node;
+    ///
+    ///     fn extensions(&self) -> &[&str] { &["node"] }
+    ///
+    ///     fn load(&self, bytes: Vec<u8>, context: &mut LoadContext) -> Result<Node, AssetError> {
+    ///         let next = String::from_utf8_lossy(&bytes).trim().to_string();
+    ///         Ok(Node {
+    ///             next: context.load(next),
+    ///         })
+    ///     }
+    /// }
+    ///
+    /// struct NextLoader;
+    ///
+    /// impl AssetLoader for NextLoader {
+    ///     type Asset = String;
+    ///
+    ///     fn extensions(&self) -> &[&str] { &["node"] }
+    ///
+    ///     fn load(&self, bytes: Vec<u8>, _context: &mut LoadContext) -> Result<String, AssetError> {
+    ///         Ok(String::from_utf8_lossy(&bytes).trim().to_string())
+    ///     }
+    /// }
+    ///
+    /// let root = std::env::temp_dir().join("qinetic_asset_load");
+    /// fs::create_dir_all(&root).unwrap();
+    /// fs::write(root.join("a.node"), "b.node").unwrap();
+    /// fs::write(root.join("b.node"), "a.node").unwrap();
+    ///
+    /// let server = AssetServer::new(&root);
+    /// server.add_loader(NodeLoader);
+    /// server.add_loader(NextLoader);
+    ///
+    /// let next = server.load::<String>("a.node");
+    /// let node = server.load::<Node>("a.node");
+    /// while [next.id(), node.id()]
+    ///     .into_iter()
+    ///     .any(|id| server.load_state(id) == LoadState::Loading)
+    /// {
+    ///     thread::sleep(Duration::from_millis(1));
+    ///     server.update();
+    /// }
+    ///
+    /// assert_eq!(server.load::<String>("a.node"), next);
+    /// assert_eq!(server.get(&next).as_deref(), Some(&String::from("b.node")));
+    /// assert!(matches!(
+    ///     server.load_state(&node),
+    ///     LoadState::Failed(AssetError::DependencyCycle { .. })
+    /// ));
+    /// ```
+    pub fn load<T: Asset>(&self, path: impl AsRef<Path>) -> Handle<T> {
+        let path = path.as_ref().to_path_buf();
+        let key = (path.clone(), TypeId::of::<T>());
+        let mut state = self.state();
+
+        if let Some(entry) = state.paths.get(&key).and_then(|id| state.entries.get(id)) {
+            if let Some(inner) = entry.handle.upgrade() {
+                return Handle::new(inner);
+            }
+        }
+
+        let (handle, id) = self.new_handle::<T>();
+        let Some(loader) = self.loader_for(TypeId::of::<T>(), &path) else {
+            let error = AssetError::NoLoader { path: path.clone() };
+            let entry = AssetEntry::new(
+                Some(path),
+                LoadState::Failed(error),
+                Arc::downgrade(handle.inner()),
+            );
+            state.entries.insert(id, entry);
+            return handle;
+        };
+
+        state.paths.insert(key, id);
+        let entry = AssetEntry::new(
+            Some(path.clone()),
+            LoadState::Loading,
+            Arc::downgrade(handle.inner()),
+        );
+        state.entries.insert(id, entry);
+        drop(state);
+
+        self.spawn_load(id, path, loader);
+        handle
+    }
+
+    /// Loads a asset by `id` from `path` by `loader` on a thread of the pool.
+    fn spawn_load(&self, id: HandleId, path: PathBuf, loader: Arc<dyn DynAssetLoader>) {
+        let server = self.clone();
+        let task: LoadTask = Box::new(move || {
+            let mut context = LoadContext::new(path.clone(), server.clone());
+            let result = server
+                .inner
+                .io
+                .read(&path)
+                .and_then(|bytes| loader.load(bytes, &mut context))
+                .map(|asset| (asset, context.into_dependencies()));
+            server
+                .inner
+                .load_sender
+                .send(LoadMessage { id, result })
+                .ok();
+        });
+        self.inner
+            .tasks
+            .get_or_init(Self::spawn_threads)
+            .send(task)
+            .ok();
+    }
+
+    /// Spawns threads of the pool, that run [`LoadTask`]s until the returned sender is dropped.
+    fn spawn_threads() -> Sender<LoadTask> {
+        let (sender, receiver) = mpsc::channel::<LoadTask>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let count = thread::available_parallelism()
+            .map_or(1, NonZeroUsize::get)
+            .min(Self::MAX_LOAD_THREADS);
+
+        for _ in 0..count {
+            let receiver = receiver.clone();
+            thread::spawn(move || {
+                loop {
+                    let task = receiver
+                        .lock()
+                        .unwrap_or_else(PoisonError::into_inner)
+                        .recv();
+                    match task {
+                        | Ok(task) => task(),
+                        | Err(_) => break,
+                    }
+                }
+            });
+        }
+        sender
+    }
+
+    /// Starts watching the root directory of the [`AssetIo`], changed assets are reloaded by [`AssetServer::update`].
+    ///
+    /// Reloaded assets are swapped behind existing [`Handle`]s and [`AssetEvent::Modified`] is sent.
+    /// Hot reload is available only in debug builds with `watch` feature, otherwise returns [`AssetError::Watch`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use std::{
+    /// #     fs, thread,
+    /// #     time::{Duration, Instant},
+    /// # };
+    /// #
+    /// # use qinetic_asset::prelude::*;
+    /// #
+    /// let root = std::env::temp_dir().join("qinetic_asset_watch");
+    /// fs::create_dir_all(&root).unwrap();
+    /// fs::write(root.join("config.txt"), "old").unwrap();
+    ///
+    /// let server = AssetServer::new(&root);
+    /// let handle = server.load::<String>("config.txt");
+    /// while server.load_state(&handle) == LoadState::Loading {
+    ///     thread::sleep(Duration::from_millis(1));
+    ///     server.update();
+    /// }
+    ///
+    /// if server.watch().is_ok() {
+    ///     fs::write(root.join("config.txt"), "new").unwrap();
+    ///
+    ///     let start = Instant::now();
+    ///     while !server
+    ///         .events::<String>()
+    ///         .contains(&AssetEvent::Modified { id: handle.id() })
+    ///     {
+    ///         assert!(start.elapsed() < Duration::from_secs(10));
+    ///         thread::sleep(Duration::from_millis(10));
+    ///         server.update();
+    ///     }
+    ///
+    ///     assert_eq!(server.get(&handle).as_deref(), Some(&String::from("new")));
+    /// }
+    /// ```
+    pub fn watch(&self) -> Result<(), AssetError> {
+        #[cfg(all(feature = "watch", debug_assertions))]
+        {
+            let root = self.inner.io.root().ok_or_else(|| {
+                AssetError::Watch {
+                    reason: "not a directory on disk".into(),
+                }
+            })?;
+            let watcher = AssetWatcher::new(root.to_path_buf(), Self::WATCH_DELAY)?;
+            self.state().watcher = Some(watcher);
+            Ok(())
+        }
+
+        #[cfg(not(all(feature = "watch", debug_assertions)))]
+        Err(AssetError::Watch {
+            reason: "disabled, hot reload requires debug build with `watch` feature".into(),
+        })
+    }
+
+    /// Returns `true`, if the root directory is watched for changes.
+    pub fn is_watching(&self) -> bool {
+        #[cfg(all(feature = "watch", debug_assertions))]
+        return self.state().watcher.is_some();
+
+        #[cfg(not(all(feature = "watch", debug_assertions)))]
+        false
+    }
+
+    /// Adds a loaded [`Asset`] and returns it's [`Handle`].
+    pub fn add<T: Asset>(&self, asset: T) -> Handle<T> {
+        let (handle, id) = self.new_handle::<T>();
+        let mut state = self.state();
+        let mut entry = AssetEntry::new(None, LoadState::Loaded, Arc::downgrade(handle.inner()));
+        entry.asset = Some(Arc::new(asset));
+        state.entries.insert(id, entry);
+        state.pending_events.push(AssetEvent::Created { id });
+        handle
+    }
+
+    /// Replaces a [`Asset`] by `handle`, existing [`Handle`]s point to the new asset.
+    pub fn set<T: Asset>(&self, handle: &Handle<T>, asset: T) {
+        let id = handle.id();
+        let mut state = self.state();
+        let Some(entry) = state.entries.get_mut(&id) else {
+            return;
+        };
+        let event = match entry.asset.replace(Arc::new(asset)) {
+            | Some(_) => AssetEvent::Modified { id },
+            | None => AssetEvent::Created { id },
+        };
+        entry.state = LoadState::Loaded;
+        state.pending_events.push(event);
+    }
+
+    /// Returns a [`Asset`] by `handle`, if it's loaded.
+    pub fn get<T: Asset>(&self, handle: &Handle<T>) -> Option<Arc<T>> {
+        let state = self.state();
+        let asset = state.entries.get(&handle.id())?.asset.clone()?;
+        asset.downcast::<T>().ok()
+    }
+
+    /// Returns a [`LoadState`] of the asset by `id`.
+    pub fn load_state(&self, id: impl Into<HandleId>) -> LoadState {
+        self.state()
+            .entries
+            .get(&id.into())
+            .map(|entry| entry.state.clone())
+            .unwrap_or_default()
+    }
+
+    /// Returns a path of the asset by `id`, if it's loaded from a file.
+    pub fn path(&self, id: impl Into<HandleId>) -> Option<PathBuf> {
+        self.state().entries.get(&id.into())?.path.clone()
+    }
+
+    /// Returns [`HandleId`]s of assets, that are loaded by the [`LoadContext`] of the asset by `id`.
+    pub fn dependencies(&self, id: impl Into<HandleId>) -> Vec<HandleId> {
+        self.state()
+            .entries
+            .get(&id.into())
+            .map(|entry| entry.dependencies.iter().map(|handle| handle.id).collect())
+            .unwrap_or_default()
+    }
+
+    /// Returns [`AssetEvent`]s of `T` assets, that are sent during the last [`AssetServer::update`].
+    pub fn events<T: Asset>(&self) -> Vec<AssetEvent> {
+        self.state()
+            .events
+            .iter()
+            .filter(|event| event.id().is::<T>())
+            .copied()
+            .collect()
+    }
+
+    /// Processes loaded assets, dropped [`Handle`]s and changed files, and sends [`AssetEvent`]s.
+    pub fn update(&self) {
+        let mut guard = self.state();
+        let state = &mut *guard;
+
+        #[cfg(all(feature = "watch", debug_assertions))]
+        if let Some(watcher) = &state.watcher {
+            for path in watcher.changed() {
+                let ids = state
+                    .paths
+                    .iter()
+                    .filter(|((p, _), _)| *p == path)
+                    .map(|(_, id)| *id)
+                    .collect::<Vec<_>>();
+                for id in ids {
+                    let Some(loader) = self.loader_for(id.asset_type(), &path) else {
+                        continue;
+                    };
+                    if let Some(entry) = state.entries.get_mut(&id) {
+                        if !matches!(entry.state, LoadState::Loaded) {
+                            entry.state = LoadState::Loading;
+                        }
+                        self.spawn_load(id, path.clone(), loader);
+                    }
+                }
+            }
+        }
+
+        while let Ok(LoadMessage { id, result }) = state.load_receiver.try_recv() {
+            let Some(entry) = state.entries.get_mut(&id) else {
+                continue;
+            };
+            match result {
+                | Ok((asset, dependencies)) => {
+                    entry.pending = Some(asset);
+                    entry.dependencies = dependencies;
+                },
+                | Err(error) => entry.state = LoadState::Failed(error),
+            }
+        }
+
+        state.resolve();
+
+        while let Ok(id) = state.drop_receiver.try_recv() {
+            let Some(entry) = state.entries.remove(&id) else {
+                continue;
+            };
+            if let Some(path) = entry.path {
+                let key = (path, id.asset_type());
+                if state.paths.get(&key) == Some(&id) {
+                    state.paths.remove(&key);
+                }
+            }
+            if entry.asset.is_some() {
+                state.pending_events.push(AssetEvent::Removed { id });
+            }
+        }
+
+        state.events = std::mem::take(&mut state.pending_events);
+    }
+
+    fn new_handle<T: Asset>(&self) -> (Handle<T>, HandleId) {
+        let id = HandleId::new::<T>(self.inner.next_id.fetch_add(1, Ordering::Relaxed));
+        let handle = Handle::new(Arc::new(StrongHandle {
+            id,
+            sender: self.inner.drop_sender.clone(),
+        }));
+        (handle, id)
+    }
+
+    #[inline]
+    fn state(&self) -> MutexGuard<'_, AssetServerState> {
+        self.inner
+            .state
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl AssetServerState {
+    /// Commits pending assets, that dependencies are loaded, and fails ones, that dependencies failed
+    /// or wait for each other through a cycle.
+    fn resolve(&mut self) {
+        loop {
+            let resolved = self
+                .entries
+                .iter()
+                .filter(|(_, entry)| entry.pending.is_some())
+                .filter_map(|(id, entry)| Some((*id, self.dependencies_result(entry)?)))
+                .collect::<Vec<_>>();
+            if resolved.is_empty() {
+                break;
+            }
+
+            for (id, result) in resolved {
+                let entry = self.entries.get_mut(&id).unwrap();
+                let asset = entry.pending.take().unwrap();
+                match result {
+                    | Ok(()) => {
+                        let event = match entry.asset.replace(asset) {
+                            | Some(_) => AssetEvent::Modified { id },
+                            | None => AssetEvent::Created { id },
+                        };
+                        entry.state = LoadState::Loaded;
+                        self.pending_events.push(event);
+                    },
+                    | Err(error) => entry.state = LoadState::Failed(error),
+                }
+            }
+        }
+        self.fail_cycles();
+    }
+
+    /// Fails pending assets, that can't be committed, because their dependencies wait for each other.
+    ///
+    /// The pending asset can be committed, only if one of it's dependencies is still loading
+    /// or can be committed itself.
+    fn fail_cycles(&mut self) {
+        let mut stuck = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.pending.is_some())
+            .map(|(id, _)| *id)
+            .collect::<HashSet<_>>();
+
+        loop {
+            let free = stuck
+                .iter()
+                .copied()
+                .filter(|id| {
+                    self.entries[id].dependencies.iter().any(|dependency| {
+                        !stuck.contains(&dependency.id)
+                            && self
+                                .entries
+                                .get(&dependency.id)
+                                .is_some_and(|entry| entry.state == LoadState::Loading)
+                    })
+                })
+                .collect::<Vec<_>>();
+            if free.is_empty() {
+                break;
+            }
+            for id in free {
+                stuck.remove(&id);
+            }
+        }
+
+        for id in stuck {
+            let entry = self.entries.get_mut(&id).unwrap();
+            entry.pending = None;
+            entry.dependencies.clear();
+            entry.state = LoadState::Failed(AssetError::DependencyCycle {
+                path: entry.path.clone().unwrap_or_default(),
+            });
+        }
+    }
+
+    /// Returns a result of dependencies of `entry`, if all of them are loaded or one of them failed.
+    fn dependencies_result(&self, entry: &AssetEntry) -> Option<Result<(), AssetError>> {
+        for dependency in &entry.dependencies {
+            let dependency = self.entries.get(&dependency.id)?;
+            match &dependency.state {
+                | LoadState::Loaded => {},
+                | LoadState::Failed(error) => {
+                    return Some(Err(AssetError::Dependency {
+                        path: dependency.path.clone().unwrap_or_default(),
+                        reason: error.to_string(),
+                    }));
+                },
+                | _ => return None,
+            }
+        }
+        Some(Ok(()))
+    }
+}
@@ -3,8 +3,12 @@
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
+use crate::server::AssetServer;
+
 /// Asset [`System`].
 ///
+/// Updates the [`AssetServer`] each frame.
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
@@ -20,8 +24,50 @@ use qinetic_utils::prelude::*;
 pub struct AssetSystem {}
 
 impl System for AssetSystem {
-    type Data = ();
+    type Data = AssetUpdate;
+
+    #[inline]
+    fn run(&mut self, data: Self::Data) { data.update(); }
+}
+
+/// [`SystemData`], that updates the [`AssetServer`] resource.
+///
+/// If the resource isn't present, nothing is updated.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_asset::prelude::*;
+/// #
+/// let mut world = World::default();
+/// AssetSystem::default().run_world(&mut world);
+///
+/// assert!(!world.has_resource::<AssetServer>());
+/// ```
+#[derive(Clone)]
+pub struct AssetUpdate {
+    server: Option<AssetServer>,
+}
+
+impl AssetUpdate {
+    /// Updates the [`AssetServer`], if it's present.
+    #[inline]
+    pub fn update(self) {
+        if let Some(server) = self.server {
+            server.update();
+        }
+    }
+}
+
+impl SystemData for AssetUpdate {
+    fn access(access: &mut SystemAccess) { access.write_resource::<AssetServer>(); }
 
-    #[allow(unused_variables)]
-    fn run(&mut self, data: Self::Data) {}
+    #[inline]
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            server: world
+                .get_resource::<AssetServer>()
+                .map(|server| server.clone()),
+        }
+    }
 }
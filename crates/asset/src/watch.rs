@@ -0,0 +1,62 @@
+//! Asset watch functionality.
+
+use std::{
+    fmt::Display,
+    path::PathBuf,
+    sync::mpsc::{self, Receiver},
+    time::Duration,
+};
+
+use notify_debouncer_mini::{
+    new_debouncer,
+    notify::{RecommendedWatcher, RecursiveMode},
+    DebounceEventResult,
+    Debouncer,
+};
+
+use crate::error::AssetError;
+
+/// Debounced watcher of the root directory.
+pub(crate) struct AssetWatcher {
+    root: PathBuf,
+    receiver: Receiver<DebounceEventResult>,
+    _debouncer: Debouncer<RecommendedWatcher>,
+}
+
+impl AssetWatcher {
+    /// Returns a [`AssetWatcher`], that watches `root` recursively and merges changes within `delay`.
+    pub(crate) fn new(root: PathBuf, delay: Duration) -> Result<Self, AssetError> {
+        let root = root.canonicalize().map_err(watch_error)?;
+        let (sender, receiver) = mpsc::channel();
+        let mut debouncer = new_debouncer(delay, sender).map_err(watch_error)?;
+        debouncer
+            .watcher()
+            .watch(&root, RecursiveMode::Recursive)
+            .map_err(watch_error)?;
+        Ok(Self {
+            root,
+            receiver,
+            _debouncer: debouncer,
+        })
+    }
+
+    /// Returns paths relative to the root, that are changed since the last call.
+    pub(crate) fn changed(&self) -> Vec<PathBuf> {
+        let mut paths = self
+            .receiver
+            .try_iter()
+            .filter_map(Result::ok)
+            .flatten()
+            .filter_map(|event| Some(event.path.strip_prefix(&self.root).ok()?.to_path_buf()))
+            .collect::<Vec<_>>();
+        paths.sort();
+        paths.dedup();
+        paths
+    }
+}
+
+fn watch_error(error: impl Display) -> AssetError {
+    AssetError::Watch {
+        reason: error.to_string(),
+    }
+}
@@ -0,0 +1,78 @@
+//! Audio clip loading.
+
+use qinetic_asset::prelude::*;
+use qinetic_utils::prelude::*;
+
+/// Encoding format of the [`AudioClip`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AudioFormat {
+    /// The RIFF WAVE format.
+    Wav,
+
+    /// The Ogg Vorbis format.
+    Ogg,
+
+    /// The FLAC format.
+    Flac,
+
+    /// The MPEG-1 Audio Layer III format.
+    Mp3,
+}
+
+impl AudioFormat {
+    /// Returns a [`AudioFormat`] by signature of `bytes`, if it's supported.
+    pub fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        match bytes {
+            | [b'R', b'I', b'F', b'F', _, _, _, _, b'W', b'A', b'V', b'E', ..] => Some(Self::Wav),
+            | [b'O', b'g', b'g', b'S', ..] => Some(Self::Ogg),
+            | [b'f', b'L', b'a', b'C', ..] => Some(Self::Flac),
+            | [b'I', b'D', b'3', ..] | [0xFF, 0xE0..=0xFF, ..] => Some(Self::Mp3),
+            | _ => None,
+        }
+    }
+}
+
+/// Audio clip [`Asset`], that contains encoded bytes of the audio file.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+pub struct AudioClip {
+    /// The encoding format of the clip.
+    #[getset(get_copy = "pub")]
+    format: AudioFormat,
+
+    /// The encoded bytes of the clip.
+    #[getset(get = "pub")]
+    bytes: Vec<u8>,
+}
+
+impl Asset for AudioClip {}
+
+/// [`AssetLoader`] of [`AudioClip`], that is registered by the [`AudioPlugin`](crate::plugins::AudioPlugin).
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// # use qinetic_audio::prelude::*;
+/// #
+/// let server = AssetServer::new("assets");
+/// server.add_loader(AudioClipLoader);
+///
+/// let handle = server.load::<AudioClip>("sounds/jump.ogg");
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct AudioClipLoader;
+
+impl AssetLoader for AudioClipLoader {
+    type Asset = AudioClip;
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { &["wav", "ogg", "flac", "mp3"] }
+
+    fn load(&self, bytes: Vec<u8>, _context: &mut LoadContext) -> Result<AudioClip, AssetError> {
+        let format = AudioFormat::from_bytes(&bytes).ok_or_else(|| {
+            AssetError::InvalidData {
+                reason: "unknown audio signature".into(),
+            }
+        })?;
+        Ok(AudioClip { format, bytes })
+    }
+}
@@ -10,12 +10,9 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_audio::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Sound::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Sound>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Sound {}
 
 /// Listener [`Component`].
@@ -25,10 +22,7 @@ pub struct Sound {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_audio::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Listener::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Listener>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Listener {}
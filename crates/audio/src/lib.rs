@@ -23,6 +23,7 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod clip;
 pub mod components;
 pub mod plugins;
 pub mod stages;
@@ -33,6 +34,7 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
+        clip::{AudioClip, AudioClipLoader, AudioFormat},
         components::{Listener, Sound},
         plugins::AudioPlugin,
         stages::{AudioStage, AudioStageGroup},
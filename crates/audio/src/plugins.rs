@@ -1,11 +1,13 @@
 //! Audio [`Plugin`]s functionality.
 
 use qinetic_app::prelude::*;
+use qinetic_asset::prelude::*;
 #[allow(unused_imports)]
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 use crate::{
+    clip::AudioClipLoader,
     components::{Listener, Sound},
     stages::{AudioStage, AudioStageGroup},
     systems::AudioSystem,
@@ -23,6 +25,9 @@ use crate::{
 /// [`System`]s:
 /// * [`AudioSystem`]
 ///
+/// [`AssetLoader`]s:
+/// * [`AudioClipLoader`]
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
@@ -39,9 +44,10 @@ pub struct AudioPlugin {}
 impl Plugin for AudioPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(Listener::default())
-            .with_component(Sound::default())
+            .with_component::<Listener>()
+            .with_component::<Sound>()
             .with_stage_group(AudioStageGroup::default())
-            .with_system(AudioStage::default(), AudioSystem::default());
+            .with_system(AudioStage::default(), AudioSystem::default())
+            .with_asset_loader(AudioClipLoader);
     }
 }
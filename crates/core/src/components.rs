@@ -10,12 +10,9 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_core::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Tag::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Tag>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Tag {
     pub tag: String,
 }
@@ -27,12 +24,9 @@ pub struct Tag {
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_core::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Script::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Script>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Script {
     pub name: String,
 }
@@ -39,8 +39,8 @@ pub struct CorePlugin {}
 impl Plugin for CorePlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(Tag::default())
-            .with_component(Script::default())
+            .with_component::<Tag>()
+            .with_component::<Script>()
             .with_stage_group(CoreStageGroup::default())
             .with_system(CoreStage::default(), CoreSystem::default());
     }
@@ -0,0 +1,153 @@
+//! Access functionality.
+
+use std::any::{type_name, TypeId};
+
+use qinetic_utils::prelude::*;
+
+use crate::{component::Component, resource::Resource};
+
+/// Identificator for a type, that [`System`](crate::system::System) accesses.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct AccessId {
+    #[getset(skip)]
+    type_id: TypeId,
+
+    /// The name of the type.
+    name: &'static str,
+}
+
+impl AccessId {
+    /// Returns a [`AccessId`] of `T`.
+    #[inline]
+    pub fn of<T: 'static>() -> Self {
+        Self {
+            type_id: TypeId::of::<T>(),
+            name: type_name::<T>(),
+        }
+    }
+}
+
+/// [`Resource`]s and [`Component`]s, that [`System`](crate::system::System) reads and writes.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Resource)]
+/// struct Time;
+///
+/// #[derive(Default, Component)]
+/// struct Position;
+///
+/// let mut movement = SystemAccess::default();
+/// movement
+///     .read_resource::<Time>()
+///     .write_component::<Position>();
+///
+/// let mut render = SystemAccess::default();
+/// render.read_component::<Position>();
+///
+/// assert!(!movement.is_compatible(&render));
+/// assert_eq!(
+///     movement.conflicts(&render),
+///     vec![AccessId::of::<Position>()]
+/// );
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct SystemAccess {
+    /// [`Resource`]s, that only read.
+    resource_reads: Vec<AccessId>,
+
+    /// [`Resource`]s, that write.
+    resource_writes: Vec<AccessId>,
+
+    /// [`Component`]s, that only read.
+    component_reads: Vec<AccessId>,
+
+    /// [`Component`]s, that write.
+    component_writes: Vec<AccessId>,
+}
+
+impl SystemAccess {
+    /// Adds a read of [`Resource`] by `T`.
+    #[inline]
+    pub fn read_resource<T: Resource>(&mut self) -> &mut Self {
+        Self::insert(&mut self.resource_reads, AccessId::of::<T>());
+        self
+    }
+
+    /// Adds a write of [`Resource`] by `T`.
+    #[inline]
+    pub fn write_resource<T: Resource>(&mut self) -> &mut Self {
+        Self::insert(&mut self.resource_writes, AccessId::of::<T>());
+        self
+    }
+
+    /// Adds a read of [`Component`] by `T`.
+    #[inline]
+    pub fn read_component<T: Component>(&mut self) -> &mut Self {
+        Self::insert(&mut self.component_reads, AccessId::of::<T>());
+        self
+    }
+
+    /// Adds a write of [`Component`] by `T`.
+    #[inline]
+    pub fn write_component<T: Component>(&mut self) -> &mut Self {
+        Self::insert(&mut self.component_writes, AccessId::of::<T>());
+        self
+    }
+
+    /// Returns `true`, if nothing is accessed.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.resource_reads.is_empty()
+            && self.resource_writes.is_empty()
+            && self.component_reads.is_empty()
+            && self.component_writes.is_empty()
+    }
+
+    /// Returns `true`, if both accesses can run in any order.
+    #[inline]
+    pub fn is_compatible(&self, other: &SystemAccess) -> bool { self.conflicts(other).is_empty() }
+
+    /// Returns types, that one access writes and another one reads or writes.
+    pub fn conflicts(&self, other: &SystemAccess) -> Vec<AccessId> {
+        let mut conflicts = Vec::new();
+
+        for (writes, reads, other_writes, other_reads) in [
+            (
+                &self.resource_writes,
+                &self.resource_reads,
+                &other.resource_writes,
+                &other.resource_reads,
+            ),
+            (
+                &self.component_writes,
+                &self.component_reads,
+                &other.component_writes,
+                &other.component_reads,
+            ),
+        ] {
+            for id in writes {
+                if other_writes.contains(id) || other_reads.contains(id) {
+                    Self::insert(&mut conflicts, *id);
+                }
+            }
+            for id in reads {
+                if other_writes.contains(id) {
+                    Self::insert(&mut conflicts, *id);
+                }
+            }
+        }
+
+        conflicts
+    }
+
+    fn insert(ids: &mut Vec<AccessId>, id: AccessId) {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+}
@@ -0,0 +1,99 @@
+//! Bundle functionality.
+
+use crate::{
+    component::{Component, ComponentId, ComponentRegistry},
+    entity::EntityId,
+};
+
+/// Group of [`Component`]s, that adds to and removes from `Entity` together.
+///
+/// Implemented for every [`Component`] and tuples of [`Bundle`]s, so [`Bundle`]s can be nested.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Component)]
+/// struct Health(u32);
+///
+/// #[derive(Default, Component)]
+/// struct Armor(u32);
+///
+/// #[derive(Default, Bundle)]
+/// struct UnitBundle {
+///     health: Health,
+///     armor: Armor,
+/// }
+///
+/// let mut world = World::default();
+/// let entity_id = world.spawn(UnitBundle::default());
+///
+/// assert!(world.has_component::<Health>(entity_id));
+/// assert!(world.has_component::<Armor>(entity_id));
+///
+/// world.remove_bundle::<UnitBundle>(entity_id);
+///
+/// assert!(!world.has_component::<Health>(entity_id));
+/// ```
+pub trait Bundle: Send + Sync + 'static {
+    /// Registers [`Component`]s of the [`Bundle`] and adds their [`ComponentId`]s to `ids`.
+    fn register(registry: &mut ComponentRegistry, ids: &mut Vec<ComponentId>);
+
+    /// Adds [`Component`]s of the [`Bundle`] to `Entity` by [`EntityId`].
+    fn insert(self, registry: &mut ComponentRegistry, entity_id: EntityId);
+
+    /// Removes [`Component`]s of the [`Bundle`] from `Entity` by [`EntityId`], if they're present.
+    fn remove(registry: &mut ComponentRegistry, entity_id: EntityId);
+}
+
+impl<T: Component> Bundle for T {
+    #[inline]
+    fn register(registry: &mut ComponentRegistry, ids: &mut Vec<ComponentId>) {
+        ids.push(registry.register_component::<T>());
+    }
+
+    #[inline]
+    fn insert(self, registry: &mut ComponentRegistry, entity_id: EntityId) {
+        registry.add_component(entity_id, self);
+    }
+
+    #[inline]
+    fn remove(registry: &mut ComponentRegistry, entity_id: EntityId) {
+        registry.remove_component::<T>(entity_id);
+    }
+}
+
+macro_rules! impl_bundle_for_tuple {
+    ($($name: ident),*) => {
+        impl<$($name: Bundle),*> Bundle for ($($name,)*) {
+            #[allow(unused_variables)]
+            #[inline]
+            fn register(registry: &mut ComponentRegistry, ids: &mut Vec<ComponentId>) {
+                $($name::register(registry, ids);)*
+            }
+
+            #[allow(non_snake_case, unused_variables)]
+            #[inline]
+            fn insert(self, registry: &mut ComponentRegistry, entity_id: EntityId) {
+                let ($($name,)*) = self;
+                $($name.insert(registry, entity_id);)*
+            }
+
+            #[allow(unused_variables)]
+            #[inline]
+            fn remove(registry: &mut ComponentRegistry, entity_id: EntityId) {
+                $($name::remove(registry, entity_id);)*
+            }
+        }
+    };
+}
+
+impl_bundle_for_tuple!();
+impl_bundle_for_tuple!(A);
+impl_bundle_for_tuple!(A, B);
+impl_bundle_for_tuple!(A, B, C);
+impl_bundle_for_tuple!(A, B, C, D);
+impl_bundle_for_tuple!(A, B, C, D, E);
+impl_bundle_for_tuple!(A, B, C, D, E, F);
+impl_bundle_for_tuple!(A, B, C, D, E, F, G);
+impl_bundle_for_tuple!(A, B, C, D, E, F, G, H);
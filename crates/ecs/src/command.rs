@@ -0,0 +1,86 @@
+//! Command functionality.
+
+use std::{
+    fmt::{self, Debug, Formatter},
+    mem,
+    sync::{Arc, Mutex, PoisonError},
+};
+
+use crate::{system::SystemData, world::World};
+
+/// Deferred change of the [`World`].
+pub type Command = Box<dyn FnOnce(&mut World) + Send + Sync>;
+
+/// Queue of [`Command`]s, that are applied to the [`World`] after the [`System`](crate::system::System) runs.
+///
+/// [`Commands`] are fetched by the [`System`](crate::system::System), so it can change the [`World`] in `run`,
+/// while it's data doesn't borrow the [`World`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Resource)]
+/// struct Score(u32);
+///
+/// struct ScoreSystem;
+///
+/// impl System for ScoreSystem {
+///     type Data = Commands;
+///
+///     fn run(&mut self, commands: Self::Data) {
+///         commands.add(|world| {
+///             world.add_resource(Score(10));
+///         });
+///     }
+/// }
+///
+/// let mut world = World::default();
+/// ScoreSystem.run_world(&mut world);
+///
+/// assert_eq!(world.get_resource::<Score>().unwrap().0, 10);
+/// ```
+#[derive(Clone, Default)]
+pub struct Commands {
+    commands: Arc<Mutex<Vec<Command>>>,
+}
+
+impl Commands {
+    /// Adds a [`Command`] to the end of the queue.
+    pub fn add(&self, command: impl FnOnce(&mut World) + Send + Sync + 'static) {
+        self.commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(Box::new(command));
+    }
+
+    /// Returns a number of queued [`Command`]s.
+    pub fn len(&self) -> usize {
+        self.commands
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Returns `true`, if no [`Command`] is queued.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.len() == 0 }
+
+    /// Removes all [`Command`]s and returns them in order of addition.
+    pub(crate) fn take(&self) -> Vec<Command> {
+        mem::take(&mut *self.commands.lock().unwrap_or_else(PoisonError::into_inner))
+    }
+}
+
+impl Debug for Commands {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Commands")
+            .field("len", &self.len())
+            .finish()
+    }
+}
+
+impl SystemData for Commands {
+    #[inline]
+    fn fetch(world: &mut World) -> Self { world.commands() }
+}
@@ -1,12 +1,20 @@
 //! Component functionality.
 
+use std::{
+    any::{type_name, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+};
+
 use qinetic_utils::prelude::*;
 
-use crate::entity::EntityId;
-#[allow(unused_imports)]
-use crate::world::*;
+use crate::{
+    entity::EntityId,
+    error::WorldError,
+    storage::{Ref, RefMut, TypeCell},
+};
 
-/// Data conteiner of the [`World`].
+/// Data conteiner of the [`World`](crate::world::World).
 ///
 /// # Examples
 /// ```
@@ -19,7 +27,7 @@ use crate::world::*;
 /// ```
 pub trait Component: Send + Sync + 'static {}
 
-/// Identificator for [`Component`] within a [`World`].
+/// Identificator for [`Component`] within a [`World`](crate::world::World).
 #[derive(
     SmartDefault, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters, new,
 )]
@@ -29,29 +37,155 @@ pub struct ComponentId {
 }
 
 /// Facilities addition and remove [`Component`]s.
-#[derive(SmartDefault, Clone, Debug)]
-pub struct ComponentRegistry {}
+///
+/// Doesn't check `Entity`s for staleness, [`World`](crate::world::World) does.
+#[derive(SmartDefault, Debug)]
+pub struct ComponentRegistry {
+    storages: HashMap<TypeId, ComponentStorage>,
+}
+
+/// [`Component`]s of a single type by [`EntityId`].
+struct ComponentStorage {
+    id: ComponentId,
+
+    name: &'static str,
+
+    components: HashMap<EntityId, TypeCell>,
+}
 
 impl ComponentRegistry {
+    /// Registers a [`Component`] by `T` and returns it's [`ComponentId`].
+    ///
+    /// If the [`Component`] by `T`, was already registered, returns the present [`ComponentId`].
+    pub fn register_component<T: Component>(&mut self) -> ComponentId {
+        let id = ComponentId::new(self.storages.len());
+        self.storages
+            .entry(TypeId::of::<T>())
+            .or_insert_with(|| {
+                ComponentStorage {
+                    id,
+                    name: type_name::<T>(),
+                    components: HashMap::new(),
+                }
+            })
+            .id
+    }
+
+    /// Returns a [`ComponentId`] of [`Component`] by `T`, if it's registered.
+    #[inline]
+    pub fn component_id<T: Component>(&self) -> Option<ComponentId> {
+        self.storages.get(&TypeId::of::<T>()).map(|s| s.id)
+    }
+
+    /// Returns `true`, if [`Component`] by `T` registered.
+    #[inline]
+    pub fn is_registered<T: Component>(&self) -> bool {
+        self.storages.contains_key(&TypeId::of::<T>())
+    }
+
     /// Adds a [`Component`] to [`Entity`] dy [`EntityId`].
+    ///
+    /// If the [`Component`] by `T`, was already present, it's replace.
     #[inline]
-    pub fn add_component<T: Component>(&mut self, entity_id: EntityId) { todo!() }
+    pub fn add_component<T: Component>(&mut self, entity_id: EntityId, component: T) {
+        self.register_component::<T>();
+        self.storages
+            .get_mut(&TypeId::of::<T>())
+            .unwrap()
+            .components
+            .insert(entity_id, TypeCell::new(component));
+    }
 
     /// Removes a [`Component`] from [`Entity`] dy [`EntityId`], if it's present.
     #[inline]
-    pub fn remove_component<T: Component>(&mut self, entity_id: EntityId) { todo!() }
+    pub fn remove_component<T: Component>(&mut self, entity_id: EntityId) {
+        self.try_remove_component::<T>(entity_id).ok();
+    }
+
+    /// Removes a [`Component`] from [`Entity`] dy [`EntityId`] and returns it.
+    pub fn try_remove_component<T: Component>(
+        &mut self,
+        entity_id: EntityId,
+    ) -> Result<T, WorldError> {
+        self.storages
+            .get_mut(&TypeId::of::<T>())
+            .ok_or_else(Self::not_registered::<T>)?
+            .components
+            .remove(&entity_id)
+            .map(TypeCell::into_inner)
+            .ok_or_else(|| Self::missing::<T>(entity_id))
+    }
+
+    /// Removes all [`Component`]s of [`Entity`] by [`EntityId`].
+    pub fn remove_components(&mut self, entity_id: EntityId) {
+        for storage in self.storages.values_mut() {
+            storage.components.remove(&entity_id);
+        }
+    }
+
+    /// Returns a immutable [`Component`] of [`Entity`] dy [`EntityId`].
+    pub fn try_get_component<T: Component>(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Ref<'_, T>, WorldError> {
+        self.cell::<T>(entity_id)?.try_borrow()
+    }
 
-    /// Returns a immutable [`Component`] of [`Entity`] dy [`EntityId`], if it's present.
+    /// Returns a mutable [`Component`] of [`Entity`] dy [`EntityId`].
+    pub fn try_get_component_mut<T: Component>(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<RefMut<'_, T>, WorldError> {
+        self.cell::<T>(entity_id)?.try_borrow_mut()
+    }
+
+    /// Returns a immutable [`Component`] of [`Entity`] dy [`EntityId`], if it's present and not mutably borrowed.
     #[inline]
-    pub fn get_component<T: Component>(&self, entity_id: EntityId) -> Option<&T> { todo!() }
+    pub fn get_component<T: Component>(&self, entity_id: EntityId) -> Option<Ref<'_, T>> {
+        self.try_get_component::<T>(entity_id).ok()
+    }
 
-    /// Returns a mutable [`Component`] of [`Entity`] dy [`EntityId`], if it's present.
+    /// Returns a mutable [`Component`] of [`Entity`] dy [`EntityId`], if it's present and not borrowed.
     #[inline]
-    pub fn get_component_mut<T: Component>(&mut self, entity_id: EntityId) -> Option<&mut T> {
-        todo!()
+    pub fn get_component_mut<T: Component>(&self, entity_id: EntityId) -> Option<RefMut<'_, T>> {
+        self.try_get_component_mut::<T>(entity_id).ok()
     }
 
     /// Returns `true`, if [`Component`] of [`Entity`] by [`EntityId`] present.
     #[inline]
-    pub fn has_component<T: Component>(&self, entity_id: EntityId) -> bool { todo!() }
+    pub fn has_component<T: Component>(&self, entity_id: EntityId) -> bool {
+        self.cell::<T>(entity_id).is_ok()
+    }
+
+    fn cell<T: Component>(&self, entity_id: EntityId) -> Result<&TypeCell, WorldError> {
+        self.storages
+            .get(&TypeId::of::<T>())
+            .ok_or_else(Self::not_registered::<T>)?
+            .components
+            .get(&entity_id)
+            .ok_or_else(|| Self::missing::<T>(entity_id))
+    }
+
+    fn not_registered<T: Component>() -> WorldError {
+        WorldError::NotRegistered {
+            name: type_name::<T>(),
+        }
+    }
+
+    fn missing<T: Component>(entity_id: EntityId) -> WorldError {
+        WorldError::MissingComponent {
+            name: type_name::<T>(),
+            entity_id,
+        }
+    }
+}
+
+impl Debug for ComponentStorage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ComponentStorage")
+            .field("id", &self.id)
+            .field("name", &self.name)
+            .field("len", &self.components.len())
+            .finish()
+    }
 }
@@ -1,33 +1,157 @@
 //! Entity functionality.
 
+use std::{any::type_name, collections::HashMap};
+
 use qinetic_utils::prelude::*;
 
-#[allow(unused_imports)]
-use crate::world::*;
+use crate::{error::WorldError, reflect::Reflect};
 
-/// Identificator for `Entity` within a [`World`].
+/// Identificator for `Entity` within a [`World`](crate::world::World).
+///
+/// The `generation` changes on each reuse of the `id`, so [`EntityId`]s of removed `Entity`s become stale.
 #[derive(
     SmartDefault, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters, new,
 )]
 #[getset(get_copy = "pub")]
 pub struct EntityId {
     id: usize,
+
+    generation: u32,
 }
 
-/// Facilities addition and remove [`Entity`]s.
+/// Facilities addition and remove `Entity`s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// let mut entity_registry = EntityRegistry::default();
+///
+/// let entity_id = entity_registry.add_entity();
+/// entity_registry.remove_entity(entity_id);
+///
+/// assert!(!entity_registry.has_entity(entity_id));
+/// assert!(entity_registry.try_remove_entity(entity_id).is_err());
+/// ```
 #[derive(SmartDefault, Clone, Debug)]
-pub struct EntityRegistry {}
+pub struct EntityRegistry {
+    /// Current generations by `id`.
+    generations: Vec<u32>,
+
+    /// `true` by `id`, if the `Entity` is alive.
+    alive: Vec<bool>,
+
+    /// Ids of removed `Entity`s to reuse.
+    free: Vec<usize>,
+}
 
 impl EntityRegistry {
-    /// Returns a [`EntityId`].
+    /// Returns a [`EntityId`] of the new `Entity`.
     #[inline]
-    pub fn add_entity(&mut self) -> EntityId { EntityId::default() }
+    pub fn add_entity(&mut self) -> EntityId {
+        match self.free.pop() {
+            | Some(id) => {
+                self.alive[id] = true;
+                EntityId::new(id, self.generations[id])
+            },
+            | None => {
+                self.generations.push(0);
+                self.alive.push(true);
+                EntityId::new(self.alive.len() - 1, 0)
+            },
+        }
+    }
 
-    /// Removes a [`EntityId`].
+    /// Removes a [`EntityId`], if it's present.
     #[inline]
-    pub fn remove_entity(&mut self, id: EntityId) { todo!() }
+    pub fn remove_entity(&mut self, id: EntityId) { self.try_remove_entity(id).ok(); }
+
+    /// Removes a [`EntityId`].
+    pub fn try_remove_entity(&mut self, id: EntityId) -> Result<(), WorldError> {
+        if !self.has_entity(id) {
+            return Err(WorldError::StaleEntity {
+                name: type_name::<EntityId>(),
+                entity_id: id,
+            });
+        }
+
+        self.alive[id.id] = false;
+        self.generations[id.id] = self.generations[id.id].wrapping_add(1);
+        self.free.push(id.id);
+        Ok(())
+    }
 
     /// Returns `true`, if [`EntityId`] present.
     #[inline]
-    pub fn has_entity(&self, id: EntityId) -> bool { todo!() }
+    pub fn has_entity(&self, id: EntityId) -> bool {
+        self.alive.get(id.id).copied().unwrap_or_default()
+            && self.generations[id.id] == id.generation
+    }
+
+    /// Returns an iterator over all present [`EntityId`]s.
+    pub fn entities(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.alive
+            .iter()
+            .enumerate()
+            .filter(|(_, alive)| **alive)
+            .map(|(id, _)| EntityId::new(id, self.generations[id]))
+    }
+}
+
+/// Map of `Entity`s from one [`World`](crate::world::World) to another.
+///
+/// `Entity`s are mapped by `id` only, since generations are not written with [`Reflect`] values.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Component, Reflect)]
+/// struct Target(EntityId);
+///
+/// let mut entity_map = EntityMap::default();
+/// entity_map.insert(EntityId::new(0, 0), EntityId::new(5, 1));
+///
+/// let mut target = Target(EntityId::new(0, 0));
+/// entity_map.map_entities(&mut target);
+///
+/// assert_eq!(target.0, EntityId::new(5, 1));
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct EntityMap {
+    map: HashMap<usize, EntityId>,
+}
+
+impl EntityMap {
+    /// Adds a mapping `from` [`EntityId`] `to` [`EntityId`].
+    /// If the mapping `from`, was already present, it's replace.
+    #[inline]
+    pub fn insert(&mut self, from: EntityId, to: EntityId) { self.map.insert(from.id, to); }
+
+    /// Returns a mapped [`EntityId`], if it's present.
+    #[inline]
+    pub fn get(&self, from: EntityId) -> Option<EntityId> { self.map.get(&from.id).copied() }
+
+    /// Returns an iterator over all mappings.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, EntityId)> + '_ {
+        self.map.iter().map(|(from, to)| (*from, *to))
+    }
+
+    /// Replaces all [`EntityId`]s, that are nested in `value`, by mapped ones.
+    /// [`EntityId`]s without mapping are kept.
+    pub fn map_entities(&self, value: &mut dyn Reflect) {
+        if let Some(entity_id) = value.downcast_mut::<EntityId>() {
+            if let Some(mapped) = self.get(*entity_id) {
+                *entity_id = mapped;
+            }
+            return;
+        }
+
+        for index in 0..value.field_len() {
+            if let Some(field) = value.field_at_mut(index) {
+                self.map_entities(field);
+            }
+        }
+    }
 }
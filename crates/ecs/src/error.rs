@@ -0,0 +1,62 @@
+//! Error functionality.
+
+use thiserror::Error;
+
+use crate::entity::EntityId;
+
+/// Error of the [`World`](crate::world::World) access.
+///
+/// Each variant names the type involved, so bad requests can be reported and recovered from.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Resource)]
+/// struct MyResource;
+///
+/// let world = World::default();
+///
+/// assert_eq!(
+///     world.try_get_resource::<MyResource>().err(),
+///     Some(WorldError::NotRegistered {
+///         name: std::any::type_name::<MyResource>()
+///     })
+/// );
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum WorldError {
+    /// The type was never added to the [`World`].
+    #[error("Failed to access `{name}`, it's never registered.")]
+    NotRegistered {
+        /// The name of the type.
+        name: &'static str,
+    },
+
+    /// The type is already borrowed, and the new borrow is incompatible with it.
+    #[error("Failed to borrow `{name}`, it's already borrowed.")]
+    AlreadyBorrowed {
+        /// The name of the type.
+        name: &'static str,
+    },
+
+    /// The `Entity` was removed or never added.
+    #[error("Failed to access `{name}` of {entity_id:?}, the entity is stale.")]
+    StaleEntity {
+        /// The name of the type.
+        name: &'static str,
+
+        /// The [`EntityId`] of the `Entity`.
+        entity_id: EntityId,
+    },
+
+    /// The `Entity` is alive, but has no [`Component`](crate::component::Component) of the type.
+    #[error("Failed to access `{name}` of {entity_id:?}, it's does not present.")]
+    MissingComponent {
+        /// The name of the type.
+        name: &'static str,
+
+        /// The [`EntityId`] of the `Entity`.
+        entity_id: EntityId,
+    },
+}
@@ -1,45 +1,169 @@
 //! Event functionality.
 
-use std::any::{type_name, Any};
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    slice::Iter,
+    vec::Drain,
+};
 
 use qinetic_utils::prelude::*;
 
-use crate::world::World;
+use crate::{
+    error::WorldError,
+    storage::{Ref, RefMut, TypeMap},
+};
 
-/// Event of the [`World`].
+/// Event of the [`World`](crate::world::World).
 pub trait Event: Any + Send + Sync + 'static {}
 
+/// Queue of [`Event`]s by `T`, that are sent in the current frame.
+///
+/// All [`Event`]s are kept in order of sending, until the end of the frame,
+/// when they're cleared by [`World::clear_events`](crate::world::World::clear_events).
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Event)]
+/// struct Scored(u32);
+///
+/// let mut world = World::default();
+/// world.add_event(Scored(1)).add_event(Scored(2));
+///
+/// let events = world.get_events::<Scored>().unwrap();
+/// assert_eq!(events.iter().map(|event| event.0).sum::<u32>(), 3);
+/// assert_eq!(events.latest().map(|event| event.0), Some(2));
+/// ```
+pub struct Events<T: Event> {
+    events: Vec<T>,
+}
+
+impl<T: Event> Events<T> {
+    /// Sends a `event` to the end of the queue.
+    #[inline]
+    pub fn send(&mut self, event: T) { self.events.push(event); }
+
+    /// Returns an iterator over [`Event`]s in order of sending.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, T> { self.events.iter() }
+
+    /// Returns the last sent [`Event`], if it's present.
+    #[inline]
+    pub fn latest(&self) -> Option<&T> { self.events.last() }
+
+    /// Returns a number of [`Event`]s.
+    #[inline]
+    pub fn len(&self) -> usize { self.events.len() }
+
+    /// Returns `true`, if no [`Event`] is sent.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.events.is_empty() }
+
+    /// Keeps only [`Event`]s, that pass the `filter`.
+    #[inline]
+    pub fn retain(&mut self, filter: impl FnMut(&T) -> bool) { self.events.retain(filter); }
+
+    /// Removes all [`Event`]s and returns them in order of sending.
+    #[inline]
+    pub fn drain(&mut self) -> Drain<'_, T> { self.events.drain(..) }
+
+    /// Removes all [`Event`]s.
+    #[inline]
+    pub fn clear(&mut self) { self.events.clear(); }
+}
+
+impl<T: Event> Default for Events<T> {
+    fn default() -> Self { Self { events: Vec::new() } }
+}
+
+impl<T: Event + Debug> Debug for Events<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(&self.events).finish()
+    }
+}
+
+impl<'a, T: Event> IntoIterator for &'a Events<T> {
+    type IntoIter = Iter<'a, T>;
+    type Item = &'a T;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter { self.iter() }
+}
+
 /// Facilities addition and remove [`Event`]s.
-#[derive(SmartDefault, Clone, Debug)]
-pub struct EventRegistry {}
+#[derive(SmartDefault, Debug)]
+pub struct EventRegistry {
+    /// [`Events`] by their [`Event`] type.
+    events: TypeMap,
 
-impl EventRegistry {
-    pub(crate) fn init_event<T: Event>(&mut self, event: T) {}
+    /// Clears [`Events`] by [`TypeId`] of their [`Event`].
+    clears: HashMap<TypeId, fn(&mut TypeMap)>,
+}
 
-    pub(crate) fn call_event<T: Event>(&self) { todo!() }
+impl EventRegistry {
+    /// Registers [`Events`] by `T`, if they're not present.
+    pub fn register_event<T: Event>(&mut self) {
+        if self.events.contains::<Events<T>>() {
+            return;
+        }
+        self.events.insert(Events::<T>::default());
+        self.clears.insert(TypeId::of::<T>(), |events| {
+            if let Some(events) = events.get_mut::<Events<T>>() {
+                events.clear();
+            }
+        });
+    }
 
-    pub(crate) fn bind_event<T: Event>(&self) { todo!() }
+    /// Sends a [`Event`] to the [`Events`] by `T`, registers them if they're not present.
+    pub fn add_event<T: Event>(&mut self, event: T) {
+        self.register_event::<T>();
+        if let Some(events) = self.events.get_mut::<Events<T>>() {
+            events.send(event);
+        }
+    }
 
-    pub(crate) fn unbind_event<T: Event>(&self) { todo!() }
+    /// Returns a immutable [`Events`] by `T`.
+    #[inline]
+    pub fn try_get_events<T: Event>(&self) -> Result<Ref<'_, Events<T>>, WorldError> {
+        self.events.try_borrow::<Events<T>>()
+    }
 
-    /// Adds a [`Event`].
-    /// If the [`Event`] by `T`, was already present, it's replace.
+    /// Returns a mutable [`Events`] by `T`.
     #[inline]
-    pub fn add_event<T: Event>(&mut self, event: T) { todo!() }
+    pub fn try_get_events_mut<T: Event>(&self) -> Result<RefMut<'_, Events<T>>, WorldError> {
+        self.events.try_borrow_mut::<Events<T>>()
+    }
 
-    /// Removes a [`Event`] by `T`.
+    /// Returns a immutable [`Events`] by `T`, if they're present and not mutably borrowed.
     #[inline]
-    pub fn remove_event<T: Event>(&mut self) { todo!() }
+    pub fn get_events<T: Event>(&self) -> Option<Ref<'_, Events<T>>> {
+        self.try_get_events::<T>().ok()
+    }
 
-    /// Returns a immutable [`Event`] by `T`, if it's present.
+    /// Returns a mutable [`Events`] by `T`, if they're present and not borrowed.
     #[inline]
-    pub fn get_event<T: Event>(&self) -> Option<&T> { todo!() }
+    pub fn get_events_mut<T: Event>(&self) -> Option<RefMut<'_, Events<T>>> {
+        self.try_get_events_mut::<T>().ok()
+    }
 
-    /// Returns a mutable [`Event`] by `T`, if it's present.
+    /// Returns `true`, if any [`Event`] by `T` is sent in the current frame.
     #[inline]
-    pub fn get_event_mut<T: Event>(&mut self) -> Option<&mut T> { todo!() }
+    pub fn has_event<T: Event>(&self) -> bool {
+        self.get_events::<T>()
+            .is_some_and(|events| !events.is_empty())
+    }
 
-    /// Returns `true`, if [`Event`] by `T` present.
+    /// Returns `true`, if [`Events`] by `T` are registered.
     #[inline]
-    pub fn has_event<T: Event>(&self) -> bool { todo!() }
+    pub fn is_event_registered<T: Event>(&self) -> bool { self.events.contains::<Events<T>>() }
+
+    /// Removes all [`Event`]s of all types, keeping them registered.
+    pub fn clear_events(&mut self) {
+        for clear in self.clears.values() {
+            clear(&mut self.events);
+        }
+    }
 }
@@ -7,12 +7,20 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod access;
+pub mod bundle;
+pub mod command;
 pub mod component;
 pub mod entity;
+pub mod error;
 pub mod event;
+pub mod reflect;
 pub mod resource;
+pub mod run_criteria;
 pub mod state;
+pub mod storage;
 pub mod system;
+pub mod value;
 pub mod world;
 
 pub mod prelude {
@@ -23,12 +31,20 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
+        access::*,
+        bundle::*,
+        command::*,
         component::*,
         entity::*,
+        error::*,
         event::*,
+        reflect::*,
         resource::*,
+        run_criteria::*,
         state::*,
+        storage::*,
         system::*,
+        value::*,
         world::*,
     };
 }
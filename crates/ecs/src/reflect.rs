@@ -0,0 +1,1020 @@
+//! Reflect functionality.
+
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::{hash_map::Entry, HashMap},
+};
+
+use qinetic_utils::prelude::*;
+use thiserror::Error;
+
+use crate::{
+    component::Component,
+    entity::EntityId,
+    error::WorldError,
+    resource::Resource,
+    value::ReflectValue,
+    world::World,
+};
+
+/// Runtime type information and access to fields by name.
+///
+/// Structs expose their fields, enums expose fields of the current variant,
+/// other types (primitives) are opaque values, that only can be replaced.
+/// Any value converts to and from [`ReflectValue`], so it can be serialized.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Reflect)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// #[derive(Default, Component, Reflect)]
+/// struct Player {
+///     name: String,
+///     position: Position,
+/// }
+///
+/// let mut player = Player::default();
+/// player.set_path("position.x", 5.0_f32).unwrap();
+///
+/// assert_eq!(player.position.x, 5.0);
+/// assert_eq!(Player::info().name(), concat!(module_path!(), "::Player"));
+/// assert_eq!(player.get_path::<f32>("position.x"), Ok(&5.0));
+/// assert_eq!(
+///     Player::info()
+///         .fields()
+///         .iter()
+///         .map(|field| field.name())
+///         .collect::<Vec<_>>(),
+///     vec!["name", "position"]
+/// );
+/// ```
+pub trait Reflect: Any + Send + Sync + 'static {
+    /// Returns a [`TypeInfo`] of the type.
+    fn info() -> TypeInfo
+    where Self: Sized;
+
+    /// Returns a [`TypeInfo`] of the value.
+    fn type_info(&self) -> TypeInfo;
+
+    /// Returns a immutable field by `name`, if it's present.
+    fn field(&self, name: &str) -> Option<&dyn Reflect>;
+
+    /// Returns a mutable field by `name`, if it's present.
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect>;
+
+    /// Returns a count of fields of the value.
+    fn field_len(&self) -> usize;
+
+    /// Returns a immutable field by `index`, if it's present.
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect>;
+
+    /// Returns a mutable field by `index`, if it's present.
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect>;
+
+    /// Returns a [`ReflectValue`] of the value.
+    fn to_value(&self) -> ReflectValue;
+
+    /// Applies a [`ReflectValue`] to the value.
+    /// Fields, that absent in `value`, are kept.
+    fn apply_value(&mut self, value: &ReflectValue) -> Result<(), ReflectError>;
+
+    /// Replaces the value by `value` of the same type.
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), ReflectError>;
+
+    /// Returns the value as [`Any`].
+    fn as_any(&self) -> &dyn Any;
+
+    /// Returns the value as mutable [`Any`].
+    fn as_any_mut(&mut self) -> &mut dyn Any;
+
+    /// Returns the boxed value as boxed [`Any`].
+    fn into_any(self: Box<Self>) -> Box<dyn Any>;
+
+    /// Returns the value as [`Reflect`].
+    fn as_reflect(&self) -> &dyn Reflect;
+
+    /// Returns the value as mutable [`Reflect`].
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect;
+}
+
+/// Error of the [`Reflect`] access.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum ReflectError {
+    /// The type has no field by the name.
+    #[error("Failed to reflect `{field}` of `{type_name}`, it's does not present.")]
+    NoField {
+        /// The name of the type.
+        type_name: &'static str,
+
+        /// The name of the field.
+        field: String,
+    },
+
+    /// The value has another type, than expected.
+    #[error("Failed to reflect `{found}`, expected `{expected}`.")]
+    TypeMismatch {
+        /// The name of the expected type.
+        expected: &'static str,
+
+        /// The name of the found type.
+        found: &'static str,
+    },
+
+    /// The enum has no variant by the name.
+    #[error("Failed to reflect variant `{variant}` of `{type_name}`, it's does not present.")]
+    NoVariant {
+        /// The name of the type.
+        type_name: &'static str,
+
+        /// The name of the variant.
+        variant: String,
+    },
+
+    /// The [`ReflectValue`] can't be applied to the type.
+    #[error("Failed to apply {found} value to `{expected}`.")]
+    InvalidValue {
+        /// The name of the expected type.
+        expected: &'static str,
+
+        /// The kind of the found value.
+        found: &'static str,
+    },
+
+    /// The [`ReflectValue`] was written by newer schema version, than registered.
+    #[error("Failed to migrate `{type_name}` from version {version}, it's newer than {current}.")]
+    UnsupportedVersion {
+        /// The name of the type.
+        type_name: &'static str,
+
+        /// The version of the value.
+        version: u32,
+
+        /// The registered version of the type.
+        current: u32,
+    },
+}
+
+/// Information about the [`Reflect`] type.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+pub struct TypeInfo {
+    /// The stable name of the type, that is registered in [`TypeRegistry`].
+    #[getset(get_copy = "pub")]
+    name: &'static str,
+
+    /// The name of the type by [`type_name`].
+    #[getset(get_copy = "pub")]
+    type_name: &'static str,
+
+    /// The [`TypeId`] of the type.
+    #[getset(get_copy = "pub")]
+    type_id: TypeId,
+
+    /// Fields of the type in declaration order.
+    #[getset(get = "pub")]
+    fields: Vec<FieldInfo>,
+}
+
+/// Information about the field of [`Reflect`] type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct FieldInfo {
+    /// The name of the field.
+    name: &'static str,
+
+    /// The name of the type of the field.
+    type_name: &'static str,
+}
+
+impl TypeInfo {
+    /// Returns a [`TypeInfo`] of `T` with `fields`, that is named by [`type_name`].
+    #[inline]
+    pub fn new<T: Any>(fields: Vec<FieldInfo>) -> Self {
+        Self::named::<T>(type_name::<T>(), fields)
+    }
+
+    /// Returns a [`TypeInfo`] of `T` by `name` with `fields`.
+    ///
+    /// The `name` is written with values of the type, so it should be stable and unique,
+    /// unlike [`type_name`], that may change between compiler versions.
+    #[inline]
+    pub fn named<T: Any>(name: &'static str, fields: Vec<FieldInfo>) -> Self {
+        Self {
+            name,
+            type_name: type_name::<T>(),
+            type_id: TypeId::of::<T>(),
+            fields,
+        }
+    }
+
+    /// Returns a [`FieldInfo`] by `name`, if it's present.
+    #[inline]
+    pub fn field(&self, name: &str) -> Option<&FieldInfo> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
+
+impl FieldInfo {
+    /// Returns a [`FieldInfo`] by `name` of type `T`.
+    #[inline]
+    pub fn new<T: Any>(name: &'static str) -> Self {
+        Self {
+            name,
+            type_name: type_name::<T>(),
+        }
+    }
+}
+
+impl dyn Reflect {
+    /// Returns `true`, if the value has type `T`.
+    #[inline]
+    pub fn is<T: Reflect>(&self) -> bool { self.as_any().is::<T>() }
+
+    /// Returns a immutable value of type `T`, if it's.
+    #[inline]
+    pub fn downcast_ref<T: Reflect>(&self) -> Option<&T> { self.as_any().downcast_ref::<T>() }
+
+    /// Returns a mutable value of type `T`, if it's.
+    #[inline]
+    pub fn downcast_mut<T: Reflect>(&mut self) -> Option<&mut T> {
+        self.as_any_mut().downcast_mut::<T>()
+    }
+}
+
+/// Access to nested fields of [`Reflect`] by path like `"position.x"`.
+///
+/// Fields of tuples and tuple structs are named by their indices, like `"0"`.
+pub trait ReflectPath {
+    /// Returns a immutable field by `path`.
+    fn path(&self, path: &str) -> Result<&dyn Reflect, ReflectError>;
+
+    /// Returns a mutable field by `path`.
+    fn path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect, ReflectError>;
+
+    /// Returns a immutable field of type `T` by `path`.
+    fn get_path<T: Reflect>(&self, path: &str) -> Result<&T, ReflectError> {
+        let field = self.path(path)?;
+        let found = field.type_info().name();
+        field.downcast_ref::<T>().ok_or(ReflectError::TypeMismatch {
+            expected: type_name::<T>(),
+            found,
+        })
+    }
+
+    /// Replaces a field by `path` with `value` of the same type.
+    fn set_path<T: Reflect>(&mut self, path: &str, value: T) -> Result<(), ReflectError> {
+        self.path_mut(path)?.set(Box::new(value))
+    }
+}
+
+impl<R: Reflect + ?Sized> ReflectPath for R {
+    fn path(&self, path: &str) -> Result<&dyn Reflect, ReflectError> {
+        let mut current = self.as_reflect();
+        for name in path.split('.').filter(|name| !name.is_empty()) {
+            current = current.field(name).ok_or_else(|| {
+                ReflectError::NoField {
+                    type_name: current.type_info().name(),
+                    field: name.into(),
+                }
+            })?;
+        }
+        Ok(current)
+    }
+
+    fn path_mut(&mut self, path: &str) -> Result<&mut dyn Reflect, ReflectError> {
+        let mut current = self.as_reflect_mut();
+        for name in path.split('.').filter(|name| !name.is_empty()) {
+            let type_name = current.type_info().name();
+            current = current.field_mut(name).ok_or_else(|| {
+                ReflectError::NoField {
+                    type_name,
+                    field: name.into(),
+                }
+            })?;
+        }
+        Ok(current)
+    }
+}
+
+/// Replaces `target` by `value`, if it has type `T`.
+///
+/// Used by [`Reflect::set`] implementations.
+pub fn set_value<T: Reflect>(target: &mut T, value: Box<dyn Reflect>) -> Result<(), ReflectError> {
+    let found = value.type_info().name();
+    *target = *value.into_any().downcast::<T>().map_err(|_| {
+        ReflectError::TypeMismatch {
+            expected: type_name::<T>(),
+            found,
+        }
+    })?;
+    Ok(())
+}
+
+/// Applies a [`ReflectValue::Map`] of fields by name or [`ReflectValue::Seq`] of fields by index to `target`.
+///
+/// Used by [`Reflect::apply_value`] implementations.
+pub fn apply_fields<R: Reflect + ?Sized>(
+    target: &mut R,
+    value: &ReflectValue,
+) -> Result<(), ReflectError> {
+    let type_name = target.type_info().name();
+    match value {
+        | ReflectValue::Map(entries) => {
+            for (name, value) in entries {
+                target
+                    .field_mut(name)
+                    .ok_or_else(|| {
+                        ReflectError::NoField {
+                            type_name,
+                            field: name.clone(),
+                        }
+                    })?
+                    .apply_value(value)?;
+            }
+        },
+        | ReflectValue::Seq(values) => {
+            for (index, value) in values.iter().enumerate() {
+                target
+                    .field_at_mut(index)
+                    .ok_or_else(|| {
+                        ReflectError::NoField {
+                            type_name,
+                            field: index.to_string(),
+                        }
+                    })?
+                    .apply_value(value)?;
+            }
+        },
+        | _ => {
+            return Err(ReflectError::InvalidValue {
+                expected: type_name,
+                found: value.kind(),
+            })
+        },
+    }
+    Ok(())
+}
+
+/// Implements [`Reflect`] for opaque value types without fields.
+///
+/// The types should be [`Clone`] and convert to and from [`ReflectValue`].
+#[macro_export]
+macro_rules! impl_reflect_value {
+    ($($ty: ty),* $(,)?) => {
+        $(
+            impl $crate::reflect::Reflect for $ty {
+                #[inline]
+                fn info() -> $crate::reflect::TypeInfo { $crate::reflect::TypeInfo::new::<Self>(Vec::new()) }
+
+                #[inline]
+                fn type_info(&self) -> $crate::reflect::TypeInfo { <Self as $crate::reflect::Reflect>::info() }
+
+                #[inline]
+                fn field(&self, _name: &str) -> Option<&dyn $crate::reflect::Reflect> { None }
+
+                #[inline]
+                fn field_mut(&mut self, _name: &str) -> Option<&mut dyn $crate::reflect::Reflect> { None }
+
+                #[inline]
+                fn field_len(&self) -> usize { 0 }
+
+                #[inline]
+                fn field_at(&self, _index: usize) -> Option<&dyn $crate::reflect::Reflect> { None }
+
+                #[inline]
+                fn field_at_mut(&mut self, _index: usize) -> Option<&mut dyn $crate::reflect::Reflect> { None }
+
+                #[inline]
+                fn to_value(&self) -> $crate::value::ReflectValue { self.clone().into() }
+
+                #[inline]
+                fn apply_value(
+                    &mut self,
+                    value: &$crate::value::ReflectValue,
+                ) -> Result<(), $crate::reflect::ReflectError> {
+                    *self = value.try_into()?;
+                    Ok(())
+                }
+
+                #[inline]
+                fn set(
+                    &mut self,
+                    value: Box<dyn $crate::reflect::Reflect>,
+                ) -> Result<(), $crate::reflect::ReflectError> {
+                    $crate::reflect::set_value(self, value)
+                }
+
+                #[inline]
+                fn as_any(&self) -> &dyn std::any::Any { self }
+
+                #[inline]
+                fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+
+                #[inline]
+                fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> { self }
+
+                #[inline]
+                fn as_reflect(&self) -> &dyn $crate::reflect::Reflect { self }
+
+                #[inline]
+                fn as_reflect_mut(&mut self) -> &mut dyn $crate::reflect::Reflect { self }
+            }
+        )*
+    };
+}
+
+impl_reflect_value!(
+    bool, char, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64, String,
+    EntityId,
+);
+
+macro_rules! impl_reflect_fields {
+    (@value Seq $self: ident $($field: tt: $name: literal)*) => {
+        ReflectValue::Seq(vec![$($self.$field.to_value()),*])
+    };
+    (@value Map $self: ident $($field: tt: $name: literal)*) => {
+        ReflectValue::Map(vec![$(($name.into(), $self.$field.to_value())),*])
+    };
+    ($kind: ident [$($generics: tt)*] $ty: ty { $($field: tt: $name: literal: $field_ty: ty),* }) => {
+        impl<$($generics)*> Reflect for $ty {
+            fn info() -> TypeInfo {
+                TypeInfo::new::<Self>(vec![$(FieldInfo::new::<$field_ty>($name)),*])
+            }
+
+            #[inline]
+            fn type_info(&self) -> TypeInfo { Self::info() }
+
+            fn field(&self, name: &str) -> Option<&dyn Reflect> {
+                match name {
+                    $(| $name => Some(&self.$field),)*
+                    | _ => None,
+                }
+            }
+
+            fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+                match name {
+                    $(| $name => Some(&mut self.$field),)*
+                    | _ => None,
+                }
+            }
+
+            #[inline]
+            fn field_len(&self) -> usize { [$($name),*].len() }
+
+            fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+                self.field([$($name),*].get(index)?)
+            }
+
+            fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                self.field_mut([$($name),*].get(index)?)
+            }
+
+            #[inline]
+            fn to_value(&self) -> ReflectValue {
+                impl_reflect_fields!(@value $kind self $($field: $name)*)
+            }
+
+            #[inline]
+            fn apply_value(&mut self, value: &ReflectValue) -> Result<(), ReflectError> {
+                apply_fields(self, value)
+            }
+
+            #[inline]
+            fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), ReflectError> { set_value(self, value) }
+
+            #[inline]
+            fn as_any(&self) -> &dyn Any { self }
+
+            #[inline]
+            fn as_any_mut(&mut self) -> &mut dyn Any { self }
+
+            #[inline]
+            fn into_any(self: Box<Self>) -> Box<dyn Any> { self }
+
+            #[inline]
+            fn as_reflect(&self) -> &dyn Reflect { self }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn Reflect { self }
+        }
+    };
+}
+
+impl_reflect_fields!(Seq [A: Reflect, B: Reflect] (A, B) { 0: "0": A, 1: "1": B });
+impl_reflect_fields!(Seq [A: Reflect, B: Reflect, C: Reflect] (A, B, C) { 0: "0": A, 1: "1": B, 2: "2": C });
+impl_reflect_fields!(
+    Seq [A: Reflect, B: Reflect, C: Reflect, D: Reflect] (A, B, C, D) { 0: "0": A, 1: "1": B, 2: "2": C, 3: "3": D }
+);
+impl_reflect_fields!(Map [T: Reflect] RGB<T> { r: "r": T, g: "g": T, b: "b": T });
+impl_reflect_fields!(Map [T: Reflect] RGBA<T> { r: "r": T, g: "g": T, b: "b": T, a: "a": T });
+
+/// The value of `Some` is the field `"0"`, `None` is [`ReflectValue::Unit`].
+impl<T: Reflect + Default> Reflect for Option<T> {
+    #[inline]
+    fn info() -> TypeInfo { TypeInfo::new::<Self>(Vec::new()) }
+
+    #[inline]
+    fn type_info(&self) -> TypeInfo { Self::info() }
+
+    fn field(&self, name: &str) -> Option<&dyn Reflect> {
+        match name {
+            | "0" => self.field_at(0),
+            | _ => None,
+        }
+    }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        match name {
+            | "0" => self.field_at_mut(0),
+            | _ => None,
+        }
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize { self.is_some() as usize }
+
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        match (self, index) {
+            | (Some(value), 0) => Some(value),
+            | _ => None,
+        }
+    }
+
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match (self, index) {
+            | (Some(value), 0) => Some(value),
+            | _ => None,
+        }
+    }
+
+    fn to_value(&self) -> ReflectValue {
+        match self {
+            | Some(value) => value.to_value(),
+            | None => ReflectValue::Unit,
+        }
+    }
+
+    fn apply_value(&mut self, value: &ReflectValue) -> Result<(), ReflectError> {
+        match value {
+            | ReflectValue::Unit => *self = None,
+            | value => self.get_or_insert_with(T::default).apply_value(value)?,
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), ReflectError> {
+        set_value(self, value)
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any { self }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> { self }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect { self }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect { self }
+}
+
+/// Elements are fields by their indices.
+impl<T: Reflect + Default> Reflect for Vec<T> {
+    #[inline]
+    fn info() -> TypeInfo { TypeInfo::new::<Self>(Vec::new()) }
+
+    #[inline]
+    fn type_info(&self) -> TypeInfo { Self::info() }
+
+    fn field(&self, name: &str) -> Option<&dyn Reflect> { self.field_at(name.parse().ok()?) }
+
+    fn field_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        self.field_at_mut(name.parse().ok()?)
+    }
+
+    #[inline]
+    fn field_len(&self) -> usize { self.len() }
+
+    fn field_at(&self, index: usize) -> Option<&dyn Reflect> {
+        self.get(index).map(|value| value as &dyn Reflect)
+    }
+
+    fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.get_mut(index).map(|value| value as &mut dyn Reflect)
+    }
+
+    fn to_value(&self) -> ReflectValue { ReflectValue::Seq(self.iter().map(T::to_value).collect()) }
+
+    fn apply_value(&mut self, value: &ReflectValue) -> Result<(), ReflectError> {
+        let ReflectValue::Seq(values) = value else {
+            return Err(value.invalid::<Self>());
+        };
+
+        self.truncate(values.len());
+        for (index, value) in values.iter().enumerate() {
+            if index == self.len() {
+                self.push(T::default());
+            }
+            self[index].apply_value(value)?;
+        }
+        Ok(())
+    }
+
+    #[inline]
+    fn set(&mut self, value: Box<dyn Reflect>) -> Result<(), ReflectError> {
+        set_value(self, value)
+    }
+
+    #[inline]
+    fn as_any(&self) -> &dyn Any { self }
+
+    #[inline]
+    fn as_any_mut(&mut self) -> &mut dyn Any { self }
+
+    #[inline]
+    fn into_any(self: Box<Self>) -> Box<dyn Any> { self }
+
+    #[inline]
+    fn as_reflect(&self) -> &dyn Reflect { self }
+
+    #[inline]
+    fn as_reflect_mut(&mut self) -> &mut dyn Reflect { self }
+}
+
+/// Callback with immutable [`Reflect`] value.
+type ReflectFn<'a> = dyn FnMut(&dyn Reflect) + 'a;
+
+/// Callback with mutable [`Reflect`] value.
+type ReflectMutFn<'a> = dyn FnMut(&mut dyn Reflect) + 'a;
+
+/// Adds a boxed [`Component`] to `Entity`.
+type InsertComponentFn = fn(&mut World, EntityId, Box<dyn Reflect>) -> Result<(), WorldError>;
+
+/// Access to [`Reflect`] [`Component`] of [`World`] without knowing it's type.
+#[derive(Clone, Copy)]
+pub struct ReflectComponent {
+    reflect: fn(&World, EntityId, &mut ReflectFn) -> Result<(), WorldError>,
+
+    reflect_mut: fn(&World, EntityId, &mut ReflectMutFn) -> Result<(), WorldError>,
+
+    insert: InsertComponentFn,
+
+    default: fn() -> Box<dyn Reflect>,
+}
+
+/// Access to [`Reflect`] [`Resource`] of [`World`] without knowing it's type.
+#[derive(Clone, Copy)]
+pub struct ReflectResource {
+    reflect: fn(&World, &mut ReflectFn) -> Result<(), WorldError>,
+
+    reflect_mut: fn(&World, &mut ReflectMutFn) -> Result<(), WorldError>,
+
+    insert: fn(&mut World, Box<dyn Reflect>),
+
+    default: fn() -> Box<dyn Reflect>,
+}
+
+impl ReflectComponent {
+    fn of<T: Component + Reflect + Default>() -> Self {
+        Self {
+            reflect: |world, entity_id, f| {
+                f(&*world.try_get_component::<T>(entity_id)?);
+                Ok(())
+            },
+            reflect_mut: |world, entity_id, f| {
+                f(&mut *world.try_get_component_mut::<T>(entity_id)?);
+                Ok(())
+            },
+            insert: |world, entity_id, value| {
+                let value = value.into_any().downcast::<T>().unwrap();
+                world.try_add_component(entity_id, *value)
+            },
+            default: || Box::<T>::default(),
+        }
+    }
+
+    /// Calls `f` with the [`Component`] of `Entity` by [`EntityId`].
+    #[inline]
+    pub fn reflect(
+        &self,
+        world: &World,
+        entity_id: EntityId,
+        mut f: impl FnMut(&dyn Reflect),
+    ) -> Result<(), WorldError> {
+        (self.reflect)(world, entity_id, &mut f)
+    }
+
+    /// Calls `f` with the mutable [`Component`] of `Entity` by [`EntityId`].
+    #[inline]
+    pub fn reflect_mut(
+        &self,
+        world: &World,
+        entity_id: EntityId,
+        mut f: impl FnMut(&mut dyn Reflect),
+    ) -> Result<(), WorldError> {
+        (self.reflect_mut)(world, entity_id, &mut f)
+    }
+
+    /// Adds the [`Component`] to `Entity` by [`EntityId`].
+    ///
+    /// # Panics
+    /// Panics, if `value` has another type.
+    #[inline]
+    pub fn insert(
+        &self,
+        world: &mut World,
+        entity_id: EntityId,
+        value: Box<dyn Reflect>,
+    ) -> Result<(), WorldError> {
+        (self.insert)(world, entity_id, value)
+    }
+
+    /// Returns a `default` [`Component`].
+    #[inline]
+    pub fn default_value(&self) -> Box<dyn Reflect> { (self.default)() }
+}
+
+impl ReflectResource {
+    fn of<T: Resource + Reflect + Default>() -> Self {
+        Self {
+            reflect: |world, f| {
+                f(&*world.try_get_resource::<T>()?);
+                Ok(())
+            },
+            reflect_mut: |world, f| {
+                f(&mut *world.try_get_resource_mut::<T>()?);
+                Ok(())
+            },
+            insert: |world, value| {
+                world.add_resource(*value.into_any().downcast::<T>().unwrap());
+            },
+            default: || Box::<T>::default(),
+        }
+    }
+
+    /// Calls `f` with the [`Resource`].
+    #[inline]
+    pub fn reflect(
+        &self,
+        world: &World,
+        mut f: impl FnMut(&dyn Reflect),
+    ) -> Result<(), WorldError> {
+        (self.reflect)(world, &mut f)
+    }
+
+    /// Calls `f` with the mutable [`Resource`].
+    #[inline]
+    pub fn reflect_mut(
+        &self,
+        world: &World,
+        mut f: impl FnMut(&mut dyn Reflect),
+    ) -> Result<(), WorldError> {
+        (self.reflect_mut)(world, &mut f)
+    }
+
+    /// Adds the [`Resource`].
+    /// If the [`Resource`], was already present, it's replace.
+    ///
+    /// # Panics
+    /// Panics, if `value` has another type.
+    #[inline]
+    pub fn insert(&self, world: &mut World, value: Box<dyn Reflect>) { (self.insert)(world, value) }
+
+    /// Returns a `default` [`Resource`].
+    #[inline]
+    pub fn default_value(&self) -> Box<dyn Reflect> { (self.default)() }
+}
+
+/// Migration of [`ReflectValue`] from a schema version to the next one.
+pub type Migration = fn(&mut ReflectValue);
+
+/// Registered [`Reflect`] type.
+///
+/// The `version` of the type schema is written with its values,
+/// so values written by older versions are migrated on read.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Component, Reflect)]
+/// struct Health {
+///     current: u32,
+/// }
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry
+///     .register_component::<Health>()
+///     .with_version(1)
+///     .with_migration(0, |value| {
+///         if let Some(hp) = value.remove("hp") {
+///             value.insert("current", hp);
+///         }
+///     });
+///
+/// let mut value = ReflectValue::Map(vec![("hp".into(), ReflectValue::UInt(50))]);
+/// type_registry
+///     .get_by_name(Health::info().name())
+///     .unwrap()
+///     .migrate(0, &mut value)
+///     .unwrap();
+///
+/// let mut health = Health::default();
+/// health.apply_value(&value).unwrap();
+///
+/// assert_eq!(health.current, 50);
+/// ```
+#[derive(Clone, Getters, CopyGetters)]
+pub struct TypeRegistration {
+    /// The [`TypeInfo`] of the type.
+    #[getset(get = "pub")]
+    info: TypeInfo,
+
+    /// The [`ReflectComponent`], if the type is [`Component`].
+    #[getset(get = "pub")]
+    component: Option<ReflectComponent>,
+
+    /// The [`ReflectResource`], if the type is [`Resource`].
+    #[getset(get = "pub")]
+    resource: Option<ReflectResource>,
+
+    /// The current schema version of the type.
+    #[getset(get_copy = "pub")]
+    version: u32,
+
+    /// The [`Migration`]s by schema version, they migrate from.
+    migrations: HashMap<u32, Migration>,
+}
+
+impl TypeRegistration {
+    /// Sets a current schema `version` of the type.
+    #[inline]
+    pub fn with_version(&mut self, version: u32) -> &mut Self {
+        self.version = version;
+        self
+    }
+
+    /// Adds a [`Migration`] from schema version `from` to the next one.
+    /// If the [`Migration`] by `from`, was already present, it's replace.
+    #[inline]
+    pub fn with_migration(&mut self, from: u32, migration: Migration) -> &mut Self {
+        self.migrations.insert(from, migration);
+        self
+    }
+
+    /// Migrates a `value`, written by schema `version`, to the current one.
+    ///
+    /// Versions without [`Migration`] are considered compatible with the next ones.
+    pub fn migrate(&self, version: u32, value: &mut ReflectValue) -> Result<(), ReflectError> {
+        if version > self.version {
+            return Err(ReflectError::UnsupportedVersion {
+                type_name: self.info.name,
+                version,
+                current: self.version,
+            });
+        }
+
+        for from in version..self.version {
+            if let Some(migration) = self.migrations.get(&from) {
+                migration(value);
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Registry of [`Reflect`] types, that is a [`Resource`] of the [`World`].
+///
+/// Types are found by their stable [`TypeInfo::name`], that can be set by `#[reflect(name = "...")]`.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Component, Reflect)]
+/// #[reflect(name = "game::Health")]
+/// struct Health(u32);
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register_component::<Health>();
+///
+/// let mut world = World::default();
+/// let entity_id = world.spawn(Health(100));
+///
+/// let registration = type_registry.get_by_name("game::Health").unwrap();
+/// registration
+///     .component()
+///     .unwrap()
+///     .reflect_mut(&world, entity_id, |health| {
+///         health.set_path("0", 50_u32).unwrap();
+///     })
+///     .unwrap();
+///
+/// assert_eq!(world.get_component::<Health>(entity_id).unwrap().0, 50);
+/// ```
+#[derive(SmartDefault, Clone)]
+pub struct TypeRegistry {
+    types: HashMap<TypeId, TypeRegistration>,
+
+    names: HashMap<&'static str, TypeId>,
+}
+
+impl Resource for TypeRegistry {}
+
+impl TypeRegistry {
+    /// Registers a [`Reflect`] type `T`.
+    ///
+    /// # Panics
+    /// Panics, if the [`TypeInfo::name`] of `T` is already bound to another type.
+    ///
+    /// # Examples
+    /// ```should_panic
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Component, Reflect)]
+    /// #[reflect(name = "game::Health")]
+    /// struct Health(u32);
+    ///
+    /// #[derive(Default, Component, Reflect)]
+    /// #[reflect(name = "game::Health")]
+    /// struct Mana(u32);
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register::<Health>();
+    /// type_registry.register::<Health>();
+    /// type_registry.register::<Mana>();
+    /// ```
+    pub fn register<T: Reflect>(&mut self) -> &mut TypeRegistration {
+        let info = T::info();
+        match self.names.entry(info.name) {
+            | Entry::Occupied(entry) if *entry.get() != info.type_id => {
+                panic!(
+                    "Failed to register `{}` by name `{}`, it's already bound to `{}`.",
+                    info.type_name,
+                    info.name,
+                    self.types[entry.get()].info.type_name
+                );
+            },
+            | entry => {
+                entry.or_insert(info.type_id);
+            },
+        }
+        self.types.entry(info.type_id).or_insert_with(|| {
+            TypeRegistration {
+                info,
+                component: None,
+                resource: None,
+                version: 0,
+                migrations: HashMap::new(),
+            }
+        })
+    }
+
+    /// Registers a [`Reflect`] [`Component`] `T`.
+    #[inline]
+    pub fn register_component<T: Component + Reflect + Default>(
+        &mut self,
+    ) -> &mut TypeRegistration {
+        let registration = self.register::<T>();
+        registration.component = Some(ReflectComponent::of::<T>());
+        registration
+    }
+
+    /// Registers a [`Reflect`] [`Resource`] `T`.
+    #[inline]
+    pub fn register_resource<T: Resource + Reflect + Default>(&mut self) -> &mut TypeRegistration {
+        let registration = self.register::<T>();
+        registration.resource = Some(ReflectResource::of::<T>());
+        registration
+    }
+
+    /// Returns a [`TypeRegistration`] by [`TypeId`], if it's present.
+    #[inline]
+    pub fn get(&self, type_id: TypeId) -> Option<&TypeRegistration> { self.types.get(&type_id) }
+
+    /// Returns a [`TypeRegistration`] by type name, if it's present.
+    #[inline]
+    pub fn get_by_name(&self, name: &str) -> Option<&TypeRegistration> {
+        self.names
+            .get(name)
+            .and_then(|type_id| self.types.get(type_id))
+    }
+
+    /// Returns `true`, if type `T` registered.
+    #[inline]
+    pub fn contains<T: Reflect>(&self) -> bool { self.types.contains_key(&TypeId::of::<T>()) }
+
+    /// Returns an iterator over all [`TypeRegistration`]s.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &TypeRegistration> { self.types.values() }
+}
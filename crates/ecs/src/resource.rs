@@ -1,13 +1,15 @@
 //! Resource functionality.
 
-use std::{
-    any::{type_name, Any, TypeId},
-    collections::HashMap,
-};
+use std::any::Any;
 
 use qinetic_utils::prelude::*;
 
-/// Resource of the [`World`].
+use crate::{
+    error::WorldError,
+    storage::{Ref, RefMut, TypeMap},
+};
+
+/// Resource of the [`World`](crate::world::World).
 ///
 /// # Examples
 /// ```
@@ -21,20 +23,55 @@ use qinetic_utils::prelude::*;
 pub trait Resource: Any + Send + Sync + 'static {}
 
 /// Facilities addition and remove [`Resource`]s.
-#[derive(SmartDefault, Clone, Debug)]
-pub struct ResourceRegistry {}
+#[derive(SmartDefault, Debug)]
+pub struct ResourceRegistry {
+    resources: TypeMap,
+}
 
 impl ResourceRegistry {
+    /// Adds a [`Resource`].
+    /// If the [`Resource`] by `T`, was already present, it's replace.
+    #[inline]
+    pub fn init_resource<T: Resource>(&mut self, resource: T) { self.add_resource(resource); }
+
+    /// Adds a [`Resource`].
+    /// If the [`Resource`] by `T`, was already present, it's replace.
+    #[inline]
+    pub fn add_resource<T: Resource>(&mut self, resource: T) { self.resources.insert(resource); }
+
+    /// Removes a [`Resource`] by `T`.
+    #[inline]
+    pub fn remove_resource<T: Resource>(&mut self) { self.resources.try_remove::<T>().ok(); }
+
+    /// Removes a [`Resource`] by `T` and returns it.
+    #[inline]
+    pub fn try_remove_resource<T: Resource>(&mut self) -> Result<T, WorldError> {
+        self.resources.try_remove::<T>()
+    }
+
+    /// Returns a immutable [`Resource`] by `T`.
+    #[inline]
+    pub fn try_get_resource<T: Resource>(&self) -> Result<Ref<'_, T>, WorldError> {
+        self.resources.try_borrow::<T>()
+    }
+
+    /// Returns a mutable [`Resource`] by `T`.
     #[inline]
-    pub fn init_resource<T: Resource>(&mut self, resource: T) {}
+    pub fn try_get_resource_mut<T: Resource>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        self.resources.try_borrow_mut::<T>()
+    }
 
-    /// Returns a immutable [`Resource`] by `T`, if it's present.
+    /// Returns a immutable [`Resource`] by `T`, if it's present and not mutably borrowed.
     #[inline]
-    pub fn get_resource<T: Resource>(&self) -> Option<&T> { todo!() }
+    pub fn get_resource<T: Resource>(&self) -> Option<Ref<'_, T>> {
+        self.try_get_resource::<T>().ok()
+    }
 
-    /// Returns a mutable [`Resource`] by `T`, if it's present.
+    /// Returns a mutable [`Resource`] by `T`, if it's present and not borrowed.
     #[inline]
-    pub fn get_resource_mut<T: Resource>(&mut self) -> Option<&mut T> { todo!() }
+    pub fn get_resource_mut<T: Resource>(&self) -> Option<RefMut<'_, T>> {
+        self.try_get_resource_mut::<T>().ok()
+    }
 
     /// Returns `true`, if [`Resource`] by `T` present.
     ///
@@ -55,5 +92,5 @@ impl ResourceRegistry {
     /// assert!(!resource_registry.has_resource::<MyResource2>());
     /// ```
     #[inline]
-    pub fn has_resource<T: Resource>(&self) -> bool { todo!() }
+    pub fn has_resource<T: Resource>(&self) -> bool { self.resources.contains::<T>() }
 }
@@ -0,0 +1,191 @@
+//! Run criteria functionality.
+
+use std::{
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use qinetic_utils::prelude::*;
+
+use crate::{event::Event, resource::Resource, state::State, world::World};
+
+/// Condition, that decides whether a [`System`](crate::system::System) should run.
+///
+/// Implemented for any `FnMut(&World) -> bool` closure, so custom predicates can be used directly.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// let mut frames = 0;
+/// let mut every_second_frame = move |_: &World| {
+///     frames += 1;
+///     frames % 2 == 0
+/// };
+///
+/// let world = World::default();
+///
+/// assert!(!every_second_frame.should_run(&world));
+/// assert!(every_second_frame.should_run(&world));
+/// ```
+pub trait RunCriteria: Send + Sync + 'static {
+    /// Returns `true`, if the [`System`](crate::system::System) should run.
+    fn should_run(&mut self, world: &World) -> bool;
+}
+
+impl<F> RunCriteria for F
+where F: FnMut(&World) -> bool + Send + Sync + 'static
+{
+    #[inline]
+    fn should_run(&mut self, world: &World) -> bool { self(world) }
+}
+
+/// [`RunCriteria`] that passes, if [`Resource`] by `T` present.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Resource)]
+/// struct MyResource;
+///
+/// let criteria = resource_exists::<MyResource>();
+/// ```
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    Default(bound = "")
+)]
+pub struct ResourceExists<T: Resource> {
+    #[derivative(Debug = "ignore")]
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Resource> RunCriteria for ResourceExists<T> {
+    #[inline]
+    fn should_run(&mut self, world: &World) -> bool { world.has_resource::<T>() }
+}
+
+/// Returns a [`RunCriteria`] that passes, if [`Resource`] by `T` present.
+#[inline]
+pub fn resource_exists<T: Resource>() -> ResourceExists<T> { ResourceExists::default() }
+
+/// [`RunCriteria`] that passes, if [`State`] by `T` equals to the expected one.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Clone, PartialEq, State)]
+/// enum MyState {
+///     #[default]
+///     Menu,
+///     Game,
+/// }
+///
+/// let criteria = in_state(MyState::Game);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct InState<T: State + PartialEq> {
+    state: T,
+}
+
+impl<T: State + PartialEq> RunCriteria for InState<T> {
+    #[inline]
+    fn should_run(&mut self, world: &World) -> bool {
+        world
+            .get_state::<T>()
+            .is_some_and(|state| *state == self.state)
+    }
+}
+
+/// Returns a [`RunCriteria`] that passes, if [`State`] by `T` equals to `state`.
+#[inline]
+pub fn in_state<T: State + PartialEq>(state: T) -> InState<T> { InState { state } }
+
+/// [`RunCriteria`] that passes, if any [`Event`] by `T` is sent in the current frame.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Event)]
+/// struct MyEvent;
+///
+/// let mut world = World::default();
+/// let mut criteria = on_event::<MyEvent>();
+///
+/// world.add_event(MyEvent);
+/// assert!(criteria.should_run(&world));
+///
+/// world.clear_events();
+/// assert!(!criteria.should_run(&world));
+/// ```
+#[derive(Derivative)]
+#[derivative(
+    Clone(bound = ""),
+    Copy(bound = ""),
+    Debug(bound = ""),
+    Default(bound = "")
+)]
+pub struct OnEvent<T: Event> {
+    #[derivative(Debug = "ignore")]
+    marker: PhantomData<fn() -> T>,
+}
+
+impl<T: Event> RunCriteria for OnEvent<T> {
+    #[inline]
+    fn should_run(&mut self, world: &World) -> bool { world.has_event::<T>() }
+}
+
+/// Returns a [`RunCriteria`] that passes, if any [`Event`] by `T` is sent in the current frame.
+#[inline]
+pub fn on_event<T: Event>() -> OnEvent<T> { OnEvent::default() }
+
+/// [`RunCriteria`] that passes once per `period`.
+///
+/// The first `period` starts on the first check.
+///
+/// # Examples
+/// ```
+/// # use std::time::Duration;
+/// #
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// // Runs AI tick at 10 Hz.
+/// let mut criteria = on_timer(Duration::from_millis(100));
+///
+/// assert!(!criteria.should_run(&World::default()));
+/// ```
+#[derive(Clone, Copy, Debug, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct OnTimer {
+    /// The period between runs.
+    period: Duration,
+
+    #[getset(skip)]
+    last: Option<Instant>,
+}
+
+impl RunCriteria for OnTimer {
+    fn should_run(&mut self, _world: &World) -> bool {
+        let now = Instant::now();
+        match self.last {
+            | Some(last) if now.duration_since(last) >= self.period => {
+                self.last = Some(now);
+                true
+            },
+            | Some(_) => false,
+            | None => {
+                self.last = Some(now);
+                false
+            },
+        }
+    }
+}
+
+/// Returns a [`RunCriteria`] that passes once per `period`.
+#[inline]
+pub fn on_timer(period: Duration) -> OnTimer { OnTimer { period, last: None } }
@@ -1,39 +1,60 @@
 //! State functionality.
 
-use std::any::type_name;
-
 use qinetic_utils::prelude::*;
 
-use crate::world::World;
+use crate::{
+    error::WorldError,
+    storage::{Ref, RefMut, TypeMap},
+};
 
-/// State of the [`World`].
+/// State of the [`World`](crate::world::World).
 pub trait State: Send + Sync + 'static {}
 
 /// Facilities addition and remove [`State`]s.
-#[derive(SmartDefault, Clone, Debug)]
-pub struct StateRegistry {}
+#[derive(SmartDefault, Debug)]
+pub struct StateRegistry {
+    states: TypeMap,
+}
 
 impl StateRegistry {
-    pub(crate) fn init_state<T: State>(&mut self, state: T) {}
-
     /// Adds a [`State`].
     /// If the [`State`] by `T`, was already present, it's replace.
     #[inline]
-    pub fn add_state<T: State>(&mut self, state: T) { todo!() }
+    pub fn add_state<T: State>(&mut self, state: T) { self.states.insert(state); }
 
     /// Removes a [`State`] by `T`.
     #[inline]
-    pub fn remove_state<T: State>(&mut self) { todo!() }
+    pub fn remove_state<T: State>(&mut self) { self.states.try_remove::<T>().ok(); }
+
+    /// Removes a [`State`] by `T` and returns it.
+    #[inline]
+    pub fn try_remove_state<T: State>(&mut self) -> Result<T, WorldError> {
+        self.states.try_remove::<T>()
+    }
+
+    /// Returns a immutable [`State`] by `T`.
+    #[inline]
+    pub fn try_get_state<T: State>(&self) -> Result<Ref<'_, T>, WorldError> {
+        self.states.try_borrow::<T>()
+    }
+
+    /// Returns a mutable [`State`] by `T`.
+    #[inline]
+    pub fn try_get_state_mut<T: State>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        self.states.try_borrow_mut::<T>()
+    }
 
-    /// Returns a immutable [`State`] by `T`, if it's present.
+    /// Returns a immutable [`State`] by `T`, if it's present and not mutably borrowed.
     #[inline]
-    pub fn get_state<T: State>(&self) -> Option<&T> { todo!() }
+    pub fn get_state<T: State>(&self) -> Option<Ref<'_, T>> { self.try_get_state::<T>().ok() }
 
-    /// Returns a mutable [`State`] by `T`, if it's present.
+    /// Returns a mutable [`State`] by `T`, if it's present and not borrowed.
     #[inline]
-    pub fn get_state_mut<T: State>(&mut self) -> Option<&mut T> { todo!() }
+    pub fn get_state_mut<T: State>(&self) -> Option<RefMut<'_, T>> {
+        self.try_get_state_mut::<T>().ok()
+    }
 
     /// Returns `true`, if [`State`] by `T` present.
     #[inline]
-    pub fn has_state<T: State>(&self) -> bool { todo!() }
+    pub fn has_state<T: State>(&self) -> bool { self.states.contains::<T>() }
 }
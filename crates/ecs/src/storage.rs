@@ -0,0 +1,179 @@
+//! Storage functionality.
+
+use std::{
+    any::{type_name, Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug, Formatter},
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+    sync::{PoisonError, RwLock, RwLockReadGuard, RwLockWriteGuard, TryLockError},
+};
+
+use crate::error::WorldError;
+
+/// Immutable borrow of a value in the [`World`](crate::world::World).
+///
+/// Other immutable borrows are allowed, while it's alive.
+pub struct Ref<'a, T: 'static> {
+    guard: RwLockReadGuard<'a, Box<dyn Any + Send + Sync>>,
+
+    marker: PhantomData<&'a T>,
+}
+
+/// Mutable borrow of a value in the [`World`](crate::world::World).
+///
+/// No other borrows are allowed, while it's alive.
+pub struct RefMut<'a, T: 'static> {
+    guard: RwLockWriteGuard<'a, Box<dyn Any + Send + Sync>>,
+
+    marker: PhantomData<&'a mut T>,
+}
+
+impl<T: 'static> Deref for Ref<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { (**self.guard).downcast_ref::<T>().unwrap() }
+}
+
+impl<T: 'static> Deref for RefMut<'_, T> {
+    type Target = T;
+
+    #[inline]
+    fn deref(&self) -> &T { (**self.guard).downcast_ref::<T>().unwrap() }
+}
+
+impl<T: 'static> DerefMut for RefMut<'_, T> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut T { (**self.guard).downcast_mut::<T>().unwrap() }
+}
+
+impl<T: Debug + 'static> Debug for Ref<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { (**self).fmt(f) }
+}
+
+impl<T: Debug + 'static> Debug for RefMut<'_, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result { (**self).fmt(f) }
+}
+
+/// Type-erased value with runtime-checked borrows.
+pub(crate) struct TypeCell {
+    name: &'static str,
+
+    value: RwLock<Box<dyn Any + Send + Sync>>,
+}
+
+impl TypeCell {
+    pub(crate) fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self {
+            name: type_name::<T>(),
+            value: RwLock::new(Box::new(value)),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &'static str { self.name }
+
+    pub(crate) fn try_borrow<T: 'static>(&self) -> Result<Ref<'_, T>, WorldError> {
+        let guard = match self.value.try_read() {
+            | Ok(guard) => guard,
+            | Err(TryLockError::Poisoned(error)) => error.into_inner(),
+            | Err(TryLockError::WouldBlock) => {
+                return Err(WorldError::AlreadyBorrowed { name: self.name });
+            },
+        };
+
+        Ok(Ref {
+            guard,
+            marker: PhantomData,
+        })
+    }
+
+    pub(crate) fn try_borrow_mut<T: 'static>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        let guard = match self.value.try_write() {
+            | Ok(guard) => guard,
+            | Err(TryLockError::Poisoned(error)) => error.into_inner(),
+            | Err(TryLockError::WouldBlock) => {
+                return Err(WorldError::AlreadyBorrowed { name: self.name });
+            },
+        };
+
+        Ok(RefMut {
+            guard,
+            marker: PhantomData,
+        })
+    }
+
+    /// Returns a mutable value without borrow checks, since the [`TypeCell`] is borrowed uniquely.
+    pub(crate) fn get_mut<T: 'static>(&mut self) -> &mut T {
+        let value = self.value.get_mut().unwrap_or_else(PoisonError::into_inner);
+        (**value).downcast_mut::<T>().unwrap()
+    }
+
+    pub(crate) fn into_inner<T: 'static>(self) -> T {
+        let value = self
+            .value
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        *value.downcast::<T>().unwrap()
+    }
+}
+
+/// Values by their types with runtime-checked borrows.
+#[derive(Default)]
+pub(crate) struct TypeMap {
+    cells: HashMap<TypeId, TypeCell>,
+}
+
+impl TypeMap {
+    /// Inserts a `value` and returns the previous one, if it's present.
+    pub(crate) fn insert<T: Any + Send + Sync>(&mut self, value: T) -> Option<T> {
+        self.cells
+            .insert(TypeId::of::<T>(), TypeCell::new(value))
+            .map(TypeCell::into_inner)
+    }
+
+    pub(crate) fn try_remove<T: 'static>(&mut self) -> Result<T, WorldError> {
+        self.cells
+            .remove(&TypeId::of::<T>())
+            .map(TypeCell::into_inner)
+            .ok_or_else(Self::not_registered::<T>)
+    }
+
+    pub(crate) fn try_borrow<T: 'static>(&self) -> Result<Ref<'_, T>, WorldError> {
+        self.cells
+            .get(&TypeId::of::<T>())
+            .ok_or_else(Self::not_registered::<T>)?
+            .try_borrow()
+    }
+
+    pub(crate) fn try_borrow_mut<T: 'static>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        self.cells
+            .get(&TypeId::of::<T>())
+            .ok_or_else(Self::not_registered::<T>)?
+            .try_borrow_mut()
+    }
+
+    pub(crate) fn get_mut<T: 'static>(&mut self) -> Option<&mut T> {
+        self.cells
+            .get_mut(&TypeId::of::<T>())
+            .map(TypeCell::get_mut)
+    }
+
+    pub(crate) fn contains<T: 'static>(&self) -> bool {
+        self.cells.contains_key(&TypeId::of::<T>())
+    }
+
+    fn not_registered<T: 'static>() -> WorldError {
+        WorldError::NotRegistered {
+            name: type_name::<T>(),
+        }
+    }
+}
+
+impl Debug for TypeMap {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries(self.cells.values().map(TypeCell::name))
+            .finish()
+    }
+}
@@ -4,7 +4,7 @@ use std::any::{type_name, Any};
 
 use qinetic_utils::prelude::*;
 
-use crate::world::World;
+use crate::{access::SystemAccess, run_criteria::RunCriteria, world::World};
 
 /// System of the [`World`].
 pub trait System: Any + Send + Sync + 'static {
@@ -12,6 +12,134 @@ pub trait System: Any + Send + Sync + 'static {
 
     #[allow(unused_variables)]
     fn run(&mut self, data: Self::Data);
+
+    /// Declares [`Resource`](crate::resource::Resource)s and [`Component`](crate::component::Component)s, that the [`System`] reads and writes.
+    ///
+    /// Access of [`System::Data`] by [`SystemData::access`] is added to it.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Resource)]
+    /// struct Time;
+    ///
+    /// #[derive(Default, Component)]
+    /// struct Position;
+    ///
+    /// struct MovementSystem;
+    ///
+    /// impl System for MovementSystem {
+    ///     type Data = ();
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    ///
+    ///     fn access(&self, access: &mut SystemAccess) {
+    ///         access.read_resource::<Time>().write_component::<Position>();
+    ///     }
+    /// }
+    /// ```
+    #[allow(unused_variables)]
+    fn access(&self, access: &mut SystemAccess) {}
+}
+
+/// Data of the [`System`], that fetches from the [`World`] before each run.
+pub trait SystemData: Sized {
+    /// Fetches a data from the [`World`].
+    fn fetch(world: &mut World) -> Self;
+
+    /// Declares [`Resource`](crate::resource::Resource)s and [`Component`](crate::component::Component)s, that the data reads and writes.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Resource)]
+    /// struct Time;
+    ///
+    /// struct TimeUpdate;
+    ///
+    /// impl SystemData for TimeUpdate {
+    ///     fn fetch(world: &mut World) -> Self { Self }
+    ///
+    ///     fn access(access: &mut SystemAccess) { access.write_resource::<Time>(); }
+    /// }
+    ///
+    /// struct TimeSystem;
+    ///
+    /// impl System for TimeSystem {
+    ///     type Data = (TimeUpdate,);
+    ///
+    ///     fn run(&mut self, data: Self::Data) {
+    ///         // Something to do
+    ///     }
+    /// }
+    ///
+    /// let mut access = SystemAccess::default();
+    /// access.write_resource::<Time>();
+    /// assert_eq!(TimeSystem.collect_access(), access);
+    /// ```
+    #[allow(unused_variables)]
+    fn access(access: &mut SystemAccess) {}
+}
+
+macro_rules! impl_system_data_for_tuple {
+    ($($name: ident),*) => {
+        impl<$($name: SystemData),*> SystemData for ($($name,)*) {
+            #[allow(unused_variables, clippy::unused_unit)]
+            #[inline]
+            fn fetch(world: &mut World) -> Self { ($($name::fetch(world),)*) }
+
+            #[allow(unused_variables)]
+            #[inline]
+            fn access(access: &mut SystemAccess) { $($name::access(access);)* }
+        }
+    };
+}
+
+impl_system_data_for_tuple!();
+impl_system_data_for_tuple!(A);
+impl_system_data_for_tuple!(A, B);
+impl_system_data_for_tuple!(A, B, C);
+impl_system_data_for_tuple!(A, B, C, D);
+impl_system_data_for_tuple!(A, B, C, D, E);
+impl_system_data_for_tuple!(A, B, C, D, E, F);
+impl_system_data_for_tuple!(A, B, C, D, E, F, G);
+impl_system_data_for_tuple!(A, B, C, D, E, F, G, H);
+
+/// Type-erased [`System`], that fetches own [`System::Data`] from the [`World`].
+pub trait DynSystem: Send + Sync + 'static {
+    /// Returns a name of the [`System`].
+    fn name(&self) -> &'static str;
+
+    /// Fetches [`System::Data`] from the [`World`], runs the [`System`] and applies it's [`Commands`](crate::command::Commands).
+    fn run_world(&mut self, world: &mut World);
+
+    /// Returns a [`SystemAccess`] declared by the [`System`].
+    fn collect_access(&self) -> SystemAccess;
+}
+
+impl<T: System> DynSystem for T
+where T::Data: SystemData
+{
+    #[inline]
+    fn name(&self) -> &'static str { type_name::<T>() }
+
+    #[inline]
+    fn run_world(&mut self, world: &mut World) {
+        self.run(T::Data::fetch(world));
+        world.apply_commands();
+    }
+
+    #[inline]
+    fn collect_access(&self) -> SystemAccess {
+        let mut access = SystemAccess::default();
+        T::Data::access(&mut access);
+        System::access(self, &mut access);
+        access
+    }
 }
 
 /// Facilities addition and remove [`System`]s.
@@ -28,3 +156,93 @@ qinetic_utils::define_label!(
     /// Strongly-typed identifier for a [`SystemLabel`].
     SystemLabelId,
 );
+
+/// Named set of [`System`]s, that shares [`RunCriteria`] and ordering.
+///
+/// The [`SystemSet`] runs, only if all of its [`RunCriteria`] pass.
+///
+/// # Examples
+/// ```
+/// # use std::time::Duration;
+/// #
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(SystemLabel)]
+/// struct Physics;
+///
+/// #[derive(SystemLabel)]
+/// struct Ai;
+///
+/// let system_set = SystemSet::new(Ai)
+///     .with_run_criteria(on_timer(Duration::from_millis(100)))
+///     .with_after(Physics);
+///
+/// assert_eq!(system_set.label(), Ai.as_label());
+/// assert_eq!(system_set.after(), &[Physics.as_label()]);
+/// ```
+#[derive(Getters, CopyGetters)]
+pub struct SystemSet {
+    /// The identity of the [`SystemSet`].
+    #[getset(get_copy = "pub")]
+    label: SystemLabelId,
+
+    /// The [`SystemLabel`]s of [`SystemSet`]s, that should run after this one.
+    #[getset(get = "pub")]
+    before: Vec<SystemLabelId>,
+
+    /// The [`SystemLabel`]s of [`SystemSet`]s, that should run before this one.
+    #[getset(get = "pub")]
+    after: Vec<SystemLabelId>,
+
+    run_criteria: Vec<Box<dyn RunCriteria>>,
+}
+
+impl SystemSet {
+    /// Returns a [`SystemSet`] by `label` without [`RunCriteria`] and ordering.
+    #[inline]
+    pub fn new(label: impl SystemLabel) -> Self {
+        Self {
+            label: label.as_label(),
+            before: Vec::new(),
+            after: Vec::new(),
+            run_criteria: Vec::new(),
+        }
+    }
+
+    /// Returns a [`SystemSet`] with added [`RunCriteria`].
+    #[inline]
+    pub fn with_run_criteria(mut self, run_criteria: impl RunCriteria) -> Self {
+        self.run_criteria.push(Box::new(run_criteria));
+        self
+    }
+
+    /// Returns a [`SystemSet`], that runs before [`SystemSet`] by `label`.
+    #[inline]
+    pub fn with_before(mut self, label: impl SystemLabel) -> Self {
+        self.before.push(label.as_label());
+        self
+    }
+
+    /// Returns a [`SystemSet`], that runs after [`SystemSet`] by `label`.
+    #[inline]
+    pub fn with_after(mut self, label: impl SystemLabel) -> Self {
+        self.after.push(label.as_label());
+        self
+    }
+
+    /// Returns `true`, if all [`RunCriteria`] of the [`SystemSet`] pass.
+    ///
+    /// Every [`RunCriteria`] is checked, so stateful ones (like [`on_timer`](crate::run_criteria::on_timer)) keep ticking.
+    pub fn should_run(&mut self, world: &World) -> bool {
+        let mut should_run = true;
+        for run_criteria in &mut self.run_criteria {
+            should_run &= run_criteria.should_run(world);
+        }
+        should_run
+    }
+
+    /// Returns `true`, if the [`SystemSet`] should run after `other` [`SystemSet`].
+    pub fn is_after(&self, other: &SystemSet) -> bool {
+        self.after.contains(&other.label) || other.before.contains(&self.label)
+    }
+}
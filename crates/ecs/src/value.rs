@@ -0,0 +1,366 @@
+//! Value functionality.
+
+use std::{any::type_name, fmt};
+
+use serde::{
+    de::{self, Deserialize, Deserializer, MapAccess, SeqAccess, Visitor},
+    ser::{Serialize, SerializeMap, SerializeSeq, Serializer},
+};
+
+use crate::{entity::EntityId, reflect::ReflectError};
+
+/// Dynamic value of [`Reflect`](crate::reflect::Reflect) type, that can be serialized without knowing the type.
+///
+/// Structs are [`ReflectValue::Map`]s of fields, tuples are [`ReflectValue::Seq`]s.
+/// Enums are variant names, or single entry [`ReflectValue::Map`]s of variant name and fields.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// #[derive(Default, Reflect)]
+/// struct Position {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let mut position = Position::default();
+/// position
+///     .apply_value(&ReflectValue::Map(vec![(
+///         "x".into(),
+///         ReflectValue::Float(5.0),
+///     )]))
+///     .unwrap();
+///
+/// assert_eq!(position.x, 5.0);
+/// assert_eq!(
+///     position.to_value(),
+///     ReflectValue::Map(vec![
+///         ("x".into(), ReflectValue::Float(5.0)),
+///         ("y".into(), ReflectValue::Float(0.0)),
+///     ])
+/// );
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum ReflectValue {
+    /// The empty value, like `None`.
+    Unit,
+
+    /// The boolean value.
+    Bool(bool),
+
+    /// The signed integer value.
+    Int(i128),
+
+    /// The unsigned integer value.
+    UInt(u128),
+
+    /// The floating point value.
+    Float(f64),
+
+    /// The string value.
+    String(String),
+
+    /// The sequence of values.
+    Seq(Vec<ReflectValue>),
+
+    /// The named values in order.
+    Map(Vec<(String, ReflectValue)>),
+}
+
+impl ReflectValue {
+    /// Returns a name of the value kind, used in errors.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            | Self::Unit => "unit",
+            | Self::Bool(_) => "bool",
+            | Self::Int(_) | Self::UInt(_) => "integer",
+            | Self::Float(_) => "float",
+            | Self::String(_) => "string",
+            | Self::Seq(_) => "sequence",
+            | Self::Map(_) => "map",
+        }
+    }
+
+    /// Returns a immutable value by `name`, if it's [`ReflectValue::Map`] and the value present.
+    pub fn get(&self, name: &str) -> Option<&ReflectValue> {
+        match self {
+            | Self::Map(entries) => entries.iter().find(|(n, _)| n == name).map(|(_, v)| v),
+            | _ => None,
+        }
+    }
+
+    /// Returns a mutable value by `name`, if it's [`ReflectValue::Map`] and the value present.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut ReflectValue> {
+        match self {
+            | Self::Map(entries) => entries.iter_mut().find(|(n, _)| n == name).map(|(_, v)| v),
+            | _ => None,
+        }
+    }
+
+    /// Inserts a value by `name`, if it's [`ReflectValue::Map`].
+    /// If the value by `name`, was already present, it's replace.
+    pub fn insert(&mut self, name: impl Into<String>, value: ReflectValue) {
+        let name = name.into();
+        if let Self::Map(entries) = self {
+            match entries.iter_mut().find(|(n, _)| *n == name) {
+                | Some((_, v)) => *v = value,
+                | None => entries.push((name, value)),
+            }
+        }
+    }
+
+    /// Removes a value by `name` and returns it, if it's [`ReflectValue::Map`] and the value present.
+    pub fn remove(&mut self, name: &str) -> Option<ReflectValue> {
+        match self {
+            | Self::Map(entries) => {
+                let index = entries.iter().position(|(n, _)| n == name)?;
+                Some(entries.remove(index).1)
+            },
+            | _ => None,
+        }
+    }
+
+    /// Returns a [`ReflectError::InvalidValue`] for the value, that can't be applied to `T`.
+    #[inline]
+    pub fn invalid<T: ?Sized>(&self) -> ReflectError {
+        ReflectError::InvalidValue {
+            expected: type_name::<T>(),
+            found: self.kind(),
+        }
+    }
+}
+
+macro_rules! impl_value_int {
+    ($variant: ident: $($ty: ty),*) => {
+        $(
+            impl From<$ty> for ReflectValue {
+                #[inline]
+                fn from(value: $ty) -> Self { Self::$variant(value as _) }
+            }
+
+            impl TryFrom<&ReflectValue> for $ty {
+                type Error = ReflectError;
+
+                fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+                    match value {
+                        | ReflectValue::Int(v) => <$ty>::try_from(*v).ok(),
+                        | ReflectValue::UInt(v) => <$ty>::try_from(*v).ok(),
+                        | _ => None,
+                    }
+                    .ok_or_else(|| value.invalid::<$ty>())
+                }
+            }
+        )*
+    };
+}
+
+impl_value_int!(Int: i8, i16, i32, i64, i128, isize);
+impl_value_int!(UInt: u8, u16, u32, u64, u128, usize);
+
+macro_rules! impl_value_float {
+    ($($ty: ty),*) => {
+        $(
+            impl From<$ty> for ReflectValue {
+                #[inline]
+                fn from(value: $ty) -> Self { Self::Float(value as _) }
+            }
+
+            impl TryFrom<&ReflectValue> for $ty {
+                type Error = ReflectError;
+
+                fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+                    match value {
+                        | ReflectValue::Float(v) => Ok(*v as _),
+                        | ReflectValue::Int(v) => Ok(*v as _),
+                        | ReflectValue::UInt(v) => Ok(*v as _),
+                        | _ => Err(value.invalid::<$ty>()),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_value_float!(f32, f64);
+
+impl From<bool> for ReflectValue {
+    #[inline]
+    fn from(value: bool) -> Self { Self::Bool(value) }
+}
+
+impl TryFrom<&ReflectValue> for bool {
+    type Error = ReflectError;
+
+    fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+        match value {
+            | ReflectValue::Bool(v) => Ok(*v),
+            | _ => Err(value.invalid::<bool>()),
+        }
+    }
+}
+
+impl From<char> for ReflectValue {
+    #[inline]
+    fn from(value: char) -> Self { Self::String(value.into()) }
+}
+
+impl TryFrom<&ReflectValue> for char {
+    type Error = ReflectError;
+
+    fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+        let mut chars = match value {
+            | ReflectValue::String(v) => v.chars(),
+            | _ => return Err(value.invalid::<char>()),
+        };
+        match (chars.next(), chars.next()) {
+            | (Some(v), None) => Ok(v),
+            | _ => Err(value.invalid::<char>()),
+        }
+    }
+}
+
+impl From<String> for ReflectValue {
+    #[inline]
+    fn from(value: String) -> Self { Self::String(value) }
+}
+
+impl TryFrom<&ReflectValue> for String {
+    type Error = ReflectError;
+
+    fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+        match value {
+            | ReflectValue::String(v) => Ok(v.clone()),
+            | _ => Err(value.invalid::<String>()),
+        }
+    }
+}
+
+/// [`EntityId`]s are written without generation, that is meaningless outside of the [`World`](crate::world::World).
+impl From<EntityId> for ReflectValue {
+    #[inline]
+    fn from(value: EntityId) -> Self { Self::UInt(value.id() as _) }
+}
+
+impl TryFrom<&ReflectValue> for EntityId {
+    type Error = ReflectError;
+
+    fn try_from(value: &ReflectValue) -> Result<Self, Self::Error> {
+        usize::try_from(value)
+            .map(|id| EntityId::new(id, 0))
+            .map_err(|_| value.invalid::<EntityId>())
+    }
+}
+
+impl Serialize for ReflectValue {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            | Self::Unit => serializer.serialize_unit(),
+            | Self::Bool(v) => serializer.serialize_bool(*v),
+            | Self::Int(v) => {
+                match i64::try_from(*v) {
+                    | Ok(v) => serializer.serialize_i64(v),
+                    | Err(_) => serializer.serialize_i128(*v),
+                }
+            },
+            | Self::UInt(v) => {
+                match u64::try_from(*v) {
+                    | Ok(v) => serializer.serialize_u64(v),
+                    | Err(_) => serializer.serialize_u128(*v),
+                }
+            },
+            | Self::Float(v) => serializer.serialize_f64(*v),
+            | Self::String(v) => serializer.serialize_str(v),
+            | Self::Seq(values) => {
+                let mut seq = serializer.serialize_seq(Some(values.len()))?;
+                for value in values {
+                    seq.serialize_element(value)?;
+                }
+                seq.end()
+            },
+            | Self::Map(entries) => {
+                let mut map = serializer.serialize_map(Some(entries.len()))?;
+                for (name, value) in entries {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            },
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ReflectValue {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(ReflectValueVisitor)
+    }
+}
+
+struct ReflectValueVisitor;
+
+impl<'de> Visitor<'de> for ReflectValueVisitor {
+    type Value = ReflectValue;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("any value")
+    }
+
+    fn visit_unit<E: de::Error>(self) -> Result<Self::Value, E> { Ok(ReflectValue::Unit) }
+
+    fn visit_none<E: de::Error>(self) -> Result<Self::Value, E> { Ok(ReflectValue::Unit) }
+
+    fn visit_some<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        ReflectValue::deserialize(deserializer)
+    }
+
+    fn visit_bool<E: de::Error>(self, v: bool) -> Result<Self::Value, E> {
+        Ok(ReflectValue::Bool(v))
+    }
+
+    fn visit_i64<E: de::Error>(self, v: i64) -> Result<Self::Value, E> {
+        Ok(ReflectValue::Int(v.into()))
+    }
+
+    fn visit_i128<E: de::Error>(self, v: i128) -> Result<Self::Value, E> {
+        Ok(ReflectValue::Int(v))
+    }
+
+    fn visit_u64<E: de::Error>(self, v: u64) -> Result<Self::Value, E> {
+        Ok(ReflectValue::UInt(v.into()))
+    }
+
+    fn visit_u128<E: de::Error>(self, v: u128) -> Result<Self::Value, E> {
+        Ok(ReflectValue::UInt(v))
+    }
+
+    fn visit_f64<E: de::Error>(self, v: f64) -> Result<Self::Value, E> {
+        Ok(ReflectValue::Float(v))
+    }
+
+    fn visit_char<E: de::Error>(self, v: char) -> Result<Self::Value, E> {
+        Ok(ReflectValue::String(v.into()))
+    }
+
+    fn visit_str<E: de::Error>(self, v: &str) -> Result<Self::Value, E> {
+        Ok(ReflectValue::String(v.into()))
+    }
+
+    fn visit_string<E: de::Error>(self, v: String) -> Result<Self::Value, E> {
+        Ok(ReflectValue::String(v))
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut values = Vec::with_capacity(seq.size_hint().unwrap_or_default());
+        while let Some(value) = seq.next_element()? {
+            values.push(value);
+        }
+        Ok(ReflectValue::Seq(values))
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut entries = Vec::with_capacity(map.size_hint().unwrap_or_default());
+        while let Some(entry) = map.next_entry()? {
+            entries.push(entry);
+        }
+        Ok(ReflectValue::Map(entries))
+    }
+}
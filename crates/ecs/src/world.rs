@@ -1,24 +1,35 @@
 //! World functionality.
 
+use std::any::type_name;
+
 use qinetic_utils::prelude::*;
 
 use crate::{
-    component::{Component, ComponentRegistry},
+    bundle::Bundle,
+    command::Commands,
+    component::{Component, ComponentId, ComponentRegistry},
     entity::{EntityId, EntityRegistry},
-    event::{Event, EventRegistry},
+    error::WorldError,
+    event::{Event, EventRegistry, Events},
     resource::{Resource, ResourceRegistry},
     state::{State, StateRegistry},
+    storage::{Ref, RefMut},
 };
 
 /// A representation of ECS `world`.
 ///
+/// Immutable and mutable access are checked at runtime, so a value can be borrowed mutably by `&World`.
+/// `try_` methods return [`WorldError`] for bad requests, other ones return [`None`] or ignore them.
+///
+/// The [`World`] isn't `Clone`, since it stores values of any type, that aren't required to be `Clone`.
+///
 /// # Examples
 /// ```
 /// # use qinetic_ecs::prelude::*;
 /// #
 /// let world = World::default();
 /// ```
-#[derive(SmartDefault, Clone, Debug, CopyGetters)]
+#[derive(SmartDefault, Debug, CopyGetters)]
 #[getset(get_copy = "pub")]
 pub struct World {
     id: WorldId,
@@ -37,6 +48,9 @@ pub struct World {
 
     #[getset(skip)]
     state_registry: StateRegistry,
+
+    #[getset(skip)]
+    commands: Commands,
 }
 
 /// Identificator for [`World`].
@@ -52,7 +66,17 @@ impl World {
     /// Returns a [`World`] with `default` configuration.
     pub fn new() -> Self { World::default() }
 
-    /// Adds a [`Component`] to [`Entity`] by [`EntityId`].
+    /// Registers a [`Component`] by `T` and returns it's [`ComponentId`].
+    ///
+    /// If the [`Component`] by `T`, was already registered, returns the present [`ComponentId`].
+    #[inline]
+    pub fn register_component<T: Component>(&mut self) -> ComponentId {
+        self.component_registry.register_component::<T>()
+    }
+
+    /// Adds a [`Component`] to [`Entity`] by [`EntityId`], if it's present.
+    ///
+    /// If the [`Component`] by `T`, was already present, it's replace.
     ///
     /// # Examples
     /// ```
@@ -63,20 +87,55 @@ impl World {
     ///     // someting to do
     /// }
     ///
-    /// let mut world = World::builder()
-    ///     .with_component(MyComponent::default())
-    ///     .build()
-    ///     .add_component::<MyComponent>(EntityId::default());
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
+    ///
+    /// world.add_component(entity_id, MyComponent::default());
     /// #
-    /// # assert!(world.has_component::<MyComponent>(EntityId::default()));
+    /// # assert!(world.has_component::<MyComponent>(entity_id));
+    ///
+    /// world.remove_entity(entity_id);
+    /// world.add_component(entity_id, MyComponent::default());
+    ///
+    /// assert!(!world.has_entity(entity_id));
     /// ```
     #[inline]
-    pub fn add_component<T: Component>(&mut self, entity_id: EntityId) -> &mut Self {
-        self.component_registry.add_component::<T>(entity_id);
+    pub fn add_component<T: Component>(&mut self, entity_id: EntityId, component: T) -> &mut Self {
+        self.try_add_component(entity_id, component).ok();
         self
     }
 
-    /// Removes a [`Component`] of [`Entity`] by [`EntityId`].
+    /// Adds a [`Component`] to [`Entity`] by [`EntityId`].
+    ///
+    /// If the [`Component`] by `T`, was already present, it's replace.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Component)]
+    /// struct MyComponent;
+    ///
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
+    /// world.remove_entity(entity_id);
+    ///
+    /// assert!(matches!(
+    ///     world.try_add_component(entity_id, MyComponent),
+    ///     Err(WorldError::StaleEntity { .. })
+    /// ));
+    /// ```
+    pub fn try_add_component<T: Component>(
+        &mut self,
+        entity_id: EntityId,
+        component: T,
+    ) -> Result<(), WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        self.component_registry.add_component(entity_id, component);
+        Ok(())
+    }
+
+    /// Removes a [`Component`] of [`Entity`] by [`EntityId`], if it's present.
     ///
     /// # Examples
     /// ```
@@ -87,16 +146,27 @@ impl World {
     ///     // someting to do
     /// }
     ///
-    /// let mut world = World::builder()
-    ///     .with_component(MyComponent::default())
-    ///     .build()
-    ///     .remove_component::<MyComponent>(EntityId::default());
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
+    ///
+    /// world
+    ///     .add_component(entity_id, MyComponent::default())
+    ///     .remove_component::<MyComponent>(entity_id);
     /// #
-    /// # assert!(!world.has_component::<MyComponent>(EntityId::default()));
+    /// # assert!(!world.has_component::<MyComponent>(entity_id));
     /// ```
     #[inline]
     pub fn remove_component<T: Component>(&mut self, entity_id: EntityId) {
-        self.component_registry.remove_component::<T>(entity_id);
+        self.try_remove_component::<T>(entity_id).ok();
+    }
+
+    /// Removes a [`Component`] of [`Entity`] by [`EntityId`] and returns it.
+    pub fn try_remove_component<T: Component>(
+        &mut self,
+        entity_id: EntityId,
+    ) -> Result<T, WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        self.component_registry.try_remove_component::<T>(entity_id)
     }
 
     /// Returns `true`, if [`Component`] of [`Entity`] by [`EntityId`] present.
@@ -110,20 +180,19 @@ impl World {
     ///     // someting to do
     /// }
     ///
-    /// let mut world = World::builder()
-    ///     .with_component(MyComponent::default())
-    ///     .build();
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
     ///
-    /// world.add_component::<MyComponent>(EntityId::default());
+    /// world.add_component(entity_id, MyComponent::default());
     ///
-    /// assert!(world.has_component::<MyComponent>(EntityId::default()));
+    /// assert!(world.has_component::<MyComponent>(entity_id));
     /// ```
     #[inline]
     pub fn has_component<T: Component>(&self, entity_id: EntityId) -> bool {
-        self.component_registry.has_component::<T>(entity_id)
+        self.has_entity(entity_id) && self.component_registry.has_component::<T>(entity_id)
     }
 
-    /// Returns a immutable [`Component`] of [`Entity`] by [`EntityId`], if it's present.
+    /// Returns a immutable [`Component`] of [`Entity`] by [`EntityId`], if it's present and not mutably borrowed.
     ///
     /// # Examples
     /// ```
@@ -134,23 +203,30 @@ impl World {
     ///     // someting to do
     /// }
     ///
-    /// let mut world = World::builder()
-    ///     .with_component(MyComponent::default())
-    ///     .build();
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
     ///
-    /// world.add_component::<MyComponent>(EntityId::default());
+    /// world.add_component(entity_id, MyComponent::default());
     ///
-    /// # assert!(world.has_component::<MyComponent>(EntityId::default()));
-    /// let component = world
-    ///     .get_component::<MyComponent>(EntityId::default())
-    ///     .unwrap();
+    /// # assert!(world.has_component::<MyComponent>(entity_id));
+    /// let component = world.get_component::<MyComponent>(entity_id).unwrap();
     /// ```
     #[inline]
-    pub fn get_component<T: Component>(&self, entity_id: EntityId) -> Option<&T> {
-        self.component_registry.get_component::<T>(entity_id)
+    pub fn get_component<T: Component>(&self, entity_id: EntityId) -> Option<Ref<'_, T>> {
+        self.try_get_component::<T>(entity_id).ok()
     }
 
-    /// Returns a mutable [`Component`] of [`Entity`] by [`EntityId`], if it's present.
+    /// Returns a immutable [`Component`] of [`Entity`] by [`EntityId`].
+    #[inline]
+    pub fn try_get_component<T: Component>(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<Ref<'_, T>, WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        self.component_registry.try_get_component::<T>(entity_id)
+    }
+
+    /// Returns a mutable [`Component`] of [`Entity`] by [`EntityId`], if it's present and not borrowed.
     ///
     /// # Examples
     /// ```
@@ -161,113 +237,393 @@ impl World {
     ///     // someting to do
     /// }
     ///
-    /// let mut world = World::builder()
-    ///     .with_component(MyComponent::default())
-    ///     .build();
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
     ///
-    /// world.add_component::<MyComponent>(EntityId::default());
+    /// world.add_component(entity_id, MyComponent::default());
     /// #
-    /// # assert!(world.has_component::<MyComponent>(EntityId::default()));
+    /// # assert!(world.has_component::<MyComponent>(entity_id));
     /// #
-    /// let component = world
-    ///     .get_component_mut::<MyComponent>(EntityId::default())
-    ///     .unwrap();
+    /// let component = world.get_component_mut::<MyComponent>(entity_id).unwrap();
     /// // component.something = ...
     /// ```
     #[inline]
-    pub fn get_component_mut<T: Component>(&mut self, entity_id: EntityId) -> Option<&mut T> {
-        self.component_registry.get_component_mut::<T>(entity_id)
+    pub fn get_component_mut<T: Component>(&self, entity_id: EntityId) -> Option<RefMut<'_, T>> {
+        self.try_get_component_mut::<T>(entity_id).ok()
     }
 
-    /// Adds a [`Entity`].
+    /// Returns a mutable [`Component`] of [`Entity`] by [`EntityId`].
     ///
     /// # Examples
     /// ```
     /// # use qinetic_ecs::prelude::*;
     /// #
-    /// #[derive(Default, Entity)]
-    /// struct MyEntity {
-    ///     // someting to do
-    /// }
+    /// #[derive(Default, Component)]
+    /// struct Health(u32);
+    ///
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
+    /// world.add_component(entity_id, Health(100));
+    ///
+    /// let health = world.try_get_component::<Health>(entity_id).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     world.try_get_component_mut::<Health>(entity_id),
+    ///     Err(WorldError::AlreadyBorrowed { .. })
+    /// ));
+    /// ```
+    #[inline]
+    pub fn try_get_component_mut<T: Component>(
+        &self,
+        entity_id: EntityId,
+    ) -> Result<RefMut<'_, T>, WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        self.component_registry
+            .try_get_component_mut::<T>(entity_id)
+    }
+
+    /// Adds a [`Entity`] with all [`Component`]s of the [`Bundle`] and returns it's [`EntityId`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Component)]
+    /// struct Position(f32, f32);
+    ///
+    /// #[derive(Default, Component)]
+    /// struct Velocity(f32, f32);
+    ///
+    /// let mut world = World::default();
+    /// let entity_id = world.spawn((Position(0.0, 0.0), Velocity(1.0, 0.0)));
+    /// #
+    /// # assert!(world.has_component::<Position>(entity_id));
+    /// # assert!(world.has_component::<Velocity>(entity_id));
+    /// ```
+    #[inline]
+    pub fn spawn<T: Bundle>(&mut self, bundle: T) -> EntityId {
+        let entity_id = self.add_entity();
+        bundle.insert(&mut self.component_registry, entity_id);
+        entity_id
+    }
+
+    /// Registers all [`Component`]s of the [`Bundle`] and returns their [`ComponentId`]s.
+    #[inline]
+    pub fn register_bundle<T: Bundle>(&mut self) -> Vec<ComponentId> {
+        let mut ids = Vec::new();
+        T::register(&mut self.component_registry, &mut ids);
+        ids
+    }
+
+    /// Adds all [`Component`]s of the [`Bundle`] to [`Entity`] by [`EntityId`], if it's present.
+    ///
+    /// Already present [`Component`]s are replaced.
+    #[inline]
+    pub fn insert_bundle<T: Bundle>(&mut self, entity_id: EntityId, bundle: T) -> &mut Self {
+        self.try_insert_bundle(entity_id, bundle).ok();
+        self
+    }
+
+    /// Adds all [`Component`]s of the [`Bundle`] to [`Entity`] by [`EntityId`].
     ///
-    /// let mut world = World::builder().build().add_entity::<MyEntity>();
+    /// Already present [`Component`]s are replaced.
+    pub fn try_insert_bundle<T: Bundle>(
+        &mut self,
+        entity_id: EntityId,
+        bundle: T,
+    ) -> Result<(), WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        bundle.insert(&mut self.component_registry, entity_id);
+        Ok(())
+    }
+
+    /// Removes all [`Component`]s of the [`Bundle`] from [`Entity`] by [`EntityId`], if they're present.
+    #[inline]
+    pub fn remove_bundle<T: Bundle>(&mut self, entity_id: EntityId) {
+        self.try_remove_bundle::<T>(entity_id).ok();
+    }
+
+    /// Removes all [`Component`]s of the [`Bundle`] from [`Entity`] by [`EntityId`].
+    ///
+    /// Missing [`Component`]s are skipped.
+    pub fn try_remove_bundle<T: Bundle>(&mut self, entity_id: EntityId) -> Result<(), WorldError> {
+        self.check_entity::<T>(entity_id)?;
+        T::remove(&mut self.component_registry, entity_id);
+        Ok(())
+    }
+
+    /// Adds a [`Entity`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
     /// #
-    /// # assert!(world.)
+    /// let mut world = World::default();
+    /// let entity_id = world.add_entity();
+    /// #
+    /// # assert!(world.has_entity(entity_id));
     /// ```
     #[inline]
     pub fn add_entity(&mut self) -> EntityId { self.entity_registry.add_entity() }
 
-    /// Removes a [`Entity`] by [`EntityId`].
+    /// Removes a [`Entity`] by [`EntityId`] with all of it's [`Component`]s, if it's present.
     #[inline]
-    pub fn remove_entity(&mut self, id: EntityId) { self.entity_registry.remove_entity(id); }
+    pub fn remove_entity(&mut self, id: EntityId) { self.try_remove_entity(id).ok(); }
+
+    /// Removes a [`Entity`] by [`EntityId`] with all of it's [`Component`]s.
+    #[inline]
+    pub fn try_remove_entity(&mut self, id: EntityId) -> Result<(), WorldError> {
+        self.entity_registry.try_remove_entity(id)?;
+        self.component_registry.remove_components(id);
+        Ok(())
+    }
 
     /// Returns `true`, if [`Entity`] by [`EntityId`] present.
     #[inline]
     pub fn has_entity(&self, id: EntityId) -> bool { self.entity_registry.has_entity(id) }
 
-    /// Returns a immutable [`Event`] by `T` of [`World`], if it's present.
+    /// Returns an iterator over [`EntityId`]s of all present `Entity`s.
     #[inline]
-    pub fn add_event<T: Event>(&self, event: T) -> Option<&T> {
-        self.event_registry.get_event::<T>()
+    pub fn entities(&self) -> impl Iterator<Item = EntityId> + '_ {
+        self.entity_registry.entities()
     }
 
-    /// Returns a immutable [`Event`] by `T` of [`World`], if it's present.
+    /// Registers [`Events`] by `T`, if they're not present.
     #[inline]
-    pub fn remove_event<T: Event>(&self) -> Option<&T> { self.event_registry.get_event::<T>() }
+    pub fn register_event<T: Event>(&mut self) -> &mut Self {
+        self.event_registry.register_event::<T>();
+        self
+    }
+
+    /// Sends a [`Event`], that is readable until the end of the frame.
+    ///
+    /// [`Events`] by `T` are registered, if they're not present.
+    #[inline]
+    pub fn add_event<T: Event>(&mut self, event: T) -> &mut Self {
+        self.event_registry.add_event(event);
+        self
+    }
+
+    /// Sends a [`Event`] by immutable [`World`], like from a [`System`](crate::system::System).
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Event)]
+    /// struct MyEvent;
+    ///
+    /// let mut world = World::default();
+    /// assert!(world.send_event(MyEvent).is_err());
+    ///
+    /// world.register_event::<MyEvent>();
+    /// assert!(world.send_event(MyEvent).is_ok());
+    /// assert!(world.has_event::<MyEvent>());
+    /// ```
+    #[inline]
+    pub fn send_event<T: Event>(&self, event: T) -> Result<(), WorldError> {
+        self.try_get_events_mut::<T>()?.send(event);
+        Ok(())
+    }
+
+    /// Returns a immutable [`Events`] by `T` of [`World`], if they're present and not mutably borrowed.
+    #[inline]
+    pub fn get_events<T: Event>(&self) -> Option<Ref<'_, Events<T>>> {
+        self.event_registry.get_events::<T>()
+    }
+
+    /// Returns a immutable [`Events`] by `T` of [`World`].
+    #[inline]
+    pub fn try_get_events<T: Event>(&self) -> Result<Ref<'_, Events<T>>, WorldError> {
+        self.event_registry.try_get_events::<T>()
+    }
+
+    /// Returns a mutable [`Events`] by `T` of [`World`], if they're present and not borrowed.
+    #[inline]
+    pub fn get_events_mut<T: Event>(&self) -> Option<RefMut<'_, Events<T>>> {
+        self.event_registry.get_events_mut::<T>()
+    }
 
-    /// Returns `true`, if [`Event`] by `T` present.
+    /// Returns a mutable [`Events`] by `T` of [`World`].
+    #[inline]
+    pub fn try_get_events_mut<T: Event>(&self) -> Result<RefMut<'_, Events<T>>, WorldError> {
+        self.event_registry.try_get_events_mut::<T>()
+    }
+
+    /// Returns `true`, if any [`Event`] by `T` is sent in the current frame.
     #[inline]
     pub fn has_event<T: Event>(&self) -> bool { self.event_registry.has_event::<T>() }
 
-    /// Returns a immutable [`Resource`] by `T` of [`World`], if it's present.
+    /// Returns `true`, if [`Events`] by `T` are registered.
     #[inline]
-    pub fn add_resource<T: Resource>(&self, resource: T) -> Option<&T> {
-        self.resource_registry.get_resource::<T>()
+    pub fn is_event_registered<T: Event>(&self) -> bool {
+        self.event_registry.is_event_registered::<T>()
     }
 
-    /// Returns a immutable [`Resource`] by `T` of [`World`], if it's present.
+    /// Removes all [`Event`]s, that are sent in the current frame.
+    ///
+    /// The `App` calls it at the end of each update.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Event)]
+    /// struct MyEvent;
+    ///
+    /// let mut world = World::default();
+    /// world.add_event(MyEvent);
+    /// world.clear_events();
+    ///
+    /// assert!(!world.has_event::<MyEvent>());
+    /// assert!(world.is_event_registered::<MyEvent>());
+    /// ```
     #[inline]
-    pub fn remove_resource<T: Resource>(&self) -> Option<&T> {
-        self.resource_registry.get_resource::<T>()
+    pub fn clear_events(&mut self) { self.event_registry.clear_events(); }
+
+    /// Adds a [`Resource`].
+    ///
+    /// If the [`Resource`] by `T`, was already present, it's replace.
+    #[inline]
+    pub fn add_resource<T: Resource>(&mut self, resource: T) -> &mut Self {
+        self.resource_registry.add_resource(resource);
+        self
+    }
+
+    /// Removes a [`Resource`] by `T`, if it's present.
+    #[inline]
+    pub fn remove_resource<T: Resource>(&mut self) {
+        self.resource_registry.remove_resource::<T>();
     }
 
-    /// Returns a immutable [`Resource`] by `T` of [`World`], if it's present.
+    /// Removes a [`Resource`] by `T` and returns it.
     #[inline]
-    pub fn get_resource<T: Resource>(&self) -> Option<&T> {
+    pub fn try_remove_resource<T: Resource>(&mut self) -> Result<T, WorldError> {
+        self.resource_registry.try_remove_resource::<T>()
+    }
+
+    /// Returns a immutable [`Resource`] by `T` of [`World`], if it's present and not mutably borrowed.
+    #[inline]
+    pub fn get_resource<T: Resource>(&self) -> Option<Ref<'_, T>> {
         self.resource_registry.get_resource::<T>()
     }
 
-    /// Returns a mutable [`Resource`] by `T` of [`World`], if it's present.
+    /// Returns a immutable [`Resource`] by `T` of [`World`].
+    #[inline]
+    pub fn try_get_resource<T: Resource>(&self) -> Result<Ref<'_, T>, WorldError> {
+        self.resource_registry.try_get_resource::<T>()
+    }
+
+    /// Returns a mutable [`Resource`] by `T` of [`World`], if it's present and not borrowed.
     #[inline]
-    pub fn get_resource_mut<T: Resource>(&mut self) -> Option<&mut T> {
+    pub fn get_resource_mut<T: Resource>(&self) -> Option<RefMut<'_, T>> {
         self.resource_registry.get_resource_mut::<T>()
     }
 
+    /// Returns a mutable [`Resource`] by `T` of [`World`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// #
+    /// #[derive(Default, Resource)]
+    /// struct Score(u32);
+    ///
+    /// let mut world = World::default();
+    /// world.add_resource(Score(0));
+    ///
+    /// let mut score = world.try_get_resource_mut::<Score>().unwrap();
+    /// score.0 += 1;
+    ///
+    /// assert_eq!(
+    ///     world.try_get_resource::<Score>().err(),
+    ///     Some(WorldError::AlreadyBorrowed {
+    ///         name: std::any::type_name::<Score>()
+    ///     })
+    /// );
+    /// ```
+    #[inline]
+    pub fn try_get_resource_mut<T: Resource>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        self.resource_registry.try_get_resource_mut::<T>()
+    }
+
     /// Returns `true`, if [`Resource`] by `T` present.
     #[inline]
     pub fn has_resource<T: Resource>(&self) -> bool { self.resource_registry.has_resource::<T>() }
 
-    /// Returns a immutable [`State`] by `T` of [`World`], if it's present.
+    /// Adds a [`State`].
+    ///
+    /// If the [`State`] by `T`, was already present, it's replace.
+    #[inline]
+    pub fn add_state<T: State>(&mut self, state: T) -> &mut Self {
+        self.state_registry.add_state(state);
+        self
+    }
+
+    /// Removes a [`State`] by `T`, if it's present.
     #[inline]
-    pub fn add_state<T: State>(&self, state: T) -> Option<&T> {
-        self.state_registry.get_state::<T>()
+    pub fn remove_state<T: State>(&mut self) { self.state_registry.remove_state::<T>(); }
+
+    /// Removes a [`State`] by `T` and returns it.
+    #[inline]
+    pub fn try_remove_state<T: State>(&mut self) -> Result<T, WorldError> {
+        self.state_registry.try_remove_state::<T>()
     }
 
-    /// Returns a immutable [`State`] by `T` of [`World`], if it's present.
+    /// Returns a immutable [`State`] by `T` of [`World`], if it's present and not mutably borrowed.
     #[inline]
-    pub fn remove_state<T: State>(&self) -> Option<&T> { self.state_registry.get_state::<T>() }
+    pub fn get_state<T: State>(&self) -> Option<Ref<'_, T>> { self.state_registry.get_state::<T>() }
 
-    /// Returns a immutable [`State`] by `T` of [`World`], if it's present.
+    /// Returns a immutable [`State`] by `T` of [`World`].
     #[inline]
-    pub fn get_state<T: State>(&self) -> Option<&T> { self.state_registry.get_state::<T>() }
+    pub fn try_get_state<T: State>(&self) -> Result<Ref<'_, T>, WorldError> {
+        self.state_registry.try_get_state::<T>()
+    }
 
-    /// Returns a mutable [`State`] by `T` of [`World`], if it's present.
+    /// Returns a mutable [`State`] by `T` of [`World`], if it's present and not borrowed.
     #[inline]
-    pub fn get_state_mut<T: State>(&mut self) -> Option<&mut T> {
+    pub fn get_state_mut<T: State>(&self) -> Option<RefMut<'_, T>> {
         self.state_registry.get_state_mut::<T>()
     }
 
+    /// Returns a mutable [`State`] by `T` of [`World`].
+    #[inline]
+    pub fn try_get_state_mut<T: State>(&self) -> Result<RefMut<'_, T>, WorldError> {
+        self.state_registry.try_get_state_mut::<T>()
+    }
+
     /// Returns `true`, if [`State`] by `T` present.
     #[inline]
     pub fn has_state<T: State>(&self) -> bool { self.state_registry.has_state::<T>() }
+
+    /// Returns a [`Commands`] queue of the [`World`], that is applied by [`World::apply_commands`].
+    #[inline]
+    pub fn commands(&self) -> Commands { self.commands.clone() }
+
+    /// Applies all queued [`Commands`] in order of addition.
+    ///
+    /// [`Commands`] added by applied ones are applied too.
+    pub fn apply_commands(&mut self) {
+        loop {
+            let commands = self.commands.take();
+            if commands.is_empty() {
+                break;
+            }
+            for command in commands {
+                command(self);
+            }
+        }
+    }
+
+    /// Returns [`WorldError::StaleEntity`] naming `T`, if [`Entity`] by [`EntityId`] doesn't present.
+    fn check_entity<T>(&self, entity_id: EntityId) -> Result<(), WorldError> {
+        if self.has_entity(entity_id) {
+            Ok(())
+        } else {
+            Err(WorldError::StaleEntity {
+                name: type_name::<T>(),
+                entity_id,
+            })
+        }
+    }
 }
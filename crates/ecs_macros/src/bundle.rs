@@ -0,0 +1,66 @@
+//! Bundle derive.
+
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{parse_macro_input, parse_quote, Data, DeriveInput, Error, Index};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    let path = crate::path();
+
+    let fields = match &ast.data {
+        | Data::Struct(data) => &data.fields,
+        | _ => {
+            return Error::new_spanned(&ast.ident, "Bundle can only be derived for structs.")
+                .to_compile_error()
+                .into();
+        },
+    };
+
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            match &field.ident {
+                | Some(ident) => ident.to_token_stream(),
+                | None => Index::from(i).to_token_stream(),
+            }
+        })
+        .collect::<Vec<_>>();
+
+    ast.generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote! { Self: Send + Sync + 'static });
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    (quote! {
+        impl #impl_generics #path::bundle::Bundle for #struct_name #type_generics #where_clause {
+            fn register(
+                registry: &mut #path::component::ComponentRegistry,
+                ids: &mut Vec<#path::component::ComponentId>,
+            ) {
+                #(<#types as #path::bundle::Bundle>::register(registry, ids);)*
+            }
+
+            fn insert(
+                self,
+                registry: &mut #path::component::ComponentRegistry,
+                entity_id: #path::entity::EntityId,
+            ) {
+                #(#path::bundle::Bundle::insert(self.#members, registry, entity_id);)*
+            }
+
+            fn remove(
+                registry: &mut #path::component::ComponentRegistry,
+                entity_id: #path::entity::EntityId,
+            ) {
+                #(<#types as #path::bundle::Bundle>::remove(registry, entity_id);)*
+            }
+        }
+    })
+    .into()
+}
@@ -9,8 +9,10 @@
 
 extern crate proc_macro;
 
+mod bundle;
 mod component;
 mod event;
+mod reflect;
 mod resource;
 mod state;
 mod system_label;
@@ -19,10 +21,24 @@ use proc_macro::TokenStream;
 use qinetic_utils::manifest::Manifest;
 use syn::Path;
 
+/// Generates an impl for [`Bundle`] trait.
+///
+/// Each field should implement [`Bundle`], so [`Component`]s and other [`Bundle`]s can be mixed.
+#[proc_macro_derive(Bundle)]
+pub fn derive_bundle(input: TokenStream) -> TokenStream { bundle::derive(input) }
+
 /// Generates an impl for [`Component`] trait.
 #[proc_macro_derive(Component)]
 pub fn derive_component(input: TokenStream) -> TokenStream { component::derive(input) }
 
+/// Generates an impl for [`Reflect`] trait.
+///
+/// Fields of structs are exposed by their names, other types are opaque values.
+/// The type is registered by `#[reflect(name = "...")]`, if it's present,
+/// otherwise by it's module path and ident, generic types are registered by [`std::any::type_name`].
+#[proc_macro_derive(Reflect, attributes(reflect))]
+pub fn derive_reflect(input: TokenStream) -> TokenStream { reflect::derive(input) }
+
 /// Generates an impl for [`Resource`] trait.
 #[proc_macro_derive(Resource)]
 pub fn derive_resource(input: TokenStream) -> TokenStream { resource::derive(input) }
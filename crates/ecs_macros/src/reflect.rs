@@ -0,0 +1,395 @@
+//! Reflect derive.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote, ToTokens};
+use syn::{
+    parse_macro_input,
+    parse_quote,
+    spanned::Spanned,
+    Data,
+    DeriveInput,
+    Field,
+    Fields,
+    Ident,
+    Index,
+    Lit,
+    LitStr,
+    Meta,
+    NestedMeta,
+    Path,
+};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let mut ast = parse_macro_input!(input as DeriveInput);
+    let path = crate::path();
+
+    let where_clause = ast.generics.make_where_clause();
+    where_clause
+        .predicates
+        .push(parse_quote! { Self: Send + Sync + 'static });
+    match &ast.data {
+        | Data::Struct(data) => {
+            for field in &data.fields {
+                let ty = &field.ty;
+                where_clause
+                    .predicates
+                    .push(parse_quote! { #ty: #path::reflect::Reflect });
+            }
+        },
+        | Data::Enum(data) => {
+            for field in data.variants.iter().flat_map(|variant| &variant.fields) {
+                let ty = &field.ty;
+                where_clause
+                    .predicates
+                    .push(parse_quote! { #ty: #path::reflect::Reflect + Default });
+            }
+        },
+        | Data::Union(_) => {},
+    }
+
+    let struct_name = &ast.ident;
+    let name = match type_name(&ast) {
+        | Ok(Some(name)) => name.to_token_stream(),
+        | Ok(None) if ast.generics.params.is_empty() => {
+            quote! { concat!(module_path!(), "::", stringify!(#struct_name)) }
+        },
+        | Ok(None) => quote! { std::any::type_name::<Self>() },
+        | Err(error) => return error.to_compile_error().into(),
+    };
+
+    let body = match &ast.data {
+        | Data::Struct(data) => derive_struct(&path, &name, &data.fields).to_token_stream(),
+        | Data::Enum(data) => {
+            derive_enum(&path, &name, data.variants.iter().collect()).to_token_stream()
+        },
+        | Data::Union(_) => derive_struct(&path, &name, &Fields::Unit).to_token_stream(),
+    };
+
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+
+    (quote! {
+        impl #impl_generics #path::reflect::Reflect for #struct_name #type_generics #where_clause {
+            #body
+
+            #[inline]
+            fn type_info(&self) -> #path::reflect::TypeInfo {
+                <Self as #path::reflect::Reflect>::info()
+            }
+
+            #[inline]
+            fn set(
+                &mut self,
+                value: Box<dyn #path::reflect::Reflect>,
+            ) -> Result<(), #path::reflect::ReflectError> {
+                #path::reflect::set_value(self, value)
+            }
+
+            #[inline]
+            fn as_any(&self) -> &dyn std::any::Any { self }
+
+            #[inline]
+            fn as_any_mut(&mut self) -> &mut dyn std::any::Any { self }
+
+            #[inline]
+            fn into_any(self: Box<Self>) -> Box<dyn std::any::Any> { self }
+
+            #[inline]
+            fn as_reflect(&self) -> &dyn #path::reflect::Reflect { self }
+
+            #[inline]
+            fn as_reflect_mut(&mut self) -> &mut dyn #path::reflect::Reflect { self }
+        }
+    })
+    .into()
+}
+
+/// Returns a name of the type from `#[reflect(name = "...")]`, if it's present.
+fn type_name(ast: &DeriveInput) -> syn::Result<Option<LitStr>> {
+    let mut name = None;
+    for attr in ast
+        .attrs
+        .iter()
+        .filter(|attr| attr.path.is_ident("reflect"))
+    {
+        let Meta::List(list) = attr.parse_meta()? else {
+            return Err(syn::Error::new(
+                attr.span(),
+                "expected `#[reflect(name = \"...\")]`",
+            ));
+        };
+        for nested in list.nested {
+            match nested {
+                | NestedMeta::Meta(Meta::NameValue(value)) if value.path.is_ident("name") => {
+                    let Lit::Str(lit) = value.lit else {
+                        return Err(syn::Error::new(value.lit.span(), "expected string literal"));
+                    };
+                    name = Some(lit);
+                },
+                | nested => {
+                    return Err(syn::Error::new(nested.span(), "unknown reflect attribute"))
+                },
+            }
+        }
+    }
+    Ok(name)
+}
+
+/// Returns names of `fields`, tuple fields are named by their indices.
+fn names<'a>(fields: impl IntoIterator<Item = &'a Field>) -> Vec<LitStr> {
+    fields
+        .into_iter()
+        .enumerate()
+        .map(|(i, field)| {
+            let name = match &field.ident {
+                | Some(ident) => ident.to_string(),
+                | None => i.to_string(),
+            };
+            LitStr::new(&name, field.span())
+        })
+        .collect()
+}
+
+/// Returns a [`ReflectValue`] expression of `bindings` of `fields`.
+fn value(path: &Path, fields: &Fields, names: &[LitStr], bindings: &[Ident]) -> impl ToTokens {
+    match fields {
+        | Fields::Unnamed(_) => {
+            quote! {
+                #path::value::ReflectValue::Seq(vec![
+                    #(#path::reflect::Reflect::to_value(#bindings)),*
+                ])
+            }
+        },
+        | _ => {
+            quote! {
+                #path::value::ReflectValue::Map(vec![
+                    #((#names.into(), #path::reflect::Reflect::to_value(#bindings))),*
+                ])
+            }
+        },
+    }
+}
+
+fn derive_struct(path: &Path, name: &impl ToTokens, fields: &Fields) -> impl ToTokens {
+    let types = fields.iter().map(|field| &field.ty).collect::<Vec<_>>();
+    let members = fields
+        .iter()
+        .enumerate()
+        .map(|(i, field)| {
+            match &field.ident {
+                | Some(ident) => ident.to_token_stream(),
+                | None => Index::from(i).to_token_stream(),
+            }
+        })
+        .collect::<Vec<_>>();
+    let names = names(fields);
+    let indices = (0..members.len()).collect::<Vec<_>>();
+    let len = members.len();
+    let bindings = (0..members.len())
+        .map(|i| format_ident!("field_{}", i))
+        .collect::<Vec<_>>();
+    let value = value(path, fields, &names, &bindings);
+
+    quote! {
+        fn info() -> #path::reflect::TypeInfo {
+            #path::reflect::TypeInfo::named::<Self>(#name, vec![
+                #(#path::reflect::FieldInfo::new::<#types>(#names)),*
+            ])
+        }
+
+        #[allow(unreachable_patterns)]
+        fn field(&self, name: &str) -> Option<&dyn #path::reflect::Reflect> {
+            match name {
+                #(#names => Some(&self.#members),)*
+                _ => None,
+            }
+        }
+
+        #[allow(unreachable_patterns)]
+        fn field_mut(&mut self, name: &str) -> Option<&mut dyn #path::reflect::Reflect> {
+            match name {
+                #(#names => Some(&mut self.#members),)*
+                _ => None,
+            }
+        }
+
+        #[inline]
+        fn field_len(&self) -> usize { #len }
+
+        #[allow(unreachable_patterns)]
+        fn field_at(&self, index: usize) -> Option<&dyn #path::reflect::Reflect> {
+            match index {
+                #(#indices => Some(&self.#members),)*
+                _ => None,
+            }
+        }
+
+        #[allow(unreachable_patterns)]
+        fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn #path::reflect::Reflect> {
+            match index {
+                #(#indices => Some(&mut self.#members),)*
+                _ => None,
+            }
+        }
+
+        fn to_value(&self) -> #path::value::ReflectValue {
+            #(let #bindings = &self.#members;)*
+            #value
+        }
+
+        #[inline]
+        fn apply_value(
+            &mut self,
+            value: &#path::value::ReflectValue,
+        ) -> Result<(), #path::reflect::ReflectError> {
+            #path::reflect::apply_fields(self, value)
+        }
+    }
+}
+
+fn derive_enum(path: &Path, name: &impl ToTokens, variants: Vec<&syn::Variant>) -> impl ToTokens {
+    let mut field_arms = Vec::new();
+    let mut field_at_arms = Vec::new();
+    let mut len_arms = Vec::new();
+    let mut value_arms = Vec::new();
+    let mut apply_arms = Vec::new();
+
+    for variant in variants {
+        let ident = &variant.ident;
+        let variant_name = LitStr::new(&ident.to_string(), ident.span());
+        let names = names(&variant.fields);
+        let bindings = (0..names.len())
+            .map(|i| format_ident!("field_{}", i))
+            .collect::<Vec<_>>();
+        let len = names.len();
+        let cfgs = variant
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("cfg"))
+            .collect::<Vec<_>>();
+
+        let (pattern, default) = match &variant.fields {
+            | Fields::Named(fields) => {
+                let idents = fields.named.iter().map(|field| &field.ident);
+                let defaults = fields.named.iter().map(|field| &field.ident);
+                (
+                    quote! { Self::#ident { #(#idents: #bindings),* } },
+                    quote! { Self::#ident { #(#defaults: Default::default()),* } },
+                )
+            },
+            | Fields::Unnamed(_) => {
+                let defaults = bindings.iter().map(|_| quote! { Default::default() });
+                (
+                    quote! { Self::#ident(#(#bindings),*) },
+                    quote! { Self::#ident(#(#defaults),*) },
+                )
+            },
+            | Fields::Unit => (quote! { Self::#ident }, quote! { Self::#ident }),
+        };
+
+        for (i, (name, binding)) in names.iter().zip(&bindings).enumerate() {
+            field_arms.push(quote! { #(#cfgs)* (#pattern, #name) => Some(#binding), });
+            field_at_arms.push(quote! { #(#cfgs)* (#pattern, #i) => Some(#binding), });
+        }
+        len_arms.push(quote! { #(#cfgs)* Self::#ident { .. } => #len, });
+
+        let value = match &variant.fields {
+            | Fields::Unit => quote! { #path::value::ReflectValue::String(#variant_name.into()) },
+            | fields => {
+                let fields = value(path, fields, &names, &bindings);
+                quote! { #path::value::ReflectValue::Map(vec![(#variant_name.into(), #fields)]) }
+            },
+        };
+        value_arms.push(quote! { #(#cfgs)* #pattern => #value, });
+
+        apply_arms.push(quote! {
+            #(#cfgs)*
+            #variant_name => {
+                if !matches!(self, Self::#ident { .. }) {
+                    *self = #default;
+                }
+            },
+        });
+    }
+
+    quote! {
+        fn info() -> #path::reflect::TypeInfo {
+            #path::reflect::TypeInfo::named::<Self>(#name, Vec::new())
+        }
+
+        #[allow(unused_variables, unreachable_patterns)]
+        fn field(&self, name: &str) -> Option<&dyn #path::reflect::Reflect> {
+            match (self, name) {
+                #(#field_arms)*
+                _ => None,
+            }
+        }
+
+        #[allow(unused_variables, unreachable_patterns)]
+        fn field_mut(&mut self, name: &str) -> Option<&mut dyn #path::reflect::Reflect> {
+            match (self, name) {
+                #(#field_arms)*
+                _ => None,
+            }
+        }
+
+        #[allow(unreachable_patterns)]
+        fn field_len(&self) -> usize {
+            match self {
+                #(#len_arms)*
+                _ => 0,
+            }
+        }
+
+        #[allow(unused_variables, unreachable_patterns)]
+        fn field_at(&self, index: usize) -> Option<&dyn #path::reflect::Reflect> {
+            match (self, index) {
+                #(#field_at_arms)*
+                _ => None,
+            }
+        }
+
+        #[allow(unused_variables, unreachable_patterns)]
+        fn field_at_mut(&mut self, index: usize) -> Option<&mut dyn #path::reflect::Reflect> {
+            match (self, index) {
+                #(#field_at_arms)*
+                _ => None,
+            }
+        }
+
+        #[allow(unreachable_patterns)]
+        fn to_value(&self) -> #path::value::ReflectValue {
+            match self {
+                #(#value_arms)*
+                _ => #path::value::ReflectValue::Unit,
+            }
+        }
+
+        fn apply_value(
+            &mut self,
+            value: &#path::value::ReflectValue,
+        ) -> Result<(), #path::reflect::ReflectError> {
+            let (variant, fields) = match value {
+                #path::value::ReflectValue::String(variant) => (variant, None),
+                #path::value::ReflectValue::Map(entries) if entries.len() == 1 => {
+                    (&entries[0].0, Some(&entries[0].1))
+                },
+                _ => return Err(value.invalid::<Self>()),
+            };
+
+            match variant.as_str() {
+                #(#apply_arms)*
+                _ => {
+                    return Err(#path::reflect::ReflectError::NoVariant {
+                        type_name: #name,
+                        variant: variant.clone(),
+                    })
+                },
+            }
+
+            match fields {
+                Some(fields) => #path::reflect::apply_fields(self, fields),
+                None => Ok(()),
+            }
+        }
+    }
+}
@@ -0,0 +1,936 @@
+//! Action mapping functionality.
+
+use std::{
+    collections::{BTreeMap, HashMap},
+    fs,
+    path::Path,
+};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use ron::ser::PrettyConfig;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    axis::Axis,
+    button::{init_resource, Input},
+    error::InputError,
+    gamepad::{Gamepad, GamepadAxis, GamepadAxisType, GamepadButton, GamepadButtonType},
+    keyboard::{KeyCode, ScanCode},
+    mouse::MouseButton,
+};
+
+/// Modifier key, that matches both left and right keys.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Modifier {
+    /// The `Shift` key.
+    Shift,
+
+    /// The `Control` key.
+    Control,
+
+    /// The `Alt` key. Maps to `Option` on Mac.
+    Alt,
+
+    /// The `Windows` key. Maps to `Command` on Mac.
+    Super,
+}
+
+impl Modifier {
+    /// Returns left and right [`KeyCode`]s of the modifier.
+    pub const fn keys(&self) -> [KeyCode; 2] {
+        match self {
+            | Modifier::Shift => [KeyCode::LShift, KeyCode::RShift],
+            | Modifier::Control => [KeyCode::LControl, KeyCode::RControl],
+            | Modifier::Alt => [KeyCode::LAlt, KeyCode::RAlt],
+            | Modifier::Super => [KeyCode::LWin, KeyCode::RWin],
+        }
+    }
+}
+
+/// Physical source of the [`Action`] value.
+///
+/// Buttons have value `1.0`, if they're pressed, and `0.0` otherwise.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputSource {
+    /// The key by [`KeyCode`].
+    Key(KeyCode),
+
+    /// The key by [`ScanCode`], that doesn't depend on the keyboard layout.
+    ScanCode(ScanCode),
+
+    /// The [`MouseButton`].
+    Mouse(MouseButton),
+
+    /// The button of the player's [`Gamepad`].
+    GamepadButton(GamepadButtonType),
+
+    /// The axis of the player's [`Gamepad`].
+    GamepadAxis(GamepadAxisType),
+}
+
+impl InputSource {
+    /// Returns a value of the source from `raw` input.
+    ///
+    /// If `gamepad` is `None`, any [`Gamepad`] is used.
+    pub fn value(&self, raw: &RawInput, gamepad: Option<Gamepad>) -> f32 {
+        let pressed = |pressed: bool| if pressed { 1.0 } else { 0.0 };
+        match *self {
+            | InputSource::Key(key) => pressed(raw.keys.pressed(key)),
+            | InputSource::ScanCode(scancode) => pressed(raw.scancodes.pressed(scancode)),
+            | InputSource::Mouse(button) => pressed(raw.mouse_buttons.pressed(button)),
+            | InputSource::GamepadButton(button_type) => {
+                pressed(match gamepad {
+                    | Some(gamepad) => {
+                        raw.gamepad_buttons
+                            .pressed(GamepadButton::new(gamepad, button_type))
+                    },
+                    | None => {
+                        raw.gamepad_buttons
+                            .get_pressed()
+                            .any(|button| button.button_type() == button_type)
+                    },
+                })
+            },
+            | InputSource::GamepadAxis(axis_type) => {
+                match gamepad {
+                    | Some(gamepad) => raw.gamepad_axes.value(GamepadAxis::new(gamepad, axis_type)),
+                    | None => {
+                        raw.gamepad_axes
+                            .iter()
+                            .filter(|(axis, _)| axis.axis_type() == axis_type)
+                            .map(|(_, value)| value)
+                            .max_by(|a, b| a.abs().total_cmp(&b.abs()))
+                            .unwrap_or_default()
+                    },
+                }
+            },
+        }
+    }
+}
+
+macro_rules! impl_from_for_source {
+    ($($ty: ty => $variant: ident),* $(,)?) => {
+        $(
+            impl From<$ty> for InputSource {
+                #[inline]
+                fn from(value: $ty) -> Self { InputSource::$variant(value) }
+            }
+
+            impl From<$ty> for InputBinding {
+                #[inline]
+                fn from(value: $ty) -> Self { InputBinding::Single(InputSource::$variant(value)) }
+            }
+        )*
+    };
+}
+
+impl_from_for_source!(
+    KeyCode => Key,
+    ScanCode => ScanCode,
+    MouseButton => Mouse,
+    GamepadButtonType => GamepadButton,
+    GamepadAxisType => GamepadAxis,
+);
+
+/// Binding of the [`Action`] to [`InputSource`]s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let jump = InputBinding::from(KeyCode::Space);
+/// let save = InputBinding::modified([Modifier::Control], KeyCode::S);
+/// let sprint_jump = InputBinding::chord([KeyCode::LShift, KeyCode::Space]);
+/// let movement = InputBinding::wasd();
+/// let look = InputBinding::Axis2d {
+///     x: GamepadAxisType::RightStickX,
+///     y: GamepadAxisType::RightStickY,
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InputBinding {
+    /// The single source.
+    Single(InputSource),
+
+    /// The sources, that should be active together, with the value of the weakest one.
+    ///
+    /// The active chord takes priority over bindings, that are subsets of it, see [`InputMap::update`].
+    Chord(Vec<InputSource>),
+
+    /// The source, that is active only with all of the modifiers.
+    ///
+    /// It's a chord of the source and pressed modifier keys.
+    Modified {
+        /// The modifiers, that should be pressed.
+        modifiers: Vec<Modifier>,
+
+        /// The source of the value.
+        source: InputSource,
+    },
+
+    /// The virtual 2D axis from four sources, like `WASD` keys.
+    VirtualAxis2d {
+        /// The source of positive `y`.
+        up: InputSource,
+
+        /// The source of negative `y`.
+        down: InputSource,
+
+        /// The source of negative `x`.
+        left: InputSource,
+
+        /// The source of positive `x`.
+        right: InputSource,
+    },
+
+    /// The 2D axis from two axes of the player's [`Gamepad`], like a stick.
+    Axis2d {
+        /// The axis of `x`.
+        x: GamepadAxisType,
+
+        /// The axis of `y`.
+        y: GamepadAxisType,
+    },
+}
+
+impl InputBinding {
+    /// Returns a [`InputBinding::Chord`] of `sources`.
+    pub fn chord<T: Into<InputSource>>(sources: impl IntoIterator<Item = T>) -> Self {
+        InputBinding::Chord(sources.into_iter().map(Into::into).collect())
+    }
+
+    /// Returns a [`InputBinding::Modified`] of `source` with `modifiers`.
+    pub fn modified(
+        modifiers: impl IntoIterator<Item = Modifier>,
+        source: impl Into<InputSource>,
+    ) -> Self {
+        InputBinding::Modified {
+            modifiers: modifiers.into_iter().collect(),
+            source: source.into(),
+        }
+    }
+
+    /// Returns a [`InputBinding::VirtualAxis2d`] of `up`, `down`, `left` and `right` sources.
+    pub fn virtual_axis(
+        up: impl Into<InputSource>,
+        down: impl Into<InputSource>,
+        left: impl Into<InputSource>,
+        right: impl Into<InputSource>,
+    ) -> Self {
+        InputBinding::VirtualAxis2d {
+            up: up.into(),
+            down: down.into(),
+            left: left.into(),
+            right: right.into(),
+        }
+    }
+
+    /// Returns a [`InputBinding::VirtualAxis2d`] of `WASD` keys.
+    #[inline]
+    pub fn wasd() -> Self { Self::virtual_axis(KeyCode::W, KeyCode::S, KeyCode::A, KeyCode::D) }
+
+    /// Returns a [`InputBinding::VirtualAxis2d`] of arrow keys.
+    #[inline]
+    pub fn arrows() -> Self {
+        Self::virtual_axis(KeyCode::Up, KeyCode::Down, KeyCode::Left, KeyCode::Right)
+    }
+
+    /// Returns [`InputSource`]s, that are held together for the binding in `raw` input.
+    ///
+    /// Axes have no such sources, so they neither take priority, nor are overridden by chords.
+    fn held_sources(&self, raw: &RawInput) -> Vec<InputSource> {
+        match self {
+            | InputBinding::Single(source) => vec![*source],
+            | InputBinding::Chord(sources) => sources.clone(),
+            | InputBinding::Modified { modifiers, source } => {
+                modifiers
+                    .iter()
+                    .flat_map(Modifier::keys)
+                    .filter(|key| raw.keys.pressed(*key))
+                    .map(InputSource::Key)
+                    .chain([*source])
+                    .collect()
+            },
+            | InputBinding::VirtualAxis2d { .. } | InputBinding::Axis2d { .. } => Vec::new(),
+        }
+    }
+
+    /// Returns a raw 2D value of the binding from `raw` input.
+    pub fn value(&self, raw: &RawInput, gamepad: Option<Gamepad>) -> (f32, f32) {
+        let value = |source: &InputSource| source.value(raw, gamepad);
+        match self {
+            | InputBinding::Single(source) => (value(source), 0.0),
+            | InputBinding::Chord(sources) => {
+                let min = sources.iter().map(value).fold(f32::INFINITY, f32::min);
+                (if min.is_finite() { min } else { 0.0 }, 0.0)
+            },
+            | InputBinding::Modified { modifiers, source } => {
+                let active = modifiers
+                    .iter()
+                    .all(|modifier| raw.keys.any_pressed(modifier.keys()));
+                (if active { value(source) } else { 0.0 }, 0.0)
+            },
+            | InputBinding::VirtualAxis2d {
+                up,
+                down,
+                left,
+                right,
+            } => (value(right) - value(left), value(up) - value(down)),
+            | InputBinding::Axis2d { x, y } => {
+                (
+                    value(&InputSource::GamepadAxis(*x)),
+                    value(&InputSource::GamepadAxis(*y)),
+                )
+            },
+        }
+    }
+}
+
+impl From<InputSource> for InputBinding {
+    #[inline]
+    fn from(source: InputSource) -> Self { InputBinding::Single(source) }
+}
+
+/// Response curve of the [`Action`] value magnitude in `0.0..=1.0`.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SensitivityCurve {
+    /// The value is unchanged.
+    #[default]
+    Linear,
+
+    /// The value is squared, that gives more precision near the center.
+    Quadratic,
+
+    /// The value is cubed, that gives even more precision near the center.
+    Cubic,
+
+    /// The value is raised to the power.
+    Power(f32),
+}
+
+impl SensitivityCurve {
+    /// Returns a `value` in `0.0..=1.0` after the curve.
+    pub fn apply(&self, value: f32) -> f32 {
+        match *self {
+            | SensitivityCurve::Linear => value,
+            | SensitivityCurve::Quadratic => value * value,
+            | SensitivityCurve::Cubic => value * value * value,
+            | SensitivityCurve::Power(power) => value.powf(power),
+        }
+    }
+}
+
+/// Logical action, like `Jump`, `Move` or `Look`, bound to [`InputBinding`]s.
+///
+/// The value of the action is the strongest value of it's bindings,
+/// that is clamped to the unit circle, filtered by the radial dead zone,
+/// shaped by the [`SensitivityCurve`] and scaled by the sensitivity.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let look = Action::default()
+///     .with_binding(InputBinding::Axis2d {
+///         x: GamepadAxisType::RightStickX,
+///         y: GamepadAxisType::RightStickY,
+///     })
+///     .with_dead_zone(0.2)
+///     .with_sensitivity(2.0)
+///     .with_curve(SensitivityCurve::Quadratic);
+///
+/// assert_eq!(look.bindings().len(), 1);
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, CopyGetters, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Action {
+    /// The bindings of the action.
+    #[getset(get = "pub")]
+    bindings: Vec<InputBinding>,
+
+    /// The radial dead zone of the value magnitude in `0.0..1.0`.
+    #[default(0.1)]
+    #[getset(get_copy = "pub")]
+    dead_zone: f32,
+
+    /// The multiplier of the value.
+    #[default(1.0)]
+    #[getset(get_copy = "pub")]
+    sensitivity: f32,
+
+    /// The response curve of the value.
+    #[getset(get_copy = "pub")]
+    curve: SensitivityCurve,
+}
+
+impl Action {
+    /// Returns a [`Action`] with added `binding`.
+    #[inline]
+    pub fn with_binding(mut self, binding: impl Into<InputBinding>) -> Self {
+        self.bind(binding);
+        self
+    }
+
+    /// Returns a [`Action`] with `dead_zone`.
+    #[inline]
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Returns a [`Action`] with `sensitivity`.
+    #[inline]
+    pub fn with_sensitivity(mut self, sensitivity: f32) -> Self {
+        self.sensitivity = sensitivity;
+        self
+    }
+
+    /// Returns a [`Action`] with `curve`.
+    #[inline]
+    pub fn with_curve(mut self, curve: SensitivityCurve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Adds a `binding`.
+    #[inline]
+    pub fn bind(&mut self, binding: impl Into<InputBinding>) { self.bindings.push(binding.into()); }
+
+    /// Replaces a binding by `index` with `binding` and returns the previous one.
+    /// If `index` is out of bindings, the `binding` is added.
+    pub fn rebind(
+        &mut self,
+        index: usize,
+        binding: impl Into<InputBinding>,
+    ) -> Option<InputBinding> {
+        match self.bindings.get_mut(index) {
+            | Some(previous) => Some(std::mem::replace(previous, binding.into())),
+            | None => {
+                self.bind(binding);
+                None
+            },
+        }
+    }
+
+    /// Removes a binding by `index` and returns it, if it's present.
+    pub fn unbind(&mut self, index: usize) -> Option<InputBinding> {
+        (index < self.bindings.len()).then(|| self.bindings.remove(index))
+    }
+
+    /// Returns a processed 2D value of the action from `raw` input.
+    #[inline]
+    pub fn value(&self, raw: &RawInput, gamepad: Option<Gamepad>) -> (f32, f32) {
+        self.value_with_chords(raw, gamepad, &[])
+    }
+
+    /// Returns a processed 2D value of the action from `raw` input,
+    /// skipping bindings, that are subsets of active `chords`.
+    fn value_with_chords(
+        &self,
+        raw: &RawInput,
+        gamepad: Option<Gamepad>,
+        chords: &[Vec<InputSource>],
+    ) -> (f32, f32) {
+        let magnitude = |(x, y): (f32, f32)| x.hypot(y);
+        let (x, y) = self
+            .bindings
+            .iter()
+            .filter(|binding| {
+                let sources = binding.held_sources(raw);
+                sources.is_empty()
+                    || !chords.iter().any(|chord| {
+                        sources.len() < chord.len()
+                            && sources.iter().all(|source| chord.contains(source))
+                    })
+            })
+            .map(|binding| binding.value(raw, gamepad))
+            .max_by(|a, b| magnitude(*a).total_cmp(&magnitude(*b)))
+            .unwrap_or((0.0, 0.0));
+
+        let length = magnitude((x, y));
+        if length <= self.dead_zone {
+            return (0.0, 0.0);
+        }
+        let scaled = ((length.min(1.0) - self.dead_zone)
+            / (1.0 - self.dead_zone).max(f32::EPSILON))
+        .clamp(0.0, 1.0);
+        let factor = self.curve.apply(scaled) * self.sensitivity / length;
+        (x * factor, y * factor)
+    }
+}
+
+/// Per-frame state of the [`Action`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct ActionState {
+    /// Whether the action is active.
+    pressed: bool,
+
+    /// Whether the action became active in this frame.
+    just_pressed: bool,
+
+    /// Whether the action became inactive in this frame.
+    just_released: bool,
+
+    /// The processed 2D value of the action.
+    axis_pair: (f32, f32),
+}
+
+impl ActionState {
+    /// Returns a 1D value of the action, that is `x` of the [`ActionState::axis_pair`].
+    #[inline]
+    pub fn value(&self) -> f32 { self.axis_pair.0 }
+
+    fn update(&mut self, axis_pair: (f32, f32)) {
+        let pressed = axis_pair != (0.0, 0.0);
+        self.just_pressed = pressed && !self.pressed;
+        self.just_released = !pressed && self.pressed;
+        self.pressed = pressed;
+        self.axis_pair = axis_pair;
+    }
+}
+
+/// Named set of [`Action`]s, like `gameplay` or `menu`.
+#[derive(SmartDefault, Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct InputContext {
+    actions: BTreeMap<String, Action>,
+}
+
+impl InputContext {
+    /// Returns a [`InputContext`] with added `action` by `name`.
+    /// If the action by `name`, was already present, it's replace.
+    #[inline]
+    pub fn with_action(mut self, name: impl Into<String>, action: Action) -> Self {
+        self.actions.insert(name.into(), action);
+        self
+    }
+
+    /// Returns a [`Action`] by `name`, if it's present.
+    #[inline]
+    pub fn action(&self, name: &str) -> Option<&Action> { self.actions.get(name) }
+
+    /// Returns a mutable [`Action`] by `name`, if it's present.
+    #[inline]
+    pub fn action_mut(&mut self, name: &str) -> Option<&mut Action> { self.actions.get_mut(name) }
+
+    /// Returns an iterator over names and [`Action`]s.
+    #[inline]
+    pub fn actions(&self) -> impl Iterator<Item = (&str, &Action)> {
+        self.actions
+            .iter()
+            .map(|(name, action)| (name.as_str(), action))
+    }
+
+    /// Returns held sources of active bindings, that combine several sources, like chords.
+    fn active_chords(&self, raw: &RawInput, gamepad: Option<Gamepad>) -> Vec<Vec<InputSource>> {
+        self.actions
+            .values()
+            .flat_map(|action| &action.bindings)
+            .filter(|binding| binding.value(raw, gamepad) != (0.0, 0.0))
+            .map(|binding| binding.held_sources(raw))
+            .filter(|sources| sources.len() > 1)
+            .collect()
+    }
+}
+
+/// Input of the player, that has an active [`InputContext`] and an optional [`Gamepad`].
+#[derive(SmartDefault, Clone, Debug, Getters, CopyGetters)]
+pub struct PlayerInput {
+    /// The name of the active [`InputContext`].
+    #[getset(get = "pub")]
+    context: String,
+
+    /// The [`Gamepad`] of the player, if it's `None`, any gamepad is used.
+    #[getset(get_copy = "pub")]
+    gamepad: Option<Gamepad>,
+
+    actions: HashMap<String, ActionState>,
+}
+
+impl PlayerInput {
+    /// Returns a [`ActionState`] of the action by `name`.
+    /// If the action isn't present, returns a released state.
+    #[inline]
+    pub fn action(&self, name: &str) -> ActionState {
+        self.actions.get(name).copied().unwrap_or_default()
+    }
+
+    /// Returns `true`, if the action by `name` is active.
+    #[inline]
+    pub fn pressed(&self, name: &str) -> bool { self.action(name).pressed() }
+
+    /// Returns `true`, if the action by `name` became active in this frame.
+    #[inline]
+    pub fn just_pressed(&self, name: &str) -> bool { self.action(name).just_pressed() }
+
+    /// Returns `true`, if the action by `name` became inactive in this frame.
+    #[inline]
+    pub fn just_released(&self, name: &str) -> bool { self.action(name).just_released() }
+
+    /// Returns a 1D value of the action by `name`.
+    #[inline]
+    pub fn value(&self, name: &str) -> f32 { self.action(name).value() }
+
+    /// Returns a 2D value of the action by `name`.
+    #[inline]
+    pub fn axis_pair(&self, name: &str) -> (f32, f32) { self.action(name).axis_pair() }
+}
+
+/// Action map [`Resource`], that binds named [`Action`]s of [`InputContext`]s to raw input,
+/// and keeps [`ActionState`]s of each player.
+///
+/// Contexts can be saved to and loaded from a RON config file, so rebinding persists.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mut map = InputMap::default()
+///     .with_context(
+///         "gameplay",
+///         InputContext::default()
+///             .with_action(
+///                 "Jump",
+///                 Action::default()
+///                     .with_binding(KeyCode::Space)
+///                     .with_binding(GamepadButtonType::A),
+///             )
+///             .with_action("Move", Action::default().with_binding(InputBinding::wasd())),
+///     )
+///     .with_player(0, "gameplay");
+///
+/// let mut keys = Input::<KeyCode>::default();
+/// keys.press(KeyCode::Space);
+/// keys.press(KeyCode::D);
+///
+/// let (scancodes, mouse_buttons) = (Input::default(), Input::default());
+/// let (gamepad_buttons, gamepad_axes) = (Input::default(), Axis::default());
+/// let raw = RawInput::new(
+///     &keys,
+///     &scancodes,
+///     &mouse_buttons,
+///     &gamepad_buttons,
+///     &gamepad_axes,
+/// );
+/// map.update(&raw);
+///
+/// let player = map.player(0).unwrap();
+/// assert!(player.just_pressed("Jump"));
+/// assert_eq!(player.axis_pair("Move"), (1.0, 0.0));
+///
+/// map.rebind("gameplay", "Jump", 0, KeyCode::J).unwrap();
+/// let config = map.to_config().unwrap();
+/// let loaded = InputMap::from_config(&config).unwrap();
+/// assert_eq!(
+///     loaded
+///         .context("gameplay")
+///         .unwrap()
+///         .action("Jump")
+///         .unwrap()
+///         .bindings()[0],
+///     InputBinding::from(KeyCode::J),
+/// );
+/// ```
+#[derive(SmartDefault, Clone, Debug, Resource, Serialize, Deserialize)]
+pub struct InputMap {
+    contexts: BTreeMap<String, InputContext>,
+
+    #[serde(skip)]
+    players: BTreeMap<usize, PlayerInput>,
+}
+
+impl InputMap {
+    /// Returns a [`InputMap`] with added `context` by `name`.
+    /// If the context by `name`, was already present, it's replace.
+    #[inline]
+    pub fn with_context(mut self, name: impl Into<String>, context: InputContext) -> Self {
+        self.contexts.insert(name.into(), context);
+        self
+    }
+
+    /// Returns a [`InputMap`] with added `player` with active `context`.
+    #[inline]
+    pub fn with_player(mut self, player: usize, context: impl Into<String>) -> Self {
+        self.set_player_context(player, context);
+        self
+    }
+
+    /// Returns a [`InputContext`] by `name`, if it's present.
+    #[inline]
+    pub fn context(&self, name: &str) -> Option<&InputContext> { self.contexts.get(name) }
+
+    /// Returns a mutable [`InputContext`] by `name`, if it's present.
+    #[inline]
+    pub fn context_mut(&mut self, name: &str) -> Option<&mut InputContext> {
+        self.contexts.get_mut(name)
+    }
+
+    /// Returns a [`PlayerInput`] of the `player`, if it's present.
+    #[inline]
+    pub fn player(&self, player: usize) -> Option<&PlayerInput> { self.players.get(&player) }
+
+    /// Sets an active `context` of the `player`.
+    /// If the `player` isn't present, it's added.
+    pub fn set_player_context(&mut self, player: usize, context: impl Into<String>) {
+        self.players.entry(player).or_default().context = context.into();
+    }
+
+    /// Sets a `gamepad` of the `player`, if it's `None`, any gamepad is used.
+    /// If the `player` isn't present, it's added.
+    pub fn set_player_gamepad(&mut self, player: usize, gamepad: Option<Gamepad>) {
+        self.players.entry(player).or_default().gamepad = gamepad;
+    }
+
+    /// Removes the `player`.
+    #[inline]
+    pub fn remove_player(&mut self, player: usize) { self.players.remove(&player); }
+
+    /// Replaces a binding by `index` of the `action` in the `context` with `binding`.
+    /// If `index` is out of bindings, the `binding` is added.
+    pub fn rebind(
+        &mut self,
+        context: &str,
+        action: &str,
+        index: usize,
+        binding: impl Into<InputBinding>,
+    ) -> Result<(), InputError> {
+        self.contexts
+            .get_mut(context)
+            .ok_or_else(|| {
+                InputError::ContextNotFound {
+                    context: context.into(),
+                }
+            })?
+            .action_mut(action)
+            .ok_or_else(|| {
+                InputError::ActionNotFound {
+                    action: action.into(),
+                }
+            })?
+            .rebind(index, binding);
+        Ok(())
+    }
+
+    /// Updates [`ActionState`]s of all players from `raw` input.
+    ///
+    /// Active chords and modified bindings take priority over bindings, that are subsets of them,
+    /// so `LShift + Space` of one action doesn't activate `Space` of another one.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let mut map = InputMap::default()
+    ///     .with_context(
+    ///         "gameplay",
+    ///         InputContext::default()
+    ///             .with_action("Jump", Action::default().with_binding(KeyCode::Space))
+    ///             .with_action(
+    ///                 "Dash",
+    ///                 Action::default()
+    ///                     .with_binding(InputBinding::chord([KeyCode::LShift, KeyCode::Space])),
+    ///             ),
+    ///     )
+    ///     .with_player(0, "gameplay");
+    ///
+    /// let mut keys = Input::<KeyCode>::default();
+    /// let (scancodes, mouse_buttons) = (Input::default(), Input::default());
+    /// let (gamepad_buttons, gamepad_axes) = (Input::default(), Axis::default());
+    ///
+    /// keys.press(KeyCode::LShift);
+    /// keys.press(KeyCode::Space);
+    /// map.update(&RawInput::new(
+    ///     &keys,
+    ///     &scancodes,
+    ///     &mouse_buttons,
+    ///     &gamepad_buttons,
+    ///     &gamepad_axes,
+    /// ));
+    ///
+    /// let player = map.player(0).unwrap();
+    /// assert!(player.pressed("Dash"));
+    /// assert!(!player.pressed("Jump"));
+    ///
+    /// keys.release(KeyCode::LShift);
+    /// map.update(&RawInput::new(
+    ///     &keys,
+    ///     &scancodes,
+    ///     &mouse_buttons,
+    ///     &gamepad_buttons,
+    ///     &gamepad_axes,
+    /// ));
+    ///
+    /// let player = map.player(0).unwrap();
+    /// assert!(player.just_released("Dash"));
+    /// assert!(player.just_pressed("Jump"));
+    /// ```
+    pub fn update(&mut self, raw: &RawInput) {
+        for player in self.players.values_mut() {
+            let context = self.contexts.get(&player.context);
+            for (name, state) in player.actions.iter_mut() {
+                if context.and_then(|context| context.action(name)).is_none() {
+                    state.update((0.0, 0.0));
+                }
+            }
+            let chords = context
+                .map(|context| context.active_chords(raw, player.gamepad))
+                .unwrap_or_default();
+            for (name, action) in context.into_iter().flat_map(InputContext::actions) {
+                let value = action.value_with_chords(raw, player.gamepad, &chords);
+                player
+                    .actions
+                    .entry(name.to_string())
+                    .or_default()
+                    .update(value);
+            }
+        }
+    }
+
+    /// Returns a RON config of contexts.
+    pub fn to_config(&self) -> Result<String, InputError> {
+        ron::ser::to_string_pretty(self, PrettyConfig::default()).map_err(|error| {
+            InputError::InvalidConfig {
+                reason: error.to_string(),
+            }
+        })
+    }
+
+    /// Returns a [`InputMap`] from the RON `config` of contexts.
+    pub fn from_config(config: &str) -> Result<Self, InputError> {
+        ron::from_str(config).map_err(|error| {
+            InputError::InvalidConfig {
+                reason: error.to_string(),
+            }
+        })
+    }
+
+    /// Saves contexts to the RON config file by `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), InputError> {
+        let path = path.as_ref();
+        fs::write(path, self.to_config()?).map_err(|error| {
+            InputError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })
+    }
+
+    /// Loads contexts from the RON config file by `path`, keeping players.
+    /// Contexts of the file replace present ones with the same name, others are kept.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), InputError> {
+        let path = path.as_ref();
+        let config = fs::read_to_string(path).map_err(|error| {
+            InputError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })?;
+        self.contexts.extend(Self::from_config(&config)?.contexts);
+        Ok(())
+    }
+}
+
+/// Raw input state, that [`Action`]s are evaluated from.
+#[derive(Clone, Copy, Debug)]
+pub struct RawInput<'a> {
+    keys: &'a Input<KeyCode>,
+    scancodes: &'a Input<ScanCode>,
+    mouse_buttons: &'a Input<MouseButton>,
+    gamepad_buttons: &'a Input<GamepadButton>,
+    gamepad_axes: &'a Axis<GamepadAxis>,
+}
+
+impl<'a> RawInput<'a> {
+    /// Returns a [`RawInput`] of input state resources.
+    #[inline]
+    pub fn new(
+        keys: &'a Input<KeyCode>,
+        scancodes: &'a Input<ScanCode>,
+        mouse_buttons: &'a Input<MouseButton>,
+        gamepad_buttons: &'a Input<GamepadButton>,
+        gamepad_axes: &'a Axis<GamepadAxis>,
+    ) -> Self {
+        Self {
+            keys,
+            scancodes,
+            mouse_buttons,
+            gamepad_buttons,
+            gamepad_axes,
+        }
+    }
+}
+
+/// [`SystemData`], that updates the [`InputMap`] resource from input state resources.
+///
+/// The update happens while fetching, because [`SystemData`] can't borrow the [`World`].
+/// If the [`InputMap`] resource isn't present, nothing happens.
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct ActionUpdate {}
+
+impl SystemData for ActionUpdate {
+    fn access(access: &mut SystemAccess) {
+        access
+            .read_resource::<Input<KeyCode>>()
+            .read_resource::<Input<ScanCode>>()
+            .read_resource::<Input<MouseButton>>()
+            .read_resource::<Input<GamepadButton>>()
+            .read_resource::<Axis<GamepadAxis>>()
+            .write_resource::<InputMap>();
+    }
+
+    fn fetch(world: &mut World) -> Self {
+        if !world.has_resource::<InputMap>() {
+            return Self::default();
+        }
+        init_resource::<Input<KeyCode>>(world);
+        init_resource::<Input<ScanCode>>(world);
+        init_resource::<Input<MouseButton>>(world);
+        init_resource::<Input<GamepadButton>>(world);
+        init_resource::<Axis<GamepadAxis>>(world);
+
+        if let (
+            Some(mut map),
+            Some(keys),
+            Some(scancodes),
+            Some(mouse),
+            Some(buttons),
+            Some(axes),
+        ) = (
+            world.get_resource_mut::<InputMap>(),
+            world.get_resource::<Input<KeyCode>>(),
+            world.get_resource::<Input<ScanCode>>(),
+            world.get_resource::<Input<MouseButton>>(),
+            world.get_resource::<Input<GamepadButton>>(),
+            world.get_resource::<Axis<GamepadAxis>>(),
+        ) {
+            map.update(&RawInput::new(&keys, &scancodes, &mouse, &buttons, &axes));
+        }
+        Self::default()
+    }
+}
+
+/// Action [`System`], that updates the [`InputMap`] resource.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_resource(InputMap::default())
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::PreUpdate, ActionSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct ActionSystem {}
+
+impl System for ActionSystem {
+    type Data = ActionUpdate;
+
+    #[allow(unused_variables)]
+    fn run(&mut self, data: Self::Data) {}
+}
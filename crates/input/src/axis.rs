@@ -0,0 +1,74 @@
+//! Axis functionality.
+
+use std::collections::HashMap;
+
+use qinetic_ecs::prelude::*;
+
+use crate::button::InputButton;
+
+/// State [`Resource`] of analog axes, like sticks and triggers.
+///
+/// The state is updated in [`InputStage::PreUpdate`](crate::stages::InputStage::PreUpdate)
+/// from the [`AxisEvent`]s, and keeps the last value of each axis.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let gamepad = Gamepad::builder().with_id(0).build().unwrap();
+/// let stick = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+///
+/// let mut axis = Axis::<GamepadAxis>::default();
+/// axis.set(stick, 0.5);
+///
+/// assert_eq!(axis.get(stick), Some(0.5));
+/// assert_eq!(
+///     axis.value(GamepadAxis::new(gamepad, GamepadAxisType::LeftStickY)),
+///     0.0
+/// );
+/// ```
+#[derive(Clone, Debug, Resource)]
+pub struct Axis<T: InputButton> {
+    values: HashMap<T, f32>,
+}
+
+impl<T: InputButton> Default for Axis<T> {
+    fn default() -> Self {
+        Self {
+            values: HashMap::new(),
+        }
+    }
+}
+
+impl<T: InputButton> Axis<T> {
+    /// Sets a `value` of the `axis`.
+    #[inline]
+    pub fn set(&mut self, axis: T, value: f32) { self.values.insert(axis, value); }
+
+    /// Returns a value of the `axis`, if it's present.
+    #[inline]
+    pub fn get(&self, axis: T) -> Option<f32> { self.values.get(&axis).copied() }
+
+    /// Returns a value of the `axis`, or `0.0`, if it's absent.
+    #[inline]
+    pub fn value(&self, axis: T) -> f32 { self.get(axis).unwrap_or_default() }
+
+    /// Removes the `axis` and returns it's value, if it's present.
+    #[inline]
+    pub fn remove(&mut self, axis: T) -> Option<f32> { self.values.remove(&axis) }
+
+    /// Returns an iterator over axes and their values.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (T, f32)> + '_ {
+        self.values.iter().map(|(axis, value)| (*axis, *value))
+    }
+}
+
+/// Axis [`Event`], that updates the [`Axis<A>`](Axis) resource.
+pub trait AxisEvent<A: InputButton>: Event + Copy {
+    /// Returns an axis of the [`Event`], if it's known.
+    fn input_axis(&self) -> Option<A>;
+
+    /// Returns a value of the axis.
+    fn input_value(&self) -> f32;
+}
@@ -0,0 +1,746 @@
+//! Gamepad backend functionality.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::{
+    button::{init_resource, ButtonState},
+    gamepad::*,
+    mapping::{GamepadGuid, GamepadMapping, GamepadMappings, MappedInput},
+    recording::InputReplay,
+};
+
+/// Info of the gamepad device, that is reported by the [`GamepadBackend`] on connection.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters)]
+pub struct GamepadDeviceInfo {
+    /// The name of the device.
+    #[getset(get = "pub")]
+    name: String,
+
+    /// The [`GamepadGuid`] of the device, that selects it's [`GamepadMapping`].
+    #[getset(get_copy = "pub")]
+    guid: GamepadGuid,
+}
+
+impl GamepadDeviceInfo {
+    /// Returns a [`GamepadDeviceInfo`] of the device with `name` and `guid`.
+    #[inline]
+    pub fn new(name: impl Into<String>, guid: GamepadGuid) -> Self {
+        Self {
+            name: name.into(),
+            guid,
+        }
+    }
+}
+
+/// Raw event of the gamepad device, that is produced by the [`GamepadBackend`].
+///
+/// Devices are numbered by the backend, buttons, axes and hats are numbered in SDL order,
+/// so they're mapped by [`GamepadMappings`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum RawGamepadEvent {
+    /// The device was connected.
+    Connected {
+        /// The number of the device.
+        device: usize,
+
+        /// The [`GamepadDeviceInfo`] of the device.
+        info: GamepadDeviceInfo,
+    },
+
+    /// The device was disconnected.
+    Disconnected {
+        /// The number of the device.
+        device: usize,
+    },
+
+    /// The button of the device was pressed or released.
+    Button {
+        /// The number of the device.
+        device: usize,
+
+        /// The index of the button.
+        index: u16,
+
+        /// The state of the button.
+        pressed: bool,
+    },
+
+    /// The axis of the device was moved.
+    Axis {
+        /// The number of the device.
+        device: usize,
+
+        /// The index of the axis.
+        index: u16,
+
+        /// The value of the axis in `-1.0..=1.0`.
+        value: f32,
+    },
+
+    /// The hat of the device was moved.
+    Hat {
+        /// The number of the device.
+        device: usize,
+
+        /// The index of the hat.
+        index: u8,
+
+        /// The directions of the hat,
+        /// `1` is up, `2` is right, `4` is down and `8` is left.
+        value: u8,
+    },
+}
+
+/// Backend of gamepad devices, that polls their [`RawGamepadEvent`]s.
+///
+/// Backends are added to the [`GamepadBackends`] resource,
+/// like [`MockGamepadBackend`] for tests, or `EvdevGamepadBackend` on Linux with `evdev` feature.
+pub trait GamepadBackend: Send + Sync + 'static {
+    /// Returns [`RawGamepadEvent`]s since the last poll, without blocking.
+    fn poll(&mut self) -> Vec<RawGamepadEvent>;
+}
+
+/// Mock [`GamepadBackend`] of virtual devices, that tests input without a physical controller.
+///
+/// Clones share devices, so a clone can drive the backend, after it's added to [`GamepadBackends`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mock = MockGamepadBackend::default();
+///
+/// let mut world = World::new();
+/// world.add_resource(GamepadBackends::default().with_backend(mock.clone()));
+///
+/// let guid = "030000005e0400008e02000010010000".parse().unwrap();
+/// let device = mock.connect(GamepadDeviceInfo::new("Xbox 360 Controller", guid));
+/// mock.press(device, 0);
+/// mock.move_axis(device, 2, 1.0);
+/// mock.move_hat(device, 0, 1);
+///
+/// GamepadBackendUpdate::fetch(&mut world);
+///
+/// let gamepad = world
+///     .get_resource::<Gamepads>()
+///     .unwrap()
+///     .iter()
+///     .next()
+///     .unwrap();
+/// let input = world.get_resource::<Input<GamepadButton>>().unwrap();
+/// assert!(input.just_pressed(GamepadButton::new(gamepad, GamepadButtonType::A)));
+/// assert!(input.pressed(GamepadButton::new(gamepad, GamepadButtonType::LeftTrigger2)));
+/// assert!(input.pressed(GamepadButton::new(gamepad, GamepadButtonType::DPadUp)));
+/// drop(input);
+///
+/// mock.disconnect(device);
+/// GamepadBackendUpdate::fetch(&mut world);
+///
+/// assert!(world.get_resource::<Gamepads>().unwrap().is_empty());
+/// assert!(!world
+///     .get_resource::<Input<GamepadButton>>()
+///     .unwrap()
+///     .pressed(GamepadButton::new(gamepad, GamepadButtonType::A)));
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct MockGamepadBackend {
+    state: Arc<Mutex<MockState>>,
+}
+
+#[derive(SmartDefault, Debug)]
+struct MockState {
+    events: Vec<RawGamepadEvent>,
+    next_device: usize,
+}
+
+impl MockGamepadBackend {
+    /// Connects a virtual device with `info` and returns it's number.
+    pub fn connect(&self, info: GamepadDeviceInfo) -> usize {
+        let mut state = self.state.lock().unwrap();
+        let device = state.next_device;
+        state.next_device += 1;
+        state
+            .events
+            .push(RawGamepadEvent::Connected { device, info });
+        device
+    }
+
+    /// Disconnects the virtual `device`.
+    #[inline]
+    pub fn disconnect(&self, device: usize) { self.send(RawGamepadEvent::Disconnected { device }); }
+
+    /// Presses the button by `index` of the virtual `device`.
+    #[inline]
+    pub fn press(&self, device: usize, index: u16) {
+        self.send(RawGamepadEvent::Button {
+            device,
+            index,
+            pressed: true,
+        });
+    }
+
+    /// Releases the button by `index` of the virtual `device`.
+    #[inline]
+    pub fn release(&self, device: usize, index: u16) {
+        self.send(RawGamepadEvent::Button {
+            device,
+            index,
+            pressed: false,
+        });
+    }
+
+    /// Moves the axis by `index` of the virtual `device` to `value` in `-1.0..=1.0`.
+    #[inline]
+    pub fn move_axis(&self, device: usize, index: u16, value: f32) {
+        self.send(RawGamepadEvent::Axis {
+            device,
+            index,
+            value,
+        });
+    }
+
+    /// Moves the hat by `index` of the virtual `device` to directions of `value`.
+    #[inline]
+    pub fn move_hat(&self, device: usize, index: u8, value: u8) {
+        self.send(RawGamepadEvent::Hat {
+            device,
+            index,
+            value,
+        });
+    }
+
+    /// Sends a raw `event`.
+    #[inline]
+    pub fn send(&self, event: RawGamepadEvent) { self.state.lock().unwrap().events.push(event); }
+}
+
+impl GamepadBackend for MockGamepadBackend {
+    fn poll(&mut self) -> Vec<RawGamepadEvent> {
+        std::mem::take(&mut self.state.lock().unwrap().events)
+    }
+}
+
+/// State of the connected device.
+#[derive(Clone, Debug)]
+struct DeviceState {
+    gamepad: Gamepad,
+    mapping: GamepadMapping,
+    hats: HashMap<u8, u8>,
+}
+
+/// [`Resource`] of [`GamepadBackend`]s, that assigns [`Gamepad`]s to their devices.
+///
+/// A disconnected [`Gamepad`] is reused by the next connected device.
+#[derive(SmartDefault, Derivative, Resource)]
+#[derivative(Debug)]
+pub struct GamepadBackends {
+    #[derivative(Debug = "ignore")]
+    backends: Vec<Box<dyn GamepadBackend>>,
+
+    devices: HashMap<(usize, usize), DeviceState>,
+}
+
+impl GamepadBackends {
+    /// Returns a [`GamepadBackends`] with added `backend`.
+    #[inline]
+    pub fn with_backend(mut self, backend: impl GamepadBackend) -> Self {
+        self.add_backend(backend);
+        self
+    }
+
+    /// Adds a `backend`.
+    #[inline]
+    pub fn add_backend(&mut self, backend: impl GamepadBackend) {
+        self.backends.push(Box::new(backend));
+    }
+
+    /// Returns a number of [`GamepadBackend`]s.
+    #[inline]
+    pub fn len(&self) -> usize { self.backends.len() }
+
+    /// Returns `true`, if no [`GamepadBackend`] is present.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.backends.is_empty() }
+
+    /// Returns a free [`Gamepad`] with the lowest id.
+    fn free_gamepad(&self) -> Gamepad {
+        let id = (0..)
+            .find(|id| self.devices.values().all(|state| state.gamepad.id() != *id))
+            .unwrap_or_default();
+        Gamepad::builder().with_id(id).build().unwrap()
+    }
+
+    /// Polls backends and applies their events to the `world`.
+    fn update(&mut self, world: &mut World) {
+        for backend in 0..self.backends.len() {
+            for event in self.backends[backend].poll() {
+                self.apply(world, backend, event);
+            }
+        }
+    }
+
+    fn apply(&mut self, world: &mut World, backend: usize, event: RawGamepadEvent) {
+        let inputs = match event {
+            | RawGamepadEvent::Connected { device, info } => {
+                let gamepad = match self.devices.get(&(backend, device)) {
+                    | Some(state) => state.gamepad,
+                    | None => self.free_gamepad(),
+                };
+                let mapping = world
+                    .get_resource::<GamepadMappings>()
+                    .map(|mappings| mappings.get_or_raw(info.guid, &info.name))
+                    .unwrap_or_else(|| GamepadMapping::raw(info.guid, &info.name));
+                self.devices.insert(
+                    (backend, device),
+                    DeviceState {
+                        gamepad,
+                        mapping,
+                        hats: HashMap::new(),
+                    },
+                );
+                if let Some(mut gamepads) = world.get_resource_mut::<Gamepads>() {
+                    gamepads.set_name(gamepad, info.name);
+                }
+                send_gamepad_event(world, gamepad, GamepadEventType::Connected);
+                return;
+            },
+            | RawGamepadEvent::Disconnected { device } => {
+                let Some(state) = self.devices.remove(&(backend, device)) else {
+                    return;
+                };
+                let gamepad = state.gamepad;
+                // Events of the gamepad in this frame would be applied after it's disconnected.
+                if let Some(mut events) = world.get_events_mut::<GamepadButtonEvent>() {
+                    events.retain(|event| event.gamepad() != gamepad);
+                }
+                if let Some(mut events) = world.get_events_mut::<GamepadAxisEvent>() {
+                    events.retain(|event| event.gamepad() != gamepad);
+                }
+                send_gamepad_event(world, gamepad, GamepadEventType::Disconnected);
+                return;
+            },
+            | RawGamepadEvent::Button {
+                device,
+                index,
+                pressed,
+            } => {
+                let Some(state) = self.devices.get(&(backend, device)) else {
+                    return;
+                };
+                (state.gamepad, state.mapping.map_button(index, pressed))
+            },
+            | RawGamepadEvent::Axis {
+                device,
+                index,
+                value,
+            } => {
+                let Some(state) = self.devices.get(&(backend, device)) else {
+                    return;
+                };
+                (state.gamepad, state.mapping.map_axis(index, value))
+            },
+            | RawGamepadEvent::Hat {
+                device,
+                index,
+                value,
+            } => {
+                let Some(state) = self.devices.get_mut(&(backend, device)) else {
+                    return;
+                };
+                let previous = state.hats.insert(index, value).unwrap_or_default();
+                (state.gamepad, state.mapping.map_hat(index, previous, value))
+            },
+        };
+
+        let (gamepad, inputs) = inputs;
+        for input in inputs {
+            match input {
+                | MappedInput::Button {
+                    button_type,
+                    pressed,
+                    value,
+                } => {
+                    let mut builder = GamepadButtonEvent::builder();
+                    builder
+                        .with_gamepad(gamepad)
+                        .with_button_type(button_type)
+                        .with_state(match pressed {
+                            | true => ButtonState::Pressed,
+                            | false => ButtonState::Released,
+                        });
+                    if let Some(value) = value {
+                        builder.with_value(value);
+                    }
+                    let event = builder.build().unwrap();
+                    world.add_event(event);
+                    apply_button_event(world, event);
+                },
+                | MappedInput::Axis { axis_type, value } => {
+                    let event = GamepadAxisEvent::builder()
+                        .with_gamepad(gamepad)
+                        .with_axis_type(axis_type)
+                        .with_value(value)
+                        .build()
+                        .unwrap();
+                    world.add_event(event);
+                    apply_axis_event(world, event);
+                },
+            }
+        }
+    }
+}
+
+fn send_gamepad_event(world: &mut World, gamepad: Gamepad, event_type: GamepadEventType) {
+    let event = GamepadEvent::builder()
+        .with_gamepad(gamepad)
+        .with_event_type(event_type)
+        .build()
+        .unwrap();
+    world.add_event(event);
+    apply_gamepad_event(world, event);
+}
+
+/// [`SystemData`], that polls the [`GamepadBackends`] resource, if it's present,
+/// and applies all [`RawGamepadEvent`]s of the frame, mapped by the [`GamepadMappings`] resource.
+///
+/// Each event is sent and applied to gamepad state resources immediately,
+/// so none of them is lost, when a frame has many events of the same type.
+///
+/// Backends aren't polled, while the [`InputReplay`] resource is playing,
+/// so their events are applied after the replay.
+///
+/// The update happens while fetching, because [`SystemData`] can't borrow the [`World`].
+/// If the resources aren't present, they're added.
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct GamepadBackendUpdate {}
+
+impl SystemData for GamepadBackendUpdate {
+    fn access(access: &mut SystemAccess) {
+        gamepad_access(access);
+        access
+            .read_resource::<InputReplay>()
+            .write_resource::<GamepadMappings>()
+            .write_resource::<GamepadBackends>();
+    }
+
+    fn fetch(world: &mut World) -> Self {
+        init_gamepad_resources(world);
+        init_resource::<GamepadMappings>(world);
+        if world
+            .get_resource::<InputReplay>()
+            .is_some_and(|replay| replay.is_playing())
+        {
+            return Self::default();
+        }
+        if let Ok(mut backends) = world.try_remove_resource::<GamepadBackends>() {
+            backends.update(world);
+            world.add_resource(backends);
+        }
+        Self::default()
+    }
+}
+
+/// Gamepad backend [`System`], that polls [`GamepadBackends`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_resource(GamepadBackends::default().with_backend(MockGamepadBackend::default()))
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::PreUpdate, GamepadBackendSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct GamepadBackendSystem {}
+
+impl System for GamepadBackendSystem {
+    type Data = GamepadBackendUpdate;
+
+    #[allow(unused_variables)]
+    fn run(&mut self, data: Self::Data) {}
+}
+
+#[cfg(all(target_os = "linux", feature = "evdev"))]
+pub use self::evdev_backend::EvdevGamepadBackend;
+
+#[cfg(all(target_os = "linux", feature = "evdev"))]
+mod evdev_backend {
+    use std::{
+        collections::HashSet,
+        fs,
+        path::{Path, PathBuf},
+        sync::{
+            atomic::{AtomicBool, Ordering},
+            mpsc::{self, Receiver, Sender},
+            Arc,
+            Mutex,
+        },
+        thread,
+        time::Duration,
+    };
+
+    use evdev::{AbsoluteAxisType, Device, InputEventKind, Key};
+
+    use super::{GamepadBackend, GamepadDeviceInfo, RawGamepadEvent};
+    use crate::mapping::GamepadGuid;
+
+    /// Interval of scanning the input directory for connected devices.
+    const SCAN_INTERVAL: Duration = Duration::from_secs(1);
+
+    const BTN_MISC: u16 = 0x100;
+    const BTN_JOYSTICK: u16 = 0x120;
+    const BTN_GAMEPAD_END: u16 = 0x140;
+    const KEY_MAX: u16 = 0x2FF;
+    const ABS_HAT0X: u16 = 0x10;
+    const ABS_HAT3Y: u16 = 0x17;
+    const ABS_MAX: u16 = 0x3F;
+
+    /// Linux [`GamepadBackend`], that reads joystick devices of `/dev/input` by evdev.
+    ///
+    /// Devices are scanned each second for hotplug, and read by their own threads.
+    /// Buttons, axes and hats are numbered like SDL does, so SDL mappings apply.
+    ///
+    /// The user needs a read access to `/dev/input/event*`, usually by the `input` group.
+    #[derive(Debug)]
+    pub struct EvdevGamepadBackend {
+        receiver: Mutex<Receiver<RawGamepadEvent>>,
+        alive: Arc<AtomicBool>,
+    }
+
+    impl Default for EvdevGamepadBackend {
+        fn default() -> Self { Self::with_dir("/dev/input") }
+    }
+
+    impl EvdevGamepadBackend {
+        /// Returns a [`EvdevGamepadBackend`], that scans `dir` for `event*` devices,
+        /// like a directory of uinput virtual devices in tests.
+        pub fn with_dir(dir: impl Into<PathBuf>) -> Self {
+            let (sender, receiver) = mpsc::channel();
+            let alive = Arc::new(AtomicBool::new(true));
+            let dir = dir.into();
+            let scanner_alive = alive.clone();
+            thread::Builder::new()
+                .name("qinetic_evdev".to_string())
+                .spawn(move || scan(&dir, &sender, &scanner_alive))
+                .expect("Failed to spawn evdev scanner thread");
+            Self {
+                receiver: Mutex::new(receiver),
+                alive,
+            }
+        }
+    }
+
+    impl Drop for EvdevGamepadBackend {
+        fn drop(&mut self) { self.alive.store(false, Ordering::Relaxed); }
+    }
+
+    impl GamepadBackend for EvdevGamepadBackend {
+        fn poll(&mut self) -> Vec<RawGamepadEvent> {
+            self.receiver.lock().unwrap().try_iter().collect()
+        }
+    }
+
+    /// Scans `dir` for new gamepad devices, while the backend is `alive`.
+    fn scan(dir: &Path, sender: &Sender<RawGamepadEvent>, alive: &AtomicBool) {
+        let opened = Arc::new(Mutex::new(HashSet::<PathBuf>::new()));
+        let mut ignored = HashSet::new();
+        let mut next_device = 0;
+        while alive.load(Ordering::Relaxed) {
+            let mut paths = fs::read_dir(dir)
+                .map(|entries| {
+                    entries
+                        .filter_map(Result::ok)
+                        .map(|entry| entry.path())
+                        .filter(|path| {
+                            path.file_name()
+                                .and_then(|name| name.to_str())
+                                .is_some_and(|name| name.starts_with("event"))
+                        })
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            paths.sort();
+            ignored.retain(|path| paths.contains(path));
+
+            for path in paths {
+                if ignored.contains(&path) || opened.lock().unwrap().contains(&path) {
+                    continue;
+                }
+                let Some(device) = Device::open(&path).ok().filter(is_gamepad) else {
+                    ignored.insert(path);
+                    continue;
+                };
+                opened.lock().unwrap().insert(path.clone());
+                let (sender, opened, id) = (sender.clone(), opened.clone(), next_device);
+                next_device += 1;
+                thread::Builder::new()
+                    .name(format!("qinetic_evdev_{id}"))
+                    .spawn(move || {
+                        read(device, id, &sender);
+                        opened.lock().unwrap().remove(&path);
+                    })
+                    .expect("Failed to spawn evdev reader thread");
+            }
+            thread::sleep(SCAN_INTERVAL);
+        }
+    }
+
+    /// Returns `true`, if the `device` has joystick or gamepad buttons.
+    fn is_gamepad(device: &Device) -> bool {
+        device.supported_absolute_axes().is_some()
+            && device.supported_keys().is_some_and(|keys| {
+                keys.iter()
+                    .any(|key| (BTN_JOYSTICK..BTN_GAMEPAD_END).contains(&key.code()))
+            })
+    }
+
+    /// Layout of the device, that numbers it's inputs in SDL order.
+    struct Layout {
+        buttons: Vec<u16>,
+        axes: Vec<(u16, i32, i32)>,
+        hats: [(i32, i32); 4],
+    }
+
+    impl Layout {
+        fn new(device: &Device) -> Self {
+            let buttons = (BTN_JOYSTICK..=KEY_MAX)
+                .chain(BTN_MISC..BTN_JOYSTICK)
+                .filter(|code| {
+                    device
+                        .supported_keys()
+                        .is_some_and(|keys| keys.contains(Key::new(*code)))
+                })
+                .collect();
+            let state = device.get_abs_state().ok();
+            let axes = (0..=ABS_MAX)
+                .filter(|code| !(ABS_HAT0X..=ABS_HAT3Y).contains(code))
+                .filter(|code| {
+                    device
+                        .supported_absolute_axes()
+                        .is_some_and(|axes| axes.contains(AbsoluteAxisType(*code)))
+                })
+                .map(|code| {
+                    let info = state.as_ref().map(|state| state[code as usize]);
+                    let (minimum, maximum) =
+                        info.map_or((-1, 1), |info| (info.minimum, info.maximum));
+                    (code, minimum, maximum)
+                })
+                .collect();
+            Self {
+                buttons,
+                axes,
+                hats: [(0, 0); 4],
+            }
+        }
+
+        fn button(&self, code: u16) -> Option<u16> {
+            self.buttons
+                .iter()
+                .position(|button| *button == code)
+                .map(|index| index as u16)
+        }
+
+        fn axis(&self, code: u16, value: i32) -> Option<(u16, f32)> {
+            let index = self.axes.iter().position(|(axis, ..)| *axis == code)?;
+            let (_, minimum, maximum) = self.axes[index];
+            let range = (maximum - minimum).max(1) as f32;
+            let value = (value - minimum) as f32 / range * 2.0 - 1.0;
+            Some((index as u16, value.clamp(-1.0, 1.0)))
+        }
+
+        fn hat(&mut self, code: u16, value: i32) -> Option<(u8, u8)> {
+            if !(ABS_HAT0X..=ABS_HAT3Y).contains(&code) {
+                return None;
+            }
+            let index = (code - ABS_HAT0X) / 2;
+            let hat = &mut self.hats[index as usize];
+            match (code - ABS_HAT0X) % 2 {
+                | 0 => hat.0 = value.signum(),
+                | _ => hat.1 = value.signum(),
+            }
+            let mask = match hat.1 {
+                | -1 => 1,
+                | 1 => 4,
+                | _ => 0,
+            } | match hat.0 {
+                | 1 => 2,
+                | -1 => 8,
+                | _ => 0,
+            };
+            Some((index as u8, mask))
+        }
+    }
+
+    /// Reads events of the `device` until it's disconnected, or the backend is dropped.
+    fn read(mut device: Device, id: usize, sender: &Sender<RawGamepadEvent>) {
+        let input_id = device.input_id();
+        let guid = GamepadGuid::from_ids(
+            input_id.bus_type().0,
+            input_id.vendor(),
+            input_id.product(),
+            input_id.version(),
+        );
+        let info = GamepadDeviceInfo::new(device.name().unwrap_or_default(), guid);
+        if sender
+            .send(RawGamepadEvent::Connected { device: id, info })
+            .is_err()
+        {
+            return;
+        }
+
+        let mut layout = Layout::new(&device);
+        'read: while let Ok(events) = device.fetch_events() {
+            for event in events {
+                let raw = match event.kind() {
+                    | InputEventKind::Key(key) => {
+                        layout.button(key.code()).map(|index| {
+                            RawGamepadEvent::Button {
+                                device: id,
+                                index,
+                                pressed: event.value() != 0,
+                            }
+                        })
+                    },
+                    | InputEventKind::AbsAxis(axis) => {
+                        match layout.hat(axis.0, event.value()) {
+                            | Some((index, value)) => {
+                                Some(RawGamepadEvent::Hat {
+                                    device: id,
+                                    index,
+                                    value,
+                                })
+                            },
+                            | None => {
+                                layout.axis(axis.0, event.value()).map(|(index, value)| {
+                                    RawGamepadEvent::Axis {
+                                        device: id,
+                                        index,
+                                        value,
+                                    }
+                                })
+                            },
+                        }
+                    },
+                    | _ => None,
+                };
+                if let Some(raw) = raw {
+                    if sender.send(raw).is_err() {
+                        break 'read;
+                    }
+                }
+            }
+        }
+        let _ = sender.send(RawGamepadEvent::Disconnected { device: id });
+    }
+}
@@ -1,9 +1,13 @@
 //! Button functionality.
 
+use std::{collections::HashSet, hash::Hash, marker::PhantomData};
+
+use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
 
 /// `Press` state of the `button`.
-#[derive(SmartDefault, Clone, Copy, Debug, Hash, PartialEq, Eq)]
+#[derive(SmartDefault, Clone, Copy, Debug, Hash, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ButtonState {
     /// The `button` is `pressed`.
     Pressed,
@@ -36,3 +40,236 @@ impl ButtonState {
     #[inline(always)]
     pub const fn is_released(&self) -> bool { matches!(self, ButtonState::Released) }
 }
+
+/// Button, that can be tracked by the [`Input`] resource.
+pub trait InputButton: Copy + Eq + Hash + Send + Sync + 'static {}
+
+impl<T: Copy + Eq + Hash + Send + Sync + 'static> InputButton for T {}
+
+/// Per-frame state [`Resource`] of buttons.
+///
+/// The state is updated in [`InputStage::PreUpdate`](crate::stages::InputStage::PreUpdate)
+/// from the [`ButtonEvent`]s, so `just` states last one frame.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mut input = Input::<KeyCode>::default();
+/// input.press(KeyCode::W);
+///
+/// assert!(input.pressed(KeyCode::W));
+/// assert!(input.just_pressed(KeyCode::W));
+///
+/// input.clear();
+/// assert!(input.pressed(KeyCode::W));
+/// assert!(!input.just_pressed(KeyCode::W));
+///
+/// input.release(KeyCode::W);
+/// assert!(input.just_released(KeyCode::W));
+/// assert_eq!(input.get_pressed().count(), 0);
+/// ```
+#[derive(Clone, Debug, Resource)]
+pub struct Input<T: InputButton> {
+    pressed: HashSet<T>,
+    just_pressed: HashSet<T>,
+    just_released: HashSet<T>,
+}
+
+impl<T: InputButton> Default for Input<T> {
+    fn default() -> Self {
+        Self {
+            pressed: HashSet::new(),
+            just_pressed: HashSet::new(),
+            just_released: HashSet::new(),
+        }
+    }
+}
+
+impl<T: InputButton> Input<T> {
+    /// Registers a press of the `button`.
+    pub fn press(&mut self, button: T) {
+        if self.pressed.insert(button) {
+            self.just_pressed.insert(button);
+        }
+    }
+
+    /// Registers a release of the `button`.
+    pub fn release(&mut self, button: T) {
+        if self.pressed.remove(&button) {
+            self.just_released.insert(button);
+        }
+    }
+
+    /// Registers a release of all pressed buttons.
+    pub fn release_all(&mut self) { self.just_released.extend(self.pressed.drain()); }
+
+    /// Registers a press or release of the `button` by `state`.
+    #[inline]
+    pub fn set(&mut self, button: T, state: ButtonState) {
+        match state {
+            | ButtonState::Pressed => self.press(button),
+            | ButtonState::Released => self.release(button),
+        }
+    }
+
+    /// Returns `true`, if the `button` is pressed.
+    #[inline]
+    pub fn pressed(&self, button: T) -> bool { self.pressed.contains(&button) }
+
+    /// Returns `true`, if any of `buttons` is pressed.
+    #[inline]
+    pub fn any_pressed(&self, buttons: impl IntoIterator<Item = T>) -> bool {
+        buttons.into_iter().any(|button| self.pressed(button))
+    }
+
+    /// Returns `true`, if the `button` was pressed in this frame.
+    #[inline]
+    pub fn just_pressed(&self, button: T) -> bool { self.just_pressed.contains(&button) }
+
+    /// Returns `true`, if the `button` was released in this frame.
+    #[inline]
+    pub fn just_released(&self, button: T) -> bool { self.just_released.contains(&button) }
+
+    /// Returns an iterator over pressed buttons.
+    #[inline]
+    pub fn get_pressed(&self) -> impl Iterator<Item = T> + '_ { self.pressed.iter().copied() }
+
+    /// Returns an iterator over buttons, that were pressed in this frame.
+    #[inline]
+    pub fn get_just_pressed(&self) -> impl Iterator<Item = T> + '_ {
+        self.just_pressed.iter().copied()
+    }
+
+    /// Returns an iterator over buttons, that were released in this frame.
+    #[inline]
+    pub fn get_just_released(&self) -> impl Iterator<Item = T> + '_ {
+        self.just_released.iter().copied()
+    }
+
+    /// Registers presses and releases of [`ButtonEvent`]s in order of sending.
+    ///
+    /// [`ButtonEvent`]s of unknown buttons are skipped.
+    pub fn apply<E: ButtonEvent<T>>(&mut self, events: &[E]) {
+        for event in events {
+            if let Some(button) = event.input_button() {
+                self.set(button, event.input_state());
+            }
+        }
+    }
+
+    /// Clears `just` states, keeping pressed buttons.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+    }
+}
+
+/// Button [`Event`], that updates the [`Input<B>`](Input) resource.
+///
+/// The [`Event`] can correspond to several button types, like [`KeyCode`](crate::keyboard::KeyCode)
+/// and [`ScanCode`](crate::keyboard::ScanCode) of the [`KeyboardEvent`](crate::keyboard::KeyboardEvent).
+pub trait ButtonEvent<B: InputButton>: Event + Copy {
+    /// Returns a button of the [`Event`], if it's known.
+    fn input_button(&self) -> Option<B>;
+
+    /// Returns a [`ButtonState`] of the [`Event`].
+    fn input_state(&self) -> ButtonState;
+}
+
+/// [`SystemData`] of [`ButtonEvent`]s, that are sent in the current frame.
+///
+/// [`InputUpdate::update`] applies all [`ButtonEvent`]s in order of sending to the [`Input<B>`](Input) resource,
+/// so a press and release in one frame are both kept.
+/// If the [`Input`] resource isn't present, it's added.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// struct KeyCodeSystem;
+///
+/// impl System for KeyCodeSystem {
+///     type Data = InputUpdate<KeyboardEvent, KeyCode>;
+///
+///     fn run(&mut self, data: Self::Data) { data.update(); }
+/// }
+///
+/// let event = |keycode, state| {
+///     KeyboardEvent::builder()
+///         .with_scancode(ScanCode::new(0))
+///         .with_keycode(keycode)
+///         .with_state(state)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut world = World::new();
+/// world
+///     .add_event(event(KeyCode::F, ButtonState::Pressed))
+///     .add_event(event(KeyCode::F, ButtonState::Released))
+///     .add_event(event(KeyCode::G, ButtonState::Pressed));
+///
+/// KeyCodeSystem.run_world(&mut world);
+/// let input = world.get_resource::<Input<KeyCode>>().unwrap();
+/// assert!(input.just_pressed(KeyCode::F) && input.just_released(KeyCode::F));
+/// assert!(!input.pressed(KeyCode::F) && input.pressed(KeyCode::G));
+/// drop(input);
+///
+/// world.clear_events();
+/// KeyCodeSystem.run_world(&mut world);
+/// let input = world.get_resource::<Input<KeyCode>>().unwrap();
+/// assert!(input.pressed(KeyCode::G) && !input.just_pressed(KeyCode::G));
+/// ```
+#[derive(Derivative)]
+#[derivative(Debug(bound = "E: std::fmt::Debug"))]
+pub struct InputUpdate<E: ButtonEvent<B>, B: InputButton> {
+    events: Vec<E>,
+
+    commands: Commands,
+
+    #[derivative(Debug = "ignore")]
+    _marker: PhantomData<fn() -> B>,
+}
+
+impl<E: ButtonEvent<B>, B: InputButton> InputUpdate<E, B> {
+    /// Returns [`ButtonEvent`]s in order of sending.
+    #[inline]
+    pub fn events(&self) -> &[E] { &self.events }
+
+    /// Clears `just` states of the [`Input<B>`](Input) resource and applies [`ButtonEvent`]s to it.
+    pub fn update(self) {
+        let events = self.events;
+        self.commands.add(move |world| {
+            init_resource::<Input<B>>(world);
+            if let Some(mut input) = world.get_resource_mut::<Input<B>>() {
+                input.clear();
+                input.apply(&events);
+            }
+        });
+    }
+}
+
+impl<E: ButtonEvent<B>, B: InputButton> SystemData for InputUpdate<E, B> {
+    fn access(access: &mut SystemAccess) { access.write_resource::<Input<B>>(); }
+
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            events: world
+                .get_events::<E>()
+                .map(|events| events.iter().copied().collect())
+                .unwrap_or_default(),
+            commands: world.commands(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Adds a `default` resource `T` to the `world`, if it isn't present.
+pub(crate) fn init_resource<T: Resource + Default>(world: &mut World) {
+    if !world.has_resource::<T>() {
+        world.add_resource(T::default());
+    }
+}
@@ -11,9 +11,27 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_input::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(PlayerController::default())
+///     .with_component::<PlayerController>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
-pub struct PlayerController {}
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, CopyGetters, Component, Reflect)]
+pub struct PlayerController {
+    /// The player, that reads actions by [`InputMap::player`](crate::action::InputMap::player).
+    #[getset(get_copy = "pub")]
+    player: usize,
+}
+
+impl PlayerController {
+    /// Returns a [`PlayerController`] of the `player`.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let controller = PlayerController::new(1);
+    /// assert_eq!(controller.player(), 1);
+    /// ```
+    #[inline(always)]
+    pub const fn new(player: usize) -> Self { Self { player } }
+}
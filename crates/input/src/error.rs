@@ -0,0 +1,54 @@
+//! Error functionality.
+
+use std::path::PathBuf;
+
+use thiserror::Error;
+
+/// Error of the [`InputMap`](crate::action::InputMap), input recording and gamepad mappings.
+#[derive(Clone, Debug, PartialEq, Eq, Error)]
+pub enum InputError {
+    /// The config or recording file can't be read or written.
+    #[error("Failed to access input file {}, it's {message}.", path.display())]
+    Io {
+        /// The path of the file.
+        path: PathBuf,
+
+        /// The message of the I/O error.
+        message: String,
+    },
+
+    /// The config can't be decoded or encoded.
+    #[error("Failed to parse input config, it's invalid: {reason}.")]
+    InvalidConfig {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The [`InputRecording`](crate::recording::InputRecording) or it's event can't be decoded or encoded.
+    #[error("Failed to parse input recording, it's invalid: {reason}.")]
+    InvalidRecording {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The [`GamepadMapping`](crate::mapping::GamepadMapping) can't be decoded.
+    #[error("Failed to parse gamepad mapping, it's invalid: {reason}.")]
+    InvalidMapping {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The [`InputContext`](crate::action::InputContext) isn't present.
+    #[error("Failed to find input context {context}, it's not present.")]
+    ContextNotFound {
+        /// The name of the context.
+        context: String,
+    },
+
+    /// The [`Action`](crate::action::Action) isn't present in the context.
+    #[error("Failed to find action {action}, it's not present.")]
+    ActionNotFound {
+        /// The name of the action.
+        action: String,
+    },
+}
@@ -15,5 +15,15 @@ pub use crate::{
         MouseWheelEvent,
         MouseWheelEventBuilder,
         MouseWheelEventBuilderError,
+        RawMouseMotionEvent,
+    },
+    text::{Ime, ReceivedCharacter},
+    touch::{
+        PenInput,
+        PenInputBuilder,
+        PenInputBuilderError,
+        TouchInput,
+        TouchInputBuilder,
+        TouchInputBuilderError,
     },
 };
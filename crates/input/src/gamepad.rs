@@ -1,9 +1,22 @@
 //! Gamepad functionality.
 
+use std::{
+    collections::{BTreeMap, HashMap},
+    time::Duration,
+};
+
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    axis::{Axis, AxisEvent},
+    button::{init_resource, ButtonEvent, ButtonState, Input, InputButton},
+};
 
-/// Gamepad [`System`].
+/// Gamepad [`System`], that updates [`Gamepads`], [`Input<GamepadButton>`](crate::button::Input),
+/// [`Axis<GamepadButton>`](crate::axis::Axis) and [`Axis<GamepadAxis>`](crate::axis::Axis) resources
+/// by [`GamepadSettings`].
 ///
 /// # Examples
 /// ```
@@ -20,14 +33,26 @@ use qinetic_utils::prelude::*;
 pub struct GamepadSystem {}
 
 impl System for GamepadSystem {
-    type Data = ();
+    type Data = GamepadUpdate;
 
-    #[allow(unused_variables)]
-    fn run(&mut self, data: Self::Data) {}
+    fn run(&mut self, data: Self::Data) { data.update(); }
 }
 
 /// Gamepad representaiton.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters, Builder)]
+#[derive(
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    CopyGetters,
+    Builder,
+    Serialize,
+    Deserialize,
+)]
 #[getset(get_copy = "pub")]
 #[builder(
     crate = "crate::gamepad",
@@ -52,8 +77,69 @@ impl Gamepad {
     pub fn builder() -> GamepadBuilder { GamepadBuilder::default() }
 }
 
+/// Connected [`Gamepad`]s [`Resource`], that is updated by [`GamepadEvent`]s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let gamepad = Gamepad::builder().with_id(0).build().unwrap();
+///
+/// let mut world = World::new();
+/// world.add_event(
+///     GamepadEvent::builder()
+///         .with_gamepad(gamepad)
+///         .with_event_type(GamepadEventType::Connected)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// GamepadSystem::default().run_world(&mut world);
+///
+/// let gamepads = world.get_resource::<Gamepads>().unwrap();
+/// assert!(gamepads.contains(gamepad));
+/// assert_eq!(gamepads.len(), 1);
+/// ```
+#[derive(SmartDefault, Clone, Debug, Resource)]
+pub struct Gamepads {
+    gamepads: BTreeMap<Gamepad, String>,
+}
+
+impl Gamepads {
+    /// Returns `true`, if the `gamepad` is connected.
+    #[inline]
+    pub fn contains(&self, gamepad: Gamepad) -> bool { self.gamepads.contains_key(&gamepad) }
+
+    /// Returns a name of the `gamepad`, if it's connected.
+    ///
+    /// The name is empty, if the backend didn't report it.
+    #[inline]
+    pub fn name(&self, gamepad: Gamepad) -> Option<&str> {
+        self.gamepads.get(&gamepad).map(String::as_str)
+    }
+
+    /// Returns an iterator over connected [`Gamepad`]s.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = Gamepad> + '_ { self.gamepads.keys().copied() }
+
+    /// Returns a number of connected [`Gamepad`]s.
+    #[inline]
+    pub fn len(&self) -> usize { self.gamepads.len() }
+
+    /// Returns `true`, if no [`Gamepad`] is connected.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.gamepads.is_empty() }
+
+    /// Sets a `name` of the connected `gamepad`.
+    #[inline]
+    pub(crate) fn set_name(&mut self, gamepad: Gamepad, name: impl Into<String>) {
+        self.gamepads.insert(gamepad, name.into());
+    }
+}
+
 /// [`Gamepad`] [`Event`].
-#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event)]
+#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event, Serialize, Deserialize)]
 #[getset(get_copy = "pub")]
 #[builder(
     crate = "crate::gamepad",
@@ -68,8 +154,21 @@ pub struct GamepadEvent {
     event_type: GamepadEventType,
 }
 
+impl GamepadEvent {
+    /// Returns a [`GamepadEventBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let gamepad_event_builder = GamepadEvent::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> GamepadEventBuilder { GamepadEventBuilder::default() }
+}
+
 /// [`Gamepad`] [`Event`] type.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GamepadEventType {
     /// The [`Gamepad`] `connected` [`Event`].
     Connected,
@@ -85,12 +184,12 @@ pub enum GamepadEventType {
 }
 
 /// [`Gamepad`] `button` [`Event`].
-#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event)]
+#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event, Serialize, Deserialize)]
 #[getset(get_copy = "pub")]
 #[builder(
     crate = "crate::gamepad",
     setter(prefix = "with"),
-    derive(Debug, PartialEq, Eq)
+    derive(Debug, PartialEq)
 )]
 pub struct GamepadButtonEvent {
     /// The [`Gamepad`] this [`Event`] corresponds to.
@@ -98,10 +197,77 @@ pub struct GamepadButtonEvent {
 
     /// The [`GamepadButtonType`] this [`Event`] corresponds to.
     button_type: GamepadButtonType,
+
+    /// The [`ButtonState`] this [`Event`] corresponds to.
+    #[builder(default)]
+    state: ButtonState,
+
+    /// The analog value of the button in `0.0..=1.0`, like trigger pressure, if it's analog.
+    ///
+    /// If it's present, the state is decided by [`ButtonSettings`] thresholds.
+    #[builder(setter(strip_option), default)]
+    value: Option<f32>,
+}
+
+impl GamepadButtonEvent {
+    /// Returns a [`GamepadButtonEventBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let gamepad_button_event_builder = GamepadButtonEvent::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> GamepadButtonEventBuilder { GamepadButtonEventBuilder::default() }
+}
+
+impl ButtonEvent<GamepadButton> for GamepadButtonEvent {
+    #[inline]
+    fn input_button(&self) -> Option<GamepadButton> {
+        Some(GamepadButton::new(self.gamepad, self.button_type))
+    }
+
+    #[inline]
+    fn input_state(&self) -> ButtonState { self.state }
+}
+
+/// Button of the specific [`Gamepad`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let gamepad = Gamepad::builder().with_id(0).build().unwrap();
+/// let button = GamepadButton::new(gamepad, GamepadButtonType::A);
+///
+/// let mut input = Input::<GamepadButton>::default();
+/// input.press(button);
+/// assert!(input.pressed(button));
+/// ```
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct GamepadButton {
+    /// The [`Gamepad`] of the button.
+    gamepad: Gamepad,
+
+    /// The [`GamepadButtonType`] of the button.
+    button_type: GamepadButtonType,
+}
+
+impl GamepadButton {
+    /// Returns a [`GamepadButton`] of the `gamepad` with `button_type`.
+    #[inline(always)]
+    pub const fn new(gamepad: Gamepad, button_type: GamepadButtonType) -> Self {
+        Self {
+            gamepad,
+            button_type,
+        }
+    }
 }
 
 /// [`Gamepad`] `button` type.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GamepadButtonType {
     /// The `A` button on `Xbox`  equivalent to [GamepadButtonType::Cross].
     A,
@@ -163,13 +329,13 @@ pub enum GamepadButtonType {
     Other(u8),
 }
 
-/// [`Gamepad`] `button` [`Event`].
-#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event)]
+/// [`Gamepad`] `axis` [`Event`].
+#[derive(Clone, Copy, Debug, CopyGetters, Builder, Event, Serialize, Deserialize)]
 #[getset(get_copy = "pub")]
 #[builder(
     crate = "crate::gamepad",
     setter(prefix = "with"),
-    derive(Debug, PartialEq, Eq)
+    derive(Debug, PartialEq)
 )]
 pub struct GamepadAxisEvent {
     /// The [`Gamepad`] this [`Event`] corresponds to.
@@ -177,10 +343,37 @@ pub struct GamepadAxisEvent {
 
     /// The [`GamepadAxisType`] this [`Event`] corresponds to.
     axis_type: GamepadAxisType,
+
+    /// The value of the axis in `-1.0..=1.0` for sticks and `0.0..=1.0` for triggers.
+    #[builder(default)]
+    value: f32,
+}
+
+impl GamepadAxisEvent {
+    /// Returns a [`GamepadAxisEventBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let gamepad_axis_event_builder = GamepadAxisEvent::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> GamepadAxisEventBuilder { GamepadAxisEventBuilder::default() }
+}
+
+impl AxisEvent<GamepadAxis> for GamepadAxisEvent {
+    #[inline]
+    fn input_axis(&self) -> Option<GamepadAxis> {
+        Some(GamepadAxis::new(self.gamepad, self.axis_type))
+    }
+
+    #[inline]
+    fn input_value(&self) -> f32 { self.value }
 }
 
 /// [`Gamepad`] `axis` type.
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum GamepadAxisType {
     /// The `horizontal` value of the `left` stick.
     LeftStickX,
@@ -200,3 +393,605 @@ pub enum GamepadAxisType {
     /// Another associated number gamepad axis.
     Other(u8),
 }
+
+impl GamepadAxisType {
+    /// Returns `x` and `y` axes of the stick, that the axis belongs to, if it's a stick axis.
+    pub const fn stick(&self) -> Option<(GamepadAxisType, GamepadAxisType)> {
+        match self {
+            | GamepadAxisType::LeftStickX | GamepadAxisType::LeftStickY => {
+                Some((GamepadAxisType::LeftStickX, GamepadAxisType::LeftStickY))
+            },
+            | GamepadAxisType::RightStickX | GamepadAxisType::RightStickY => {
+                Some((GamepadAxisType::RightStickX, GamepadAxisType::RightStickY))
+            },
+            | _ => None,
+        }
+    }
+
+    /// Returns `true`, if the axis is a trigger axis.
+    #[inline]
+    pub const fn is_trigger(&self) -> bool {
+        matches!(self, GamepadAxisType::LeftZ | GamepadAxisType::RightZ)
+    }
+}
+
+/// Axis of the specific [`Gamepad`].
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct GamepadAxis {
+    /// The [`Gamepad`] of the axis.
+    gamepad: Gamepad,
+
+    /// The [`GamepadAxisType`] of the axis.
+    axis_type: GamepadAxisType,
+}
+
+impl GamepadAxis {
+    /// Returns a [`GamepadAxis`] of the `gamepad` with `axis_type`.
+    #[inline(always)]
+    pub const fn new(gamepad: Gamepad, axis_type: GamepadAxisType) -> Self {
+        Self { gamepad, axis_type }
+    }
+}
+
+/// Shape of the dead zone of the [`AxisSettings`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DeadZoneShape {
+    /// The dead zone is applied to each axis separately, that snaps the stick to axes.
+    Axial,
+
+    /// The dead zone is applied to the magnitude of the stick, that keeps the direction precise.
+    #[default]
+    Radial,
+}
+
+/// Settings of analog values, that filter out noise near the rest and the edge.
+///
+/// Values below the dead zone are `0.0`, values above the live zone are `1.0`,
+/// and values between them are remapped to `0.0..=1.0`.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let settings = AxisSettings::default()
+///     .with_dead_zone(0.2)
+///     .with_live_zone(0.8);
+///
+/// assert_eq!(settings.filter(0.1), 0.0);
+/// assert_eq!(settings.filter(-0.5), -0.5);
+/// assert_eq!(settings.filter(0.9), 1.0);
+///
+/// let (x, y) = settings.filter_pair(0.0, 0.5);
+/// assert_eq!((x, y), (0.0, 0.5));
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct AxisSettings {
+    /// The magnitude, below which the value is `0.0`.
+    #[default(0.1)]
+    dead_zone: f32,
+
+    /// The magnitude, above which the value is `1.0`.
+    #[default(0.95)]
+    live_zone: f32,
+
+    /// The [`DeadZoneShape`] of stick pairs.
+    shape: DeadZoneShape,
+}
+
+impl AxisSettings {
+    /// Returns a [`AxisSettings`] with `dead_zone`.
+    #[inline]
+    pub fn with_dead_zone(mut self, dead_zone: f32) -> Self {
+        self.dead_zone = dead_zone;
+        self
+    }
+
+    /// Returns a [`AxisSettings`] with `live_zone`.
+    #[inline]
+    pub fn with_live_zone(mut self, live_zone: f32) -> Self {
+        self.live_zone = live_zone;
+        self
+    }
+
+    /// Returns a [`AxisSettings`] with `shape`.
+    #[inline]
+    pub fn with_shape(mut self, shape: DeadZoneShape) -> Self {
+        self.shape = shape;
+        self
+    }
+
+    /// Returns a remapped `magnitude` in `0.0..=1.0`.
+    fn remap(&self, magnitude: f32) -> f32 {
+        if magnitude <= self.dead_zone {
+            0.0
+        } else if magnitude >= self.live_zone {
+            1.0
+        } else {
+            (magnitude - self.dead_zone) / (self.live_zone - self.dead_zone)
+        }
+    }
+
+    /// Returns a filtered `value` of the single axis.
+    #[inline]
+    pub fn filter(&self, value: f32) -> f32 { value.signum() * self.remap(value.abs()) }
+
+    /// Returns filtered `x` and `y` values of the stick by the [`DeadZoneShape`].
+    pub fn filter_pair(&self, x: f32, y: f32) -> (f32, f32) {
+        match self.shape {
+            | DeadZoneShape::Axial => (self.filter(x), self.filter(y)),
+            | DeadZoneShape::Radial => {
+                let magnitude = x.hypot(y);
+                if magnitude == 0.0 {
+                    return (0.0, 0.0);
+                }
+                let factor = self.remap(magnitude) / magnitude;
+                (x * factor, y * factor)
+            },
+        }
+    }
+}
+
+/// Settings of analog buttons, that decide the state by the value with hysteresis.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct ButtonSettings {
+    /// The value, above which the released button becomes pressed.
+    #[default(0.75)]
+    press_threshold: f32,
+
+    /// The value, below which the pressed button becomes released.
+    #[default(0.65)]
+    release_threshold: f32,
+}
+
+impl ButtonSettings {
+    /// Returns a [`ButtonSettings`] with `press_threshold` and `release_threshold`.
+    #[inline]
+    pub fn with_thresholds(mut self, press_threshold: f32, release_threshold: f32) -> Self {
+        self.press_threshold = press_threshold;
+        self.release_threshold = release_threshold;
+        self
+    }
+
+    /// Returns a [`ButtonState`] of the button with `value`, that was `pressed` before.
+    pub fn state(&self, value: f32, pressed: bool) -> ButtonState {
+        let threshold = match pressed {
+            | true => self.release_threshold,
+            | false => self.press_threshold,
+        };
+        match value >= threshold {
+            | true => ButtonState::Pressed,
+            | false => ButtonState::Released,
+        }
+    }
+}
+
+/// Settings of the [`Gamepad`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct GamepadConfig {
+    /// The [`AxisSettings`] of sticks and unknown axes.
+    sticks: AxisSettings,
+
+    /// The [`AxisSettings`] of trigger axes and analog button values.
+    #[default(AxisSettings::default().with_dead_zone(0.05).with_shape(DeadZoneShape::Axial))]
+    triggers: AxisSettings,
+
+    /// The [`ButtonSettings`] of analog buttons.
+    buttons: ButtonSettings,
+}
+
+impl GamepadConfig {
+    /// Returns a [`GamepadConfig`] with `sticks` settings.
+    #[inline]
+    pub fn with_sticks(mut self, sticks: AxisSettings) -> Self {
+        self.sticks = sticks;
+        self
+    }
+
+    /// Returns a [`GamepadConfig`] with `triggers` settings.
+    #[inline]
+    pub fn with_triggers(mut self, triggers: AxisSettings) -> Self {
+        self.triggers = triggers;
+        self
+    }
+
+    /// Returns a [`GamepadConfig`] with `buttons` settings.
+    #[inline]
+    pub fn with_buttons(mut self, buttons: ButtonSettings) -> Self {
+        self.buttons = buttons;
+        self
+    }
+}
+
+/// Per-gamepad settings [`Resource`], that falls back to the default [`GamepadConfig`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let wheel = Gamepad::builder().with_id(1).build().unwrap();
+/// let settings = GamepadSettings::default().with_gamepad(
+///     wheel,
+///     GamepadConfig::default().with_triggers(AxisSettings::default().with_dead_zone(0.0)),
+/// );
+///
+/// assert_eq!(settings.get(wheel).triggers().dead_zone(), 0.0);
+/// ```
+#[derive(SmartDefault, Clone, Debug, Resource)]
+pub struct GamepadSettings {
+    default: GamepadConfig,
+    gamepads: HashMap<Gamepad, GamepadConfig>,
+}
+
+impl GamepadSettings {
+    /// Returns a [`GamepadSettings`] with the `default` [`GamepadConfig`].
+    #[inline]
+    pub fn with_default(mut self, default: GamepadConfig) -> Self {
+        self.default = default;
+        self
+    }
+
+    /// Returns a [`GamepadSettings`] with `config` of the `gamepad`.
+    #[inline]
+    pub fn with_gamepad(mut self, gamepad: Gamepad, config: GamepadConfig) -> Self {
+        self.set(gamepad, config);
+        self
+    }
+
+    /// Sets a `config` of the `gamepad`.
+    #[inline]
+    pub fn set(&mut self, gamepad: Gamepad, config: GamepadConfig) {
+        self.gamepads.insert(gamepad, config);
+    }
+
+    /// Removes a config of the `gamepad`, so it uses the default one.
+    #[inline]
+    pub fn remove(&mut self, gamepad: Gamepad) { self.gamepads.remove(&gamepad); }
+
+    /// Returns a [`GamepadConfig`] of the `gamepad`.
+    #[inline]
+    pub fn get(&self, gamepad: Gamepad) -> &GamepadConfig {
+        self.gamepads.get(&gamepad).unwrap_or(&self.default)
+    }
+}
+
+/// Raw values of [`GamepadAxis`]es before filtering, that radial dead zones need.
+#[derive(Default, Resource)]
+struct RawGamepadAxes(Axis<GamepadAxis>);
+
+/// [`SystemData`] of [`GamepadEvent`]s, [`GamepadButtonEvent`]s and [`GamepadAxisEvent`]s,
+/// that are sent in the current frame.
+///
+/// [`GamepadUpdate::update`] applies all of them in order of sending to gamepad state resources,
+/// filtering them by [`GamepadSettings`].
+/// If the resources aren't present, they're added.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let gamepad = Gamepad::builder().with_id(0).build().unwrap();
+///
+/// let mut world = World::new();
+/// world.add_event(
+///     GamepadButtonEvent::builder()
+///         .with_gamepad(gamepad)
+///         .with_button_type(GamepadButtonType::RightTrigger2)
+///         .with_value(0.9)
+///         .build()
+///         .unwrap(),
+/// );
+/// world.add_event(
+///     GamepadAxisEvent::builder()
+///         .with_gamepad(gamepad)
+///         .with_axis_type(GamepadAxisType::LeftStickX)
+///         .with_value(0.05)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// GamepadSystem::default().run_world(&mut world);
+///
+/// let trigger = GamepadButton::new(gamepad, GamepadButtonType::RightTrigger2);
+/// assert!(world
+///     .get_resource::<Input<GamepadButton>>()
+///     .unwrap()
+///     .just_pressed(trigger));
+/// assert!(
+///     world
+///         .get_resource::<Axis<GamepadButton>>()
+///         .unwrap()
+///         .value(trigger)
+///         > 0.9
+/// );
+///
+/// let stick = GamepadAxis::new(gamepad, GamepadAxisType::LeftStickX);
+/// assert_eq!(
+///     world
+///         .get_resource::<Axis<GamepadAxis>>()
+///         .unwrap()
+///         .value(stick),
+///     0.0
+/// );
+/// ```
+#[derive(Clone, Debug)]
+pub struct GamepadUpdate {
+    gamepad_events: Vec<GamepadEvent>,
+
+    button_events: Vec<GamepadButtonEvent>,
+
+    axis_events: Vec<GamepadAxisEvent>,
+
+    commands: Commands,
+}
+
+impl GamepadUpdate {
+    /// Clears `just` states of the [`Input<GamepadButton>`](Input) resource and applies [`Event`]s to gamepad state resources.
+    ///
+    /// Connections are applied first, then buttons, then axes.
+    pub fn update(self) {
+        let Self {
+            gamepad_events,
+            button_events,
+            axis_events,
+            commands,
+        } = self;
+        commands.add(move |world| {
+            init_gamepad_resources(world);
+            if let Some(mut input) = world.get_resource_mut::<Input<GamepadButton>>() {
+                input.clear();
+            }
+            for event in gamepad_events {
+                apply_gamepad_event(world, event);
+            }
+            for event in button_events {
+                apply_button_event(world, event);
+            }
+            for event in axis_events {
+                apply_axis_event(world, event);
+            }
+        });
+    }
+}
+
+impl SystemData for GamepadUpdate {
+    fn access(access: &mut SystemAccess) { gamepad_access(access); }
+
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            gamepad_events: collect_events(world),
+            button_events: collect_events(world),
+            axis_events: collect_events(world),
+            commands: world.commands(),
+        }
+    }
+}
+
+/// Returns copies of [`Event`]s by `T`, that are sent in the current frame.
+pub(crate) fn collect_events<T: Event + Copy>(world: &World) -> Vec<T> {
+    world
+        .get_events::<T>()
+        .map(|events| events.iter().copied().collect())
+        .unwrap_or_default()
+}
+
+/// Adds access of gamepad state resources to the `access`.
+pub(crate) fn gamepad_access(access: &mut SystemAccess) {
+    access
+        .read_resource::<GamepadSettings>()
+        .write_resource::<Gamepads>()
+        .write_resource::<Input<GamepadButton>>()
+        .write_resource::<Axis<GamepadButton>>()
+        .write_resource::<Axis<GamepadAxis>>()
+        .write_resource::<RawGamepadAxes>();
+}
+
+/// Adds gamepad state resources to the `world`, if they aren't present.
+pub(crate) fn init_gamepad_resources(world: &mut World) {
+    init_resource::<Gamepads>(world);
+    init_resource::<Input<GamepadButton>>(world);
+    init_resource::<Axis<GamepadButton>>(world);
+    init_resource::<Axis<GamepadAxis>>(world);
+    init_resource::<RawGamepadAxes>(world);
+    init_resource::<GamepadSettings>(world);
+}
+
+/// Applies the [`GamepadEvent`] to the [`Gamepads`] resource.
+///
+/// If the [`Gamepad`] is disconnected, it's buttons are released and it's axes are removed.
+pub(crate) fn apply_gamepad_event(world: &World, event: GamepadEvent) {
+    let gamepad = event.gamepad;
+    match event.event_type {
+        | GamepadEventType::Connected => {
+            if let Some(mut gamepads) = world.get_resource_mut::<Gamepads>() {
+                gamepads.gamepads.entry(gamepad).or_default();
+            }
+        },
+        | GamepadEventType::Disconnected => {
+            if let Some(mut gamepads) = world.get_resource_mut::<Gamepads>() {
+                gamepads.gamepads.remove(&gamepad);
+            }
+            if let Some(mut input) = world.get_resource_mut::<Input<GamepadButton>>() {
+                let buttons = input
+                    .get_pressed()
+                    .filter(|button| button.gamepad == gamepad)
+                    .collect::<Vec<_>>();
+                buttons.into_iter().for_each(|button| input.release(button));
+            }
+            if let Some(mut values) = world.get_resource_mut::<Axis<GamepadButton>>() {
+                remove_axes(&mut values, |button| button.gamepad == gamepad);
+            }
+            if let Some(mut axes) = world.get_resource_mut::<Axis<GamepadAxis>>() {
+                remove_axes(&mut axes, |axis| axis.gamepad == gamepad);
+            }
+            if let Some(mut raw) = world.get_resource_mut::<RawGamepadAxes>() {
+                remove_axes(&mut raw.0, |axis| axis.gamepad == gamepad);
+            }
+        },
+        | _ => {},
+    }
+}
+
+fn remove_axes<T: InputButton>(axes: &mut Axis<T>, filter: impl Fn(&T) -> bool) {
+    let removed = axes
+        .iter()
+        .map(|(axis, _)| axis)
+        .filter(|axis| filter(axis))
+        .collect::<Vec<_>>();
+    removed.into_iter().for_each(|axis| {
+        axes.remove(axis);
+    });
+}
+
+/// Applies the [`GamepadButtonEvent`] to [`Input<GamepadButton>`] and [`Axis<GamepadButton>`] resources.
+pub(crate) fn apply_button_event(world: &World, event: GamepadButtonEvent) {
+    let Some(settings) = world.get_resource::<GamepadSettings>() else {
+        return;
+    };
+    if let (Some(mut input), Some(mut values)) = (
+        world.get_resource_mut::<Input<GamepadButton>>(),
+        world.get_resource_mut::<Axis<GamepadButton>>(),
+    ) {
+        let button = GamepadButton::new(event.gamepad, event.button_type);
+        let config = settings.get(event.gamepad);
+        let (value, state) = match event.value {
+            | Some(value) => {
+                let value = config.triggers.filter(value);
+                (value, config.buttons.state(value, input.pressed(button)))
+            },
+            | None => {
+                (
+                    if event.state.is_pressed() { 1.0 } else { 0.0 },
+                    event.state,
+                )
+            },
+        };
+        values.set(button, value);
+        input.set(button, state);
+    }
+}
+
+/// Applies the [`GamepadAxisEvent`] to the [`Axis<GamepadAxis>`] resource.
+pub(crate) fn apply_axis_event(world: &World, event: GamepadAxisEvent) {
+    let Some(settings) = world.get_resource::<GamepadSettings>() else {
+        return;
+    };
+    if let (Some(mut raw), Some(mut axes)) = (
+        world.get_resource_mut::<RawGamepadAxes>(),
+        world.get_resource_mut::<Axis<GamepadAxis>>(),
+    ) {
+        let config = settings.get(event.gamepad);
+        raw.0.set(
+            GamepadAxis::new(event.gamepad, event.axis_type),
+            event.value,
+        );
+        match event.axis_type.stick() {
+            | Some((x, y)) => {
+                let (x, y) = (
+                    GamepadAxis::new(event.gamepad, x),
+                    GamepadAxis::new(event.gamepad, y),
+                );
+                let (x_value, y_value) = config.sticks.filter_pair(raw.0.value(x), raw.0.value(y));
+                axes.set(x, x_value);
+                axes.set(y, y_value);
+            },
+            | None if event.axis_type.is_trigger() => {
+                axes.set(
+                    GamepadAxis::new(event.gamepad, event.axis_type),
+                    config.triggers.filter(event.value),
+                );
+            },
+            | None => {
+                axes.set(
+                    GamepadAxis::new(event.gamepad, event.axis_type),
+                    config.sticks.filter(event.value),
+                );
+            },
+        }
+    }
+}
+
+/// Intensity of the [`Gamepad`] rumble motors in `0.0..=1.0`.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct GamepadRumbleIntensity {
+    /// The intensity of the strong, low-frequency motor.
+    strong_motor: f32,
+
+    /// The intensity of the weak, high-frequency motor.
+    weak_motor: f32,
+}
+
+impl GamepadRumbleIntensity {
+    /// Full intensity of both motors.
+    pub const MAX: Self = Self::new(1.0, 1.0);
+    /// Full intensity of the strong motor only.
+    pub const STRONG_MAX: Self = Self::new(1.0, 0.0);
+    /// Full intensity of the weak motor only.
+    pub const WEAK_MAX: Self = Self::new(0.0, 1.0);
+
+    /// Returns a [`GamepadRumbleIntensity`] of `strong_motor` and `weak_motor`.
+    #[inline(always)]
+    pub const fn new(strong_motor: f32, weak_motor: f32) -> Self {
+        Self {
+            strong_motor,
+            weak_motor,
+        }
+    }
+}
+
+/// Request [`Event`] of the [`Gamepad`] rumble, that backends carry out.
+///
+/// # Examples
+/// ```
+/// # use std::time::Duration;
+/// #
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let gamepad = Gamepad::builder().with_id(0).build().unwrap();
+///
+/// App::builder()
+///     .with_event(GamepadRumbleRequest::Add {
+///         gamepad,
+///         intensity: GamepadRumbleIntensity::STRONG_MAX,
+///         duration: Duration::from_millis(300),
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Event, Serialize, Deserialize)]
+pub enum GamepadRumbleRequest {
+    /// Rumbles the [`Gamepad`] with `intensity` for `duration`.
+    Add {
+        /// The [`Gamepad`] to rumble.
+        gamepad: Gamepad,
+
+        /// The [`GamepadRumbleIntensity`] of motors.
+        intensity: GamepadRumbleIntensity,
+
+        /// The duration of the rumble.
+        duration: Duration,
+    },
+
+    /// Stops all rumbles of the [`Gamepad`].
+    Stop {
+        /// The [`Gamepad`] to stop.
+        gamepad: Gamepad,
+    },
+}
+
+impl GamepadRumbleRequest {
+    /// Returns a [`Gamepad`] of the request.
+    #[inline]
+    pub const fn gamepad(&self) -> Gamepad {
+        match self {
+            | GamepadRumbleRequest::Add { gamepad, .. }
+            | GamepadRumbleRequest::Stop { gamepad } => *gamepad,
+        }
+    }
+}
@@ -2,30 +2,187 @@
 
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::button::ButtonState;
+use crate::{
+    action::Modifier,
+    button::{init_resource, ButtonEvent, ButtonState, Input, InputUpdate},
+};
 
-/// Keyboard [`System`].
+/// Keyboard [`System`], that updates [`Input<KeyCode>`](crate::button::Input),
+/// [`Input<ScanCode>`](crate::button::Input) and [`KeyboardModifiers`] resources.
 ///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_input::prelude::*;
 /// #
-/// App::builder()
+/// let event = |keycode, state| {
+///     KeyboardEvent::builder()
+///         .with_scancode(ScanCode::new(0))
+///         .with_keycode(keycode)
+///         .with_state(state)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut app = App::builder()
 ///     .with_stage_group(InputStageGroup::default())
 ///     .with_system(InputStage::default(), KeyboardSystem::default())
 ///     .build()
 ///     .unwrap();
+///
+/// app.world_mut()
+///     .add_event(event(KeyCode::Space, ButtonState::Pressed))
+///     .add_event(event(KeyCode::Space, ButtonState::Released))
+///     .add_event(event(KeyCode::LShift, ButtonState::Pressed))
+///     .add_event(event(KeyCode::W, ButtonState::Pressed));
+/// app.update();
+///
+/// let input = app.world().get_resource::<Input<KeyCode>>().unwrap();
+/// assert!(input.just_pressed(KeyCode::Space) && input.just_released(KeyCode::Space));
+/// assert!(input.pressed(KeyCode::LShift) && input.pressed(KeyCode::W));
+/// assert!(!input.pressed(KeyCode::Space));
+/// assert!(app
+///     .world()
+///     .get_resource::<KeyboardModifiers>()
+///     .unwrap()
+///     .shift());
+/// drop(input);
+///
+/// app.update();
+///
+/// let input = app.world().get_resource::<Input<KeyCode>>().unwrap();
+/// assert!(!input.just_pressed(KeyCode::Space) && !input.just_released(KeyCode::Space));
+/// assert!(input.pressed(KeyCode::W) && !input.just_pressed(KeyCode::W));
 /// ```
 #[derive(SmartDefault, Clone, Debug)]
 pub struct KeyboardSystem {}
 
 impl System for KeyboardSystem {
-    type Data = ();
+    type Data = (
+        InputUpdate<KeyboardEvent, KeyCode>,
+        InputUpdate<KeyboardEvent, ScanCode>,
+        ModifiersUpdate,
+    );
+
+    fn run(&mut self, (keycodes, scancodes, modifiers): Self::Data) {
+        keycodes.update();
+        scancodes.update();
+        modifiers.update();
+    }
+}
+
+/// Keyboard modifiers state [`Resource`], that matches both left and right keys.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mut world = World::new();
+/// world.add_event(
+///     KeyboardEvent::builder()
+///         .with_scancode(ScanCode::new(54))
+///         .with_keycode(KeyCode::RShift)
+///         .with_state(ButtonState::Pressed)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// KeyboardSystem::default().run_world(&mut world);
+///
+/// let modifiers = world.get_resource::<KeyboardModifiers>().unwrap();
+/// assert!(modifiers.shift());
+/// assert!(modifiers.pressed(Modifier::Shift));
+/// assert!(!modifiers.control());
+/// ```
+#[derive(
+    SmartDefault, Clone, Copy, Debug, PartialEq, Eq, CopyGetters, Resource, Serialize, Deserialize,
+)]
+#[getset(get_copy = "pub")]
+pub struct KeyboardModifiers {
+    /// The `Shift` key is pressed.
+    shift: bool,
+
+    /// The `Control` key is pressed.
+    control: bool,
+
+    /// The `Alt` key is pressed. Maps to `Option` on Mac.
+    alt: bool,
 
-    #[allow(unused_variables)]
-    fn run(&mut self, data: Self::Data) {}
+    /// The `Windows` key is pressed. Maps to `Command` on Mac.
+    super_key: bool,
+}
+
+impl KeyboardModifiers {
+    /// Returns a [`KeyboardModifiers`] of pressed keys of the `input`.
+    pub fn from_input(input: &Input<KeyCode>) -> Self {
+        let pressed = |modifier: Modifier| input.any_pressed(modifier.keys());
+        Self {
+            shift: pressed(Modifier::Shift),
+            control: pressed(Modifier::Control),
+            alt: pressed(Modifier::Alt),
+            super_key: pressed(Modifier::Super),
+        }
+    }
+
+    /// Returns `true`, if the `modifier` is pressed.
+    pub const fn pressed(&self, modifier: Modifier) -> bool {
+        match modifier {
+            | Modifier::Shift => self.shift,
+            | Modifier::Control => self.control,
+            | Modifier::Alt => self.alt,
+            | Modifier::Super => self.super_key,
+        }
+    }
+
+    /// Returns `true`, if no modifier is pressed.
+    #[inline]
+    pub const fn is_empty(&self) -> bool {
+        !(self.shift || self.control || self.alt || self.super_key)
+    }
+}
+
+/// [`SystemData`], that updates the [`KeyboardModifiers`] resource by the [`Input<KeyCode>`] resource.
+///
+/// [`ModifiersUpdate::update`] reads the [`Input<KeyCode>`] resource,
+/// after [`InputUpdate`]s, that are updated before it, are applied.
+/// If the resource isn't present, it's added.
+#[derive(Clone, Debug)]
+pub struct ModifiersUpdate {
+    commands: Commands,
+}
+
+impl ModifiersUpdate {
+    /// Updates the [`KeyboardModifiers`] resource by pressed keys.
+    pub fn update(self) {
+        self.commands.add(|world| {
+            init_resource::<KeyboardModifiers>(world);
+            let modifiers = world
+                .get_resource::<Input<KeyCode>>()
+                .map(|input| KeyboardModifiers::from_input(&input))
+                .unwrap_or_default();
+            if let Some(mut resource) = world.get_resource_mut::<KeyboardModifiers>() {
+                *resource = modifiers;
+            }
+        });
+    }
+}
+
+impl SystemData for ModifiersUpdate {
+    fn access(access: &mut SystemAccess) {
+        access
+            .read_resource::<Input<KeyCode>>()
+            .write_resource::<KeyboardModifiers>();
+    }
+
+    #[inline]
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            commands: world.commands(),
+        }
+    }
 }
 
 /// Keyboard [`Event`].
@@ -46,7 +203,7 @@ impl System for KeyboardSystem {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Clone, Copy, Debug, Derivative, CopyGetters, Builder, Event)]
+#[derive(Clone, Copy, Debug, Derivative, CopyGetters, Builder, Event, Serialize, Deserialize)]
 #[derivative(PartialEq, Eq)]
 #[getset(get_copy = "pub")]
 #[builder(
@@ -77,8 +234,24 @@ impl KeyboardEvent {
     pub fn builder() -> KeyboardEventBuilder { KeyboardEventBuilder::default() }
 }
 
+impl ButtonEvent<KeyCode> for KeyboardEvent {
+    #[inline]
+    fn input_button(&self) -> Option<KeyCode> { self.keycode }
+
+    #[inline]
+    fn input_state(&self) -> ButtonState { self.state }
+}
+
+impl ButtonEvent<ScanCode> for KeyboardEvent {
+    #[inline]
+    fn input_button(&self) -> Option<ScanCode> { Some(self.scancode) }
+
+    #[inline]
+    fn input_state(&self) -> ButtonState { self.state }
+}
+
 /// The `keycode` of a [`KeyboardEvent`].
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum KeyCode {
     /// The `1` key over the letters.
@@ -430,7 +603,9 @@ pub enum KeyCode {
 }
 
 /// The `scancode` of a [`KeyboardEvent`].
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters)]
+#[derive(
+    Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, CopyGetters, Serialize, Deserialize,
+)]
 #[getset(get_copy = "pub")]
 pub struct ScanCode {
     scancode: u32,
@@ -23,28 +23,44 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod action;
+pub mod axis;
+pub mod backend;
 pub mod button;
 pub mod components;
+pub mod error;
 pub mod events;
 pub mod gamepad;
 pub mod keyboard;
+pub mod mapping;
 pub mod mouse;
 pub mod plugins;
+pub mod recording;
 pub mod stages;
 pub mod systems;
+pub mod text;
+pub mod touch;
 
 pub mod prelude {
     //! Main input functionality.
 
     #[doc(hidden)]
     pub use crate::{
+        action::*,
+        axis::*,
+        backend::*,
         button::*,
         components::PlayerController,
+        error::InputError,
         gamepad::*,
         keyboard::*,
+        mapping::*,
         mouse::*,
         plugins::InputPlugin,
+        recording::*,
         stages::{InputStage, InputStageGroup},
-        systems::InputSystem,
+        systems::{InputSystem, InputSystemSet},
+        text::*,
+        touch::*,
     };
 }
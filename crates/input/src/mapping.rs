@@ -0,0 +1,629 @@
+//! Gamepad mapping functionality.
+
+use std::{collections::HashMap, fmt, fs, path::Path, str::FromStr};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::InputError,
+    gamepad::{GamepadAxisType, GamepadButtonType},
+};
+
+/// Mappings of common controllers, that are always present in [`GamepadMappings`].
+const BUILTIN_MAPPINGS: &str = "\
+030000005e0400008e02000010010000,Xbox 360 Controller,a:b0,b:b1,back:b6,dpdown:h0.4,dpleft:h0.8,dpright:h0.2,dpup:h0.1,guide:b8,leftshoulder:b4,leftstick:b9,lefttrigger:a2,leftx:a0,lefty:a1,rightshoulder:b5,rightstick:b10,righttrigger:a5,rightx:a3,righty:a4,start:b7,x:b2,y:b3,platform:Linux,
+";
+
+/// SDL-style GUID of the gamepad device, that identifies it's model.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let guid = GamepadGuid::from_ids(0x03, 0x045E, 0x028E, 0x0110);
+///
+/// assert_eq!(guid.to_string(), "030000005e0400008e02000010010000");
+/// assert_eq!(guid.to_string().parse::<GamepadGuid>(), Ok(guid));
+/// ```
+#[derive(Clone, Copy, Default, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct GamepadGuid([u8; 16]);
+
+impl GamepadGuid {
+    /// Returns a [`GamepadGuid`] of `bytes`.
+    #[inline(always)]
+    pub const fn new(bytes: [u8; 16]) -> Self { Self(bytes) }
+
+    /// Returns a [`GamepadGuid`] of the device with `bus`, `vendor`, `product` and `version` ids.
+    pub const fn from_ids(bus: u16, vendor: u16, product: u16, version: u16) -> Self {
+        let mut bytes = [0; 16];
+        let ids = [bus, vendor, product, version];
+        let mut index = 0;
+        while index < ids.len() {
+            let [low, high] = ids[index].to_le_bytes();
+            bytes[index * 4] = low;
+            bytes[index * 4 + 1] = high;
+            index += 1;
+        }
+        Self(bytes)
+    }
+
+    /// Returns bytes of the [`GamepadGuid`].
+    #[inline(always)]
+    pub const fn bytes(&self) -> [u8; 16] { self.0 }
+}
+
+impl fmt::Debug for GamepadGuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result { write!(f, "GamepadGuid({self})") }
+}
+
+impl fmt::Display for GamepadGuid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|byte| write!(f, "{byte:02x}"))
+    }
+}
+
+impl FromStr for GamepadGuid {
+    type Err = InputError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        if text.len() != 32 || !text.is_ascii() {
+            return Err(invalid(format!("GUID {text} isn't 32 hex digits")));
+        }
+        let mut bytes = [0; 16];
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            *byte = u8::from_str_radix(&text[index * 2..index * 2 + 2], 16)
+                .map_err(|_| invalid(format!("GUID {text} isn't 32 hex digits")))?;
+        }
+        Ok(Self(bytes))
+    }
+}
+
+fn invalid(reason: impl Into<String>) -> InputError {
+    InputError::InvalidMapping {
+        reason: reason.into(),
+    }
+}
+
+/// Raw input of the gamepad device, that is numbered by the backend in SDL order.
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RawGamepadInput {
+    /// The button by index.
+    Button(u16),
+
+    /// The axis by index.
+    Axis(u16),
+
+    /// The direction of the hat by index,
+    /// `1` is up, `2` is right, `4` is down and `8` is left.
+    Hat(u8, u8),
+}
+
+/// Range of the axis, that is used by the [`GamepadMapping`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AxisRange {
+    Full,
+    Positive,
+    Negative,
+}
+
+/// Source of the [`GamepadMapping`] binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct MappingSource {
+    input: RawGamepadInput,
+    range: AxisRange,
+    inverted: bool,
+}
+
+/// Target of the [`GamepadMapping`] binding.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MappingTarget {
+    Button(GamepadButtonType),
+    Axis(GamepadAxisType, AxisRange),
+}
+
+/// Input, that is mapped from the [`RawGamepadInput`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum MappedInput {
+    Button {
+        button_type: GamepadButtonType,
+        pressed: bool,
+        value: Option<f32>,
+    },
+    Axis {
+        axis_type: GamepadAxisType,
+        value: f32,
+    },
+}
+
+/// SDL-style mapping of the gamepad device, that maps it's [`RawGamepadInput`]s
+/// to [`GamepadButtonType`]s and [`GamepadAxisType`]s.
+///
+/// The mapping is a line of the `gamecontrollerdb.txt`:
+/// `GUID,Name,a:b0,b:b1,leftx:a0,lefty:a1,dpup:h0.1,...,platform:Linux,`.
+/// Axis sources can be prefixed by `+` or `-` for a half axis and suffixed by `~` for an inverted one.
+///
+/// Stick `y` axes are flipped, so up is positive.
+/// Triggers are mapped to both analog [`GamepadButtonType::LeftTrigger2`]/[`GamepadButtonType::RightTrigger2`]
+/// and [`GamepadAxisType::LeftZ`]/[`GamepadAxisType::RightZ`] in `0.0..=1.0`.
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mapping: GamepadMapping = "03000000de280000ff11000001000000,Steam Virtual Gamepad,\
+///     a:b0,b:b1,x:b2,y:b3,leftx:a0,lefty:a1,lefttrigger:+a2,platform:Linux,"
+///     .parse()
+///     .unwrap();
+///
+/// assert_eq!(mapping.name(), "Steam Virtual Gamepad");
+/// assert_eq!(mapping.to_string().parse::<GamepadMapping>(), Ok(mapping));
+/// ```
+#[derive(Clone, Debug, PartialEq, Getters, CopyGetters)]
+pub struct GamepadMapping {
+    /// The [`GamepadGuid`] of devices, that the mapping is for.
+    #[getset(get_copy = "pub")]
+    guid: GamepadGuid,
+
+    /// The name of the device.
+    #[getset(get = "pub")]
+    name: String,
+
+    bindings: Vec<(MappingSource, MappingTarget)>,
+    platform: Option<String>,
+}
+
+impl GamepadMapping {
+    /// Returns a [`GamepadMapping`] of the device without a known mapping,
+    /// that maps buttons and axes to `Other` by index, and the first hat to the `D-Pad`.
+    pub fn raw(guid: GamepadGuid, name: impl Into<String>) -> Self {
+        let hat = |mask, button_type| {
+            (
+                MappingSource {
+                    input: RawGamepadInput::Hat(0, mask),
+                    range: AxisRange::Full,
+                    inverted: false,
+                },
+                MappingTarget::Button(button_type),
+            )
+        };
+        Self {
+            guid,
+            name: name.into(),
+            bindings: vec![
+                hat(1, GamepadButtonType::DPadUp),
+                hat(2, GamepadButtonType::DPadRight),
+                hat(4, GamepadButtonType::DPadDown),
+                hat(8, GamepadButtonType::DPadLeft),
+            ],
+            platform: None,
+        }
+    }
+
+    /// Returns `true`, if the mapping is for the current platform,
+    /// or it's platform isn't specified.
+    pub fn is_current_platform(&self) -> bool {
+        let current = match std::env::consts::OS {
+            | "linux" => "Linux",
+            | "windows" => "Windows",
+            | "macos" => "Mac OS X",
+            | "android" => "Android",
+            | "ios" => "iOS",
+            | other => other,
+        };
+        self.platform
+            .as_deref()
+            .is_none_or(|platform| platform == current)
+    }
+
+    /// Returns inputs, that are mapped from the button by `index`.
+    pub(crate) fn map_button(&self, index: u16, pressed: bool) -> Vec<MappedInput> {
+        let value = if pressed { 1.0 } else { 0.0 };
+        let mut inputs = self.map(RawGamepadInput::Button(index), value);
+        if inputs.is_empty() && self.is_raw() {
+            inputs.push(MappedInput::Button {
+                button_type: GamepadButtonType::Other(index as u8),
+                pressed,
+                value: None,
+            });
+        }
+        inputs
+    }
+
+    /// Returns inputs, that are mapped from the axis by `index` with `value` in `-1.0..=1.0`.
+    pub(crate) fn map_axis(&self, index: u16, value: f32) -> Vec<MappedInput> {
+        let mut inputs = self.map(RawGamepadInput::Axis(index), value);
+        if inputs.is_empty() && self.is_raw() {
+            inputs.push(MappedInput::Axis {
+                axis_type: GamepadAxisType::Other(index as u8),
+                value,
+            });
+        }
+        inputs
+    }
+
+    /// Returns inputs, that are mapped from the hat by `index`,
+    /// which directions are changed from `previous` to `value`.
+    pub(crate) fn map_hat(&self, index: u8, previous: u8, value: u8) -> Vec<MappedInput> {
+        [1, 2, 4, 8]
+            .into_iter()
+            .filter(|mask| (previous ^ value) & mask != 0)
+            .flat_map(|mask| {
+                let pressed = value & mask != 0;
+                self.map(
+                    RawGamepadInput::Hat(index, mask),
+                    if pressed { 1.0 } else { 0.0 },
+                )
+            })
+            .collect()
+    }
+
+    #[inline]
+    fn is_raw(&self) -> bool {
+        self.bindings
+            .iter()
+            .all(|(source, _)| matches!(source.input, RawGamepadInput::Hat(..)))
+    }
+
+    fn map(&self, input: RawGamepadInput, value: f32) -> Vec<MappedInput> {
+        self.bindings
+            .iter()
+            .filter(|(source, _)| source.input == input)
+            .map(|(source, target)| {
+                let value = if source.inverted { -value } else { value };
+                let value = match source.range {
+                    | AxisRange::Full => value,
+                    | AxisRange::Positive => value.max(0.0),
+                    | AxisRange::Negative => (-value).max(0.0),
+                };
+                (source, target, value)
+            })
+            .flat_map(|(source, target, value)| {
+                let is_full = source.range == AxisRange::Full;
+                let is_axis = matches!(source.input, RawGamepadInput::Axis(_));
+                match *target {
+                    | MappingTarget::Button(button_type) => {
+                        match trigger_axis(button_type) {
+                            | Some(axis_type) if is_axis => {
+                                let value = if is_full { (value + 1.0) / 2.0 } else { value };
+                                vec![
+                                    MappedInput::Button {
+                                        button_type,
+                                        pressed: value > 0.5,
+                                        value: Some(value),
+                                    },
+                                    MappedInput::Axis { axis_type, value },
+                                ]
+                            },
+                            | _ => {
+                                vec![MappedInput::Button {
+                                    button_type,
+                                    pressed: value > 0.5,
+                                    value: None,
+                                }]
+                            },
+                        }
+                    },
+                    | MappingTarget::Axis(axis_type, range) => {
+                        let value = match range {
+                            | AxisRange::Negative => -value.abs(),
+                            | AxisRange::Positive if is_axis && is_full => (value + 1.0) / 2.0,
+                            | _ => value,
+                        };
+                        let value = match axis_type {
+                            | GamepadAxisType::LeftStickY | GamepadAxisType::RightStickY => -value,
+                            | _ => value,
+                        };
+                        vec![MappedInput::Axis { axis_type, value }]
+                    },
+                }
+            })
+            .collect()
+    }
+}
+
+fn trigger_axis(button_type: GamepadButtonType) -> Option<GamepadAxisType> {
+    match button_type {
+        | GamepadButtonType::LeftTrigger2 => Some(GamepadAxisType::LeftZ),
+        | GamepadButtonType::RightTrigger2 => Some(GamepadAxisType::RightZ),
+        | _ => None,
+    }
+}
+
+/// Names of SDL buttons and axes with their targets.
+const TARGETS: &[(&str, MappingTarget)] = &[
+    ("a", MappingTarget::Button(GamepadButtonType::A)),
+    ("b", MappingTarget::Button(GamepadButtonType::B)),
+    ("x", MappingTarget::Button(GamepadButtonType::X)),
+    ("y", MappingTarget::Button(GamepadButtonType::Y)),
+    ("back", MappingTarget::Button(GamepadButtonType::Select)),
+    ("guide", MappingTarget::Button(GamepadButtonType::Mode)),
+    ("start", MappingTarget::Button(GamepadButtonType::Start)),
+    (
+        "leftstick",
+        MappingTarget::Button(GamepadButtonType::LeftThumb),
+    ),
+    (
+        "rightstick",
+        MappingTarget::Button(GamepadButtonType::RightThumb),
+    ),
+    (
+        "leftshoulder",
+        MappingTarget::Button(GamepadButtonType::LeftTrigger),
+    ),
+    (
+        "rightshoulder",
+        MappingTarget::Button(GamepadButtonType::RightTrigger),
+    ),
+    (
+        "lefttrigger",
+        MappingTarget::Button(GamepadButtonType::LeftTrigger2),
+    ),
+    (
+        "righttrigger",
+        MappingTarget::Button(GamepadButtonType::RightTrigger2),
+    ),
+    ("dpup", MappingTarget::Button(GamepadButtonType::DPadUp)),
+    ("dpdown", MappingTarget::Button(GamepadButtonType::DPadDown)),
+    ("dpleft", MappingTarget::Button(GamepadButtonType::DPadLeft)),
+    (
+        "dpright",
+        MappingTarget::Button(GamepadButtonType::DPadRight),
+    ),
+    ("misc1", MappingTarget::Button(GamepadButtonType::Other(0))),
+    (
+        "paddle1",
+        MappingTarget::Button(GamepadButtonType::Other(1)),
+    ),
+    (
+        "paddle2",
+        MappingTarget::Button(GamepadButtonType::Other(2)),
+    ),
+    (
+        "paddle3",
+        MappingTarget::Button(GamepadButtonType::Other(3)),
+    ),
+    (
+        "paddle4",
+        MappingTarget::Button(GamepadButtonType::Other(4)),
+    ),
+    (
+        "touchpad",
+        MappingTarget::Button(GamepadButtonType::Other(5)),
+    ),
+    (
+        "leftx",
+        MappingTarget::Axis(GamepadAxisType::LeftStickX, AxisRange::Full),
+    ),
+    (
+        "lefty",
+        MappingTarget::Axis(GamepadAxisType::LeftStickY, AxisRange::Full),
+    ),
+    (
+        "rightx",
+        MappingTarget::Axis(GamepadAxisType::RightStickX, AxisRange::Full),
+    ),
+    (
+        "righty",
+        MappingTarget::Axis(GamepadAxisType::RightStickY, AxisRange::Full),
+    ),
+];
+
+fn parse_target(name: &str) -> Option<MappingTarget> {
+    let (range, name) = match name.split_at(name.len().min(1)) {
+        | ("+", name) => (AxisRange::Positive, name),
+        | ("-", name) => (AxisRange::Negative, name),
+        | _ => (AxisRange::Full, name),
+    };
+    let target = TARGETS.iter().find(|(target, _)| *target == name)?.1;
+    Some(match target {
+        | MappingTarget::Axis(axis_type, _) => MappingTarget::Axis(axis_type, range),
+        | target => target,
+    })
+}
+
+fn parse_source(text: &str) -> Result<MappingSource, InputError> {
+    let error = || invalid(format!("source {text} isn't a button, an axis or a hat"));
+    let (inverted, text) = match text.strip_suffix('~') {
+        | Some(text) => (true, text),
+        | None => (false, text),
+    };
+    let (range, text) = match text.split_at(text.len().min(1)) {
+        | ("+", text) => (AxisRange::Positive, text),
+        | ("-", text) => (AxisRange::Negative, text),
+        | _ => (AxisRange::Full, text),
+    };
+    let input = match text.split_at(text.len().min(1)) {
+        | ("b", index) => RawGamepadInput::Button(index.parse().map_err(|_| error())?),
+        | ("a", index) => RawGamepadInput::Axis(index.parse().map_err(|_| error())?),
+        | ("h", hat) => {
+            let (index, mask) = hat.split_once('.').ok_or_else(error)?;
+            RawGamepadInput::Hat(
+                index.parse().map_err(|_| error())?,
+                mask.parse().map_err(|_| error())?,
+            )
+        },
+        | _ => return Err(error()),
+    };
+    Ok(MappingSource {
+        input,
+        range,
+        inverted,
+    })
+}
+
+impl FromStr for GamepadMapping {
+    type Err = InputError;
+
+    fn from_str(line: &str) -> Result<Self, Self::Err> {
+        let mut fields = line.trim().split(',');
+        let guid = fields.next().unwrap_or_default().parse()?;
+        let name = fields
+            .next()
+            .ok_or_else(|| invalid(format!("mapping {line} has no name")))?
+            .to_string();
+
+        let mut mapping = Self {
+            guid,
+            name,
+            bindings: Vec::new(),
+            platform: None,
+        };
+        for field in fields.filter(|field| !field.is_empty()) {
+            let (key, value) = field
+                .split_once(':')
+                .ok_or_else(|| invalid(format!("field {field} isn't a key:value pair")))?;
+            if key == "platform" {
+                mapping.platform = Some(value.to_string());
+                continue;
+            }
+            // Unknown targets, like `crc` or `hint`, are skipped for forward compatibility.
+            if let Some(target) = parse_target(key) {
+                mapping.bindings.push((parse_source(value)?, target));
+            }
+        }
+        Ok(mapping)
+    }
+}
+
+impl fmt::Display for GamepadMapping {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},", self.guid, self.name)?;
+        for (source, target) in &self.bindings {
+            let (name, range) = match target {
+                | MappingTarget::Button(button_type) => {
+                    let name = TARGETS
+                        .iter()
+                        .find(|(_, other)| *other == MappingTarget::Button(*button_type))
+                        .map_or("", |(name, _)| name);
+                    (name, AxisRange::Full)
+                },
+                | MappingTarget::Axis(axis_type, range) => {
+                    let name = TARGETS
+                        .iter()
+                        .find(|(_, other)| {
+                            *other == MappingTarget::Axis(*axis_type, AxisRange::Full)
+                        })
+                        .map_or("", |(name, _)| name);
+                    (name, *range)
+                },
+            };
+            write!(f, "{}{name}:", range_prefix(range))?;
+            write!(f, "{}", range_prefix(source.range))?;
+            match source.input {
+                | RawGamepadInput::Button(index) => write!(f, "b{index}")?,
+                | RawGamepadInput::Axis(index) => write!(f, "a{index}")?,
+                | RawGamepadInput::Hat(index, mask) => write!(f, "h{index}.{mask}")?,
+            }
+            write!(f, "{},", if source.inverted { "~" } else { "" })?;
+        }
+        if let Some(platform) = &self.platform {
+            write!(f, "platform:{platform},")?;
+        }
+        Ok(())
+    }
+}
+
+#[inline]
+fn range_prefix(range: AxisRange) -> &'static str {
+    match range {
+        | AxisRange::Full => "",
+        | AxisRange::Positive => "+",
+        | AxisRange::Negative => "-",
+    }
+}
+
+/// Database [`Resource`] of [`GamepadMapping`]s by [`GamepadGuid`], like SDL `gamecontrollerdb.txt`.
+///
+/// The `default` database contains mappings of common controllers.
+/// Devices without a mapping use [`GamepadMapping::raw`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mappings = GamepadMappings::default()
+///     .with_database(
+///         "# Comments and mappings of other platforms are skipped.\n\
+///          03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b0,b:b1,platform:Linux,\n\
+///          03000000de280000ff11000001000000,Steam Virtual Gamepad,a:b1,b:b0,platform:Windows,\n",
+///     )
+///     .unwrap();
+///
+/// let guid = "03000000de280000ff11000001000000".parse().unwrap();
+/// assert_eq!(mappings.get(guid).unwrap().name(), "Steam Virtual Gamepad");
+/// ```
+#[derive(Clone, Debug, Resource)]
+pub struct GamepadMappings {
+    mappings: HashMap<GamepadGuid, GamepadMapping>,
+}
+
+impl Default for GamepadMappings {
+    fn default() -> Self {
+        Self {
+            mappings: HashMap::new(),
+        }
+        .with_database(BUILTIN_MAPPINGS)
+        .expect("Built-in gamepad mappings are valid")
+    }
+}
+
+impl GamepadMappings {
+    /// Returns a [`GamepadMappings`] with added `mapping`.
+    /// If the mapping of it's [`GamepadGuid`], was already present, it's replace.
+    #[inline]
+    pub fn with_mapping(mut self, mapping: GamepadMapping) -> Self {
+        self.insert(mapping);
+        self
+    }
+
+    /// Returns a [`GamepadMappings`] with added mappings of the `database` text,
+    /// one mapping per line.
+    ///
+    /// Empty lines, `#` comments and mappings of other platforms are skipped.
+    pub fn with_database(mut self, database: &str) -> Result<Self, InputError> {
+        for line in database.lines().map(str::trim) {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mapping = line.parse::<GamepadMapping>()?;
+            if mapping.is_current_platform() {
+                self.insert(mapping);
+            }
+        }
+        Ok(self)
+    }
+
+    /// Loads mappings from the database file by `path`, like SDL `gamecontrollerdb.txt`.
+    /// Mappings of the file replace present ones with the same [`GamepadGuid`], others are kept.
+    pub fn load(&mut self, path: impl AsRef<Path>) -> Result<(), InputError> {
+        let path = path.as_ref();
+        let database = fs::read_to_string(path).map_err(|error| {
+            InputError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })?;
+        *self = std::mem::take(self).with_database(&database)?;
+        Ok(())
+    }
+
+    /// Adds a `mapping`.
+    /// If the mapping of it's [`GamepadGuid`], was already present, it's replace.
+    #[inline]
+    pub fn insert(&mut self, mapping: GamepadMapping) {
+        self.mappings.insert(mapping.guid, mapping);
+    }
+
+    /// Returns a [`GamepadMapping`] by `guid`, if it's present.
+    #[inline]
+    pub fn get(&self, guid: GamepadGuid) -> Option<&GamepadMapping> { self.mappings.get(&guid) }
+
+    /// Returns a [`GamepadMapping`] by `guid`, or [`GamepadMapping::raw`] if it's not present.
+    pub fn get_or_raw(&self, guid: GamepadGuid, name: &str) -> GamepadMapping {
+        self.get(guid)
+            .cloned()
+            .unwrap_or_else(|| GamepadMapping::raw(guid, name))
+    }
+}
@@ -2,10 +2,11 @@
 
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
 
-use crate::button::ButtonState;
+use crate::button::{ButtonEvent, ButtonState, InputUpdate};
 
-/// Mouse [`System`].
+/// Mouse [`System`], that updates the [`Input<MouseButton>`](crate::button::Input) resource.
 ///
 /// # Examples
 /// ```
@@ -22,10 +23,9 @@ use crate::button::ButtonState;
 pub struct MouseSystem {}
 
 impl System for MouseSystem {
-    type Data = ();
+    type Data = InputUpdate<MouseButtonEvent, MouseButton>;
 
-    #[allow(unused_variables)]
-    fn run(&mut self, data: Self::Data) {}
+    fn run(&mut self, data: Self::Data) { data.update(); }
 }
 
 /// Mouse button [`Event`].
@@ -46,7 +46,7 @@ impl System for MouseSystem {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Clone, Copy, Debug, Derivative, CopyGetters, Builder, Event)]
+#[derive(Clone, Copy, Debug, Derivative, CopyGetters, Builder, Event, Serialize, Deserialize)]
 #[derivative(PartialEq, Eq)]
 #[getset(get_copy = "pub")]
 #[builder(
@@ -74,8 +74,16 @@ impl MouseButtonEvent {
     pub fn builder() -> MouseButtonEventBuilder { MouseButtonEventBuilder::default() }
 }
 
+impl ButtonEvent<MouseButton> for MouseButtonEvent {
+    #[inline]
+    fn input_button(&self) -> Option<MouseButton> { Some(self.button) }
+
+    #[inline]
+    fn input_state(&self) -> ButtonState { self.state }
+}
+
 /// The `button` of a [`MouseEvent`].
-#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
 pub enum MouseButton {
     /// The `left` mouse button.
     Left,
@@ -108,7 +116,7 @@ pub enum MouseButton {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Clone, Copy, Debug, CopyGetters, Builder, Derivative, Event)]
+#[derive(Clone, Copy, Debug, CopyGetters, Builder, Derivative, Event, Serialize, Deserialize)]
 #[derivative(PartialEq, PartialOrd)]
 #[getset(get_copy = "pub")]
 #[builder(
@@ -136,6 +144,35 @@ impl MouseMotionEvent {
     pub fn builder() -> MouseMotionEventBuilder { MouseMotionEventBuilder::default() }
 }
 
+/// Raw mouse motion [`Event`], that comes from the device without OS acceleration.
+///
+/// Unlike [`MouseMotionEvent`], it's reported while the cursor is locked,
+/// so it suits first-person camera controls.
+/// The backend sends the sum of the motion in the frame.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let event = RawMouseMotionEvent::new((4.0, -2.0));
+/// assert_eq!(event.delta(), (4.0, -2.0));
+///
+/// App::builder().with_event(event).build().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, CopyGetters, Event, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct RawMouseMotionEvent {
+    /// The relative motion in device units.
+    delta: (f32, f32),
+}
+
+impl RawMouseMotionEvent {
+    /// Returns a [`RawMouseMotionEvent`] of `delta`.
+    #[inline(always)]
+    pub const fn new(delta: (f32, f32)) -> Self { Self { delta } }
+}
+
 /// Mouse wheel [`Event`].
 ///
 /// # Examples
@@ -153,7 +190,9 @@ impl MouseMotionEvent {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(Clone, Copy, Debug, PartialEq, PartialOrd, CopyGetters, Builder, Event)]
+#[derive(
+    Clone, Copy, Debug, PartialEq, PartialOrd, CopyGetters, Builder, Event, Serialize, Deserialize,
+)]
 #[getset(get_copy = "pub")]
 #[builder(
     crate = "crate::mouse",
@@ -6,9 +6,19 @@ use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 use crate::{
+    action::{ActionSystem, InputMap},
+    axis::Axis,
+    backend::{GamepadBackendSystem, GamepadBackends},
+    button::Input,
     components::PlayerController,
+    gamepad::{GamepadAxis, GamepadButton, GamepadSettings, GamepadSystem, Gamepads},
+    keyboard::{KeyCode, KeyboardModifiers, KeyboardSystem, ScanCode},
+    mapping::GamepadMappings,
+    mouse::{MouseButton, MouseSystem},
+    recording::{PlaybackSystem, RecordingSystem},
     stages::{InputStage, InputStageGroup},
-    systems::InputSystem,
+    systems::{InputSystem, InputSystemSet},
+    touch::{GestureSystem, Gestures, Pen, TouchSystem, Touches},
 };
 
 /// Input [`Plugin`].
@@ -19,27 +29,165 @@ use crate::{
 /// [`Stage`]s:
 /// * [`InputStageGroup`]
 ///
+/// [`Resource`]s:
+/// * [`Input<KeyCode>`]
+/// * [`Input<ScanCode>`]
+/// * [`KeyboardModifiers`]
+/// * [`Input<MouseButton>`]
+/// * [`Input<GamepadButton>`]
+/// * [`Axis<GamepadButton>`]
+/// * [`Axis<GamepadAxis>`]
+/// * [`GamepadSettings`]
+/// * [`Gamepads`]
+/// * [`GamepadMappings`]
+/// * [`Touches`]
+/// * [`Gestures`]
+/// * [`Pen`]
+/// * [`GamepadBackends`], with `EvdevGamepadBackend` on Linux with `evdev` feature
+/// * [`InputMap`]
+///
 /// [`System`]s:
 /// * [`InputSystem`]
+/// * [`KeyboardSystem`]
+/// * [`MouseSystem`]
+/// * [`GamepadSystem`]
+/// * [`TouchSystem`]
+/// * [`GamepadBackendSystem`]
+/// * [`ActionSystem`]
+/// * [`GestureSystem`]
+/// * [`PlaybackSystem`]
+/// * [`RecordingSystem`]
 ///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_input::prelude::*;
 /// #
+/// let input_map = InputMap::default()
+///     .with_context(
+///         "gameplay",
+///         InputContext::default()
+///             .with_action("Jump", Action::default().with_binding(KeyCode::Space)),
+///     )
+///     .with_player(0, "gameplay");
+///
 /// App::builder()
-///     .with_plugin(InputPlugin::default())
+///     .with_plugin(InputPlugin::default().with_input_map(input_map))
 ///     .build()
 ///     .unwrap();
 /// ```
 #[derive(SmartDefault, Clone, Debug)]
-pub struct InputPlugin {}
+pub struct InputPlugin {
+    input_map: InputMap,
+    gamepad_settings: GamepadSettings,
+    gamepad_mappings: GamepadMappings,
+}
+
+impl InputPlugin {
+    /// Returns a [`InputPlugin`] with `input_map`.
+    #[inline]
+    pub fn with_input_map(mut self, input_map: InputMap) -> Self {
+        self.input_map = input_map;
+        self
+    }
+
+    /// Returns a [`InputPlugin`] with `gamepad_settings`.
+    #[inline]
+    pub fn with_gamepad_settings(mut self, gamepad_settings: GamepadSettings) -> Self {
+        self.gamepad_settings = gamepad_settings;
+        self
+    }
+
+    /// Returns a [`InputPlugin`] with `gamepad_mappings`.
+    #[inline]
+    pub fn with_gamepad_mappings(mut self, gamepad_mappings: GamepadMappings) -> Self {
+        self.gamepad_mappings = gamepad_mappings;
+        self
+    }
+}
 
 impl Plugin for InputPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
+        #[allow(unused_mut)]
+        let mut gamepad_backends = GamepadBackends::default();
+        #[cfg(all(target_os = "linux", feature = "evdev"))]
+        gamepad_backends.add_backend(crate::backend::EvdevGamepadBackend::default());
+
         app_builder
-            .with_component(PlayerController::default())
+            .with_component::<PlayerController>()
+            .with_resource(Input::<KeyCode>::default())
+            .with_resource(Input::<ScanCode>::default())
+            .with_resource(KeyboardModifiers::default())
+            .with_resource(Input::<MouseButton>::default())
+            .with_resource(Input::<GamepadButton>::default())
+            .with_resource(Axis::<GamepadButton>::default())
+            .with_resource(Axis::<GamepadAxis>::default())
+            .with_resource(self.gamepad_settings.clone())
+            .with_resource(Gamepads::default())
+            .with_resource(self.gamepad_mappings.clone())
+            .with_resource(Touches::default())
+            .with_resource(Gestures::default())
+            .with_resource(Pen::default())
+            .with_resource(gamepad_backends)
+            .with_resource(self.input_map.clone())
             .with_stage_group(InputStageGroup::default())
+            .with_system_set(
+                SystemSet::new(InputSystemSet::Devices).with_after(InputSystemSet::Playback),
+            )
+            .with_system_set(
+                SystemSet::new(InputSystemSet::Backends).with_after(InputSystemSet::Devices),
+            )
+            .with_system_set(
+                SystemSet::new(InputSystemSet::Actions).with_after(InputSystemSet::Backends),
+            )
+            .with_system_set(
+                SystemSet::new(InputSystemSet::Recording).with_after(InputSystemSet::Actions),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Playback,
+                PlaybackSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Devices,
+                KeyboardSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Devices,
+                MouseSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Devices,
+                GamepadSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Devices,
+                TouchSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Backends,
+                GamepadBackendSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Actions,
+                ActionSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Actions,
+                GestureSystem::default(),
+            )
+            .with_system_in_set(
+                InputStage::PreUpdate,
+                InputSystemSet::Recording,
+                RecordingSystem::default(),
+            )
             .with_system(InputStage::default(), InputSystem::default());
     }
 }
@@ -0,0 +1,543 @@
+//! Input recording and replay functionality.
+
+use std::{fs, path::Path};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+#[cfg(feature = "window")]
+use qinetic_window::events::{CursorEvent, WindowEvent};
+use ron::ser::PrettyConfig;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+
+use crate::{
+    error::InputError,
+    gamepad::{GamepadAxisEvent, GamepadButtonEvent, GamepadEvent},
+    keyboard::KeyboardEvent,
+    mouse::{MouseButtonEvent, MouseMotionEvent, MouseWheelEvent, RawMouseMotionEvent},
+    text::{Ime, ReceivedCharacter},
+    touch::{PenInput, TouchInput},
+};
+
+/// [`Event`], that can be recorded and replayed.
+pub trait RecordableEvent: Event + Clone + Serialize + DeserializeOwned {}
+
+impl<T: Event + Clone + Serialize + DeserializeOwned> RecordableEvent for T {}
+
+/// Named channel of the [`RecordableEvent`].
+#[derive(Clone, Copy)]
+struct EventChannel {
+    name: &'static str,
+    capture: fn(&World) -> Result<Vec<String>, InputError>,
+    replay: fn(&mut World, &str) -> Result<(), InputError>,
+    clear: fn(&World),
+}
+
+fn capture<T: RecordableEvent>(world: &World) -> Result<Vec<String>, InputError> {
+    let Some(events) = world.get_events::<T>() else {
+        return Ok(Vec::new());
+    };
+    events
+        .iter()
+        .map(|event| ron::to_string(event).map_err(invalid))
+        .collect()
+}
+
+fn replay<T: RecordableEvent>(world: &mut World, data: &str) -> Result<(), InputError> {
+    world.add_event(ron::from_str::<T>(data).map_err(invalid)?);
+    Ok(())
+}
+
+fn clear<T: RecordableEvent>(world: &World) {
+    if let Some(mut events) = world.get_events_mut::<T>() {
+        events.clear();
+    }
+}
+
+fn invalid(error: impl ToString) -> InputError {
+    InputError::InvalidRecording {
+        reason: error.to_string(),
+    }
+}
+
+/// Registry of [`RecordableEvent`]s by name, that are recorded and replayed.
+///
+/// The `default` registry contains input [`Event`]s:
+/// * `keyboard` - [`KeyboardEvent`]
+/// * `received_character` - [`ReceivedCharacter`]
+/// * `ime` - [`Ime`]
+/// * `mouse_motion` - [`MouseMotionEvent`]
+/// * `mouse_raw_motion` - [`RawMouseMotionEvent`]
+/// * `mouse_button` - [`MouseButtonEvent`]
+/// * `mouse_wheel` - [`MouseWheelEvent`]
+/// * `gamepad` - [`GamepadEvent`]
+/// * `gamepad_button` - [`GamepadButtonEvent`]
+/// * `gamepad_axis` - [`GamepadAxisEvent`]
+/// * `touch` - [`TouchInput`]
+/// * `pen` - [`PenInput`]
+/// * `window` - [`WindowEvent`], with `window` feature
+/// * `cursor` - [`CursorEvent`], with `window` feature
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// #[derive(Clone, Copy, Event, serde::Serialize, serde::Deserialize)]
+/// struct ShakeEvent {
+///     x: f32,
+///     y: f32,
+/// }
+///
+/// let channels = EventChannels::default().with_event::<ShakeEvent>("shake");
+/// assert!(channels.contains("shake"));
+/// assert!(channels.contains("window") && channels.contains("cursor"));
+/// ```
+#[derive(Clone, Derivative)]
+#[derivative(Debug)]
+pub struct EventChannels {
+    #[derivative(Debug = "ignore")]
+    channels: Vec<EventChannel>,
+}
+
+impl Default for EventChannels {
+    fn default() -> Self {
+        let channels = Self {
+            channels: Vec::new(),
+        }
+        .with_event::<KeyboardEvent>("keyboard")
+        .with_event::<ReceivedCharacter>("received_character")
+        .with_event::<Ime>("ime")
+        .with_event::<MouseMotionEvent>("mouse_motion")
+        .with_event::<RawMouseMotionEvent>("mouse_raw_motion")
+        .with_event::<MouseButtonEvent>("mouse_button")
+        .with_event::<MouseWheelEvent>("mouse_wheel")
+        .with_event::<GamepadEvent>("gamepad")
+        .with_event::<GamepadButtonEvent>("gamepad_button")
+        .with_event::<GamepadAxisEvent>("gamepad_axis")
+        .with_event::<TouchInput>("touch")
+        .with_event::<PenInput>("pen");
+        #[cfg(feature = "window")]
+        let channels = channels
+            .with_event::<WindowEvent>("window")
+            .with_event::<CursorEvent>("cursor");
+        channels
+    }
+}
+
+impl EventChannels {
+    /// Returns a [`EventChannels`] with added [`RecordableEvent`] by `name`.
+    /// If the event by `name`, was already present, it's replace.
+    pub fn with_event<T: RecordableEvent>(mut self, name: &'static str) -> Self {
+        self.channels.retain(|channel| channel.name != name);
+        self.channels.push(EventChannel {
+            name,
+            capture: capture::<T>,
+            replay: replay::<T>,
+            clear: clear::<T>,
+        });
+        self
+    }
+
+    /// Returns `true`, if the event by `name` present.
+    #[inline]
+    pub fn contains(&self, name: &str) -> bool {
+        self.channels.iter().any(|channel| channel.name == name)
+    }
+
+    #[inline]
+    fn get(&self, name: &str) -> Option<&EventChannel> {
+        self.channels.iter().find(|channel| channel.name == name)
+    }
+}
+
+/// Recorded [`Event`] with the frame number.
+#[derive(Clone, Debug, PartialEq, Eq, Getters, CopyGetters, Serialize, Deserialize)]
+pub struct InputRecord {
+    /// The frame number, that the event was sent in.
+    #[getset(get_copy = "pub")]
+    frame: u64,
+
+    /// The name of the event in [`EventChannels`].
+    #[getset(get = "pub")]
+    event: String,
+
+    /// The RON data of the event.
+    #[getset(get = "pub")]
+    data: String,
+}
+
+/// Recorded stream of [`Event`]s, that can be saved to and loaded from a RON file.
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InputRecording {
+    records: Vec<InputRecord>,
+}
+
+impl InputRecording {
+    /// Returns recorded [`InputRecord`]s in frame order.
+    #[inline]
+    pub fn records(&self) -> &[InputRecord] { &self.records }
+
+    /// Returns a number of frames, that the recording lasts.
+    #[inline]
+    pub fn frames(&self) -> u64 { self.records.last().map_or(0, |record| record.frame + 1) }
+
+    /// Saves the recording to the RON file by `path`.
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<(), InputError> {
+        let path = path.as_ref();
+        let text = ron::ser::to_string_pretty(self, PrettyConfig::default()).map_err(invalid)?;
+        fs::write(path, text).map_err(|error| {
+            InputError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })
+    }
+
+    /// Loads a recording from the RON file by `path`.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, InputError> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path).map_err(|error| {
+            InputError::Io {
+                path: path.to_path_buf(),
+                message: error.to_string(),
+            }
+        })?;
+        ron::from_str(&text).map_err(invalid)
+    }
+}
+
+/// Recorder [`Resource`], that captures [`Event`]s of [`EventChannels`] each frame.
+///
+/// All events of the frame are recorded in order of sending, including repeated ones.
+/// Combined with a fixed timestep, the [`InputRecording`] replays deterministically by [`InputReplay`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mut world = World::new();
+/// let mut recorder = InputRecorder::default();
+///
+/// let click = MouseButtonEvent::builder()
+///     .with_button(MouseButton::Left)
+///     .with_state(ButtonState::Pressed)
+///     .build()
+///     .unwrap();
+///
+/// recorder.capture(&world);
+/// world.add_event(click).add_event(click);
+/// recorder.capture(&world);
+/// world.clear_events();
+/// world.add_event(click);
+/// recorder.capture(&world);
+///
+/// let recording = recorder.recording();
+/// assert_eq!(recording.records().len(), 3);
+/// assert_eq!(recording.records()[0].frame(), 1);
+/// assert_eq!(recording.records()[2].frame(), 2);
+///
+/// let mut world = World::new();
+/// let mut replay = InputReplay::new(recording);
+/// replay.apply(&mut world).unwrap();
+/// assert!(!world.has_event::<MouseButtonEvent>());
+/// replay.apply(&mut world).unwrap();
+/// assert_eq!(world.get_events::<MouseButtonEvent>().unwrap().len(), 2);
+/// world.clear_events();
+/// replay.apply(&mut world).unwrap();
+/// assert_eq!(world.get_events::<MouseButtonEvent>().unwrap().len(), 1);
+/// assert!(replay.is_finished());
+/// ```
+#[derive(SmartDefault, Clone, Debug, Getters, CopyGetters, Resource)]
+pub struct InputRecorder {
+    channels: EventChannels,
+
+    /// The number of the next captured frame.
+    #[getset(get_copy = "pub")]
+    frame: u64,
+
+    recording: InputRecording,
+
+    /// The last error of capturing, if it's present.
+    #[getset(get = "pub")]
+    error: Option<InputError>,
+
+    #[default(true)]
+    active: bool,
+}
+
+impl InputRecorder {
+    /// Returns a [`InputRecorder`] with `channels`.
+    #[inline]
+    pub fn with_channels(mut self, channels: EventChannels) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Resumes recording.
+    #[inline]
+    pub fn start(&mut self) { self.active = true; }
+
+    /// Pauses recording, frames are still counted.
+    #[inline]
+    pub fn stop(&mut self) { self.active = false; }
+
+    /// Returns `true`, if recording isn't paused.
+    #[inline]
+    pub fn is_recording(&self) -> bool { self.active }
+
+    /// Returns a clone of the [`InputRecording`].
+    #[inline]
+    pub fn recording(&self) -> InputRecording { self.recording.clone() }
+
+    /// Captures [`Event`]s of the `world` in the current frame and advances the frame.
+    ///
+    /// If events of a channel can't be encoded, they're skipped and the error is stored.
+    pub fn capture(&mut self, world: &World) {
+        if self.active {
+            for channel in &self.channels.channels {
+                let data = match (channel.capture)(world) {
+                    | Ok(data) => data,
+                    | Err(error) => {
+                        self.error = Some(error);
+                        continue;
+                    },
+                };
+                self.recording.records.extend(data.into_iter().map(|data| {
+                    InputRecord {
+                        frame: self.frame,
+                        event: channel.name.to_string(),
+                        data,
+                    }
+                }));
+            }
+        }
+        self.frame += 1;
+    }
+
+    /// Returns the last error of capturing and removes it, if it's present.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<InputError> { self.error.take() }
+}
+
+/// Replay [`Resource`], that sends [`Event`]s of the [`InputRecording`] in their frames,
+/// in place of the windowing backend.
+///
+/// While it's playing, live [`Event`]s of [`EventChannels`] are removed before replaying,
+/// and [`GamepadBackends`](crate::backend::GamepadBackends) aren't polled,
+/// so live devices don't add input on top of the replayed one.
+///
+/// Events of other crates, like window events, are replayed only if they're registered in [`EventChannels`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let click = |button| {
+///     MouseButtonEvent::builder()
+///         .with_button(button)
+///         .with_state(ButtonState::Pressed)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut world = World::new();
+/// let mut recorder = InputRecorder::default();
+/// world.add_event(click(MouseButton::Left));
+/// recorder.capture(&world);
+///
+/// let mut world = World::new();
+/// let mut replay = InputReplay::new(recorder.recording());
+/// world.add_event(click(MouseButton::Right));
+/// replay.apply(&mut world).unwrap();
+/// let events = world.get_events::<MouseButtonEvent>().unwrap();
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(events.latest().unwrap().button(), MouseButton::Left);
+/// drop(events);
+///
+/// world.clear_events();
+/// world.add_event(click(MouseButton::Right));
+/// replay.apply(&mut world).unwrap();
+/// assert!(!replay.is_playing());
+/// assert_eq!(world.get_events::<MouseButtonEvent>().unwrap().len(), 1);
+/// ```
+#[derive(Clone, Debug, Getters, CopyGetters, Resource)]
+pub struct InputReplay {
+    channels: EventChannels,
+    recording: InputRecording,
+
+    /// The number of the next replayed frame.
+    #[getset(get_copy = "pub")]
+    frame: u64,
+
+    next: usize,
+
+    /// The last error of replaying by the [`PlaybackSystem`], if it's present.
+    #[getset(get = "pub")]
+    error: Option<InputError>,
+}
+
+impl InputReplay {
+    /// Returns a [`InputReplay`] of the `recording` with `default` [`EventChannels`].
+    pub fn new(recording: InputRecording) -> Self {
+        Self {
+            channels: EventChannels::default(),
+            recording,
+            frame: 0,
+            next: 0,
+            error: None,
+        }
+    }
+
+    /// Returns a [`InputReplay`] with `channels`.
+    #[inline]
+    pub fn with_channels(mut self, channels: EventChannels) -> Self {
+        self.channels = channels;
+        self
+    }
+
+    /// Returns the last error of replaying by the [`PlaybackSystem`] and removes it, if it's present.
+    #[inline]
+    pub fn take_error(&mut self) -> Option<InputError> { self.error.take() }
+
+    /// Returns `true`, if all records were replayed.
+    #[inline]
+    pub fn is_finished(&self) -> bool { self.next >= self.recording.records.len() }
+
+    /// Returns `true`, if the last applied frame is in the [`InputRecording`].
+    #[inline]
+    pub fn is_playing(&self) -> bool { self.frame <= self.recording.frames() }
+
+    /// Removes live [`Event`]s of [`EventChannels`], then sends [`Event`]s of the current frame
+    /// to the `world` and advances the frame.
+    ///
+    /// Events, that aren't registered in [`EventChannels`], are skipped.
+    /// After the last frame of the [`InputRecording`] live [`Event`]s aren't removed.
+    pub fn apply(&mut self, world: &mut World) -> Result<(), InputError> {
+        let frame = self.frame;
+        self.frame += 1;
+        if frame < self.recording.frames() {
+            for channel in &self.channels.channels {
+                (channel.clear)(world);
+            }
+        }
+        while let Some(record) = self.recording.records.get(self.next) {
+            if record.frame > frame {
+                break;
+            }
+            self.next += 1;
+            if let Some(channel) = self.channels.get(&record.event) {
+                (channel.replay)(world, &record.data)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// [`SystemData`], that replays [`Event`]s by the [`InputReplay`] resource, if it's present.
+///
+/// Errors of replaying are stored in the [`InputReplay`] resource.
+#[derive(Clone, Debug)]
+pub struct PlaybackUpdate {
+    commands: Commands,
+}
+
+impl PlaybackUpdate {
+    /// Replays [`Event`]s of the current frame.
+    pub fn update(self) {
+        self.commands.add(|world| {
+            if let Ok(mut replay) = world.try_remove_resource::<InputReplay>() {
+                if let Err(error) = replay.apply(world) {
+                    replay.error = Some(error);
+                }
+                world.add_resource(replay);
+            }
+        });
+    }
+}
+
+impl SystemData for PlaybackUpdate {
+    fn access(access: &mut SystemAccess) { access.write_resource::<InputReplay>(); }
+
+    #[inline]
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            commands: world.commands(),
+        }
+    }
+}
+
+/// Playback [`System`], that replays [`Event`]s before device [`System`]s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_resource(InputReplay::new(InputRecording::default()))
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::PreUpdate, PlaybackSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct PlaybackSystem {}
+
+impl System for PlaybackSystem {
+    type Data = PlaybackUpdate;
+
+    fn run(&mut self, data: Self::Data) { data.update(); }
+}
+
+/// [`SystemData`], that captures [`Event`]s by the [`InputRecorder`] resource, if it's present.
+#[derive(Clone, Debug)]
+pub struct RecordingUpdate {
+    commands: Commands,
+}
+
+impl RecordingUpdate {
+    /// Captures all [`Event`]s of the current frame.
+    pub fn update(self) {
+        self.commands.add(|world| {
+            if let Some(mut recorder) = world.get_resource_mut::<InputRecorder>() {
+                recorder.capture(world);
+            }
+        });
+    }
+}
+
+impl SystemData for RecordingUpdate {
+    fn access(access: &mut SystemAccess) { access.write_resource::<InputRecorder>(); }
+
+    #[inline]
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            commands: world.commands(),
+        }
+    }
+}
+
+/// Recording [`System`], that captures [`Event`]s after backend and action [`System`]s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_resource(InputRecorder::default())
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::PreUpdate, RecordingSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct RecordingSystem {}
+
+impl System for RecordingSystem {
+    type Data = RecordingUpdate;
+
+    fn run(&mut self, data: Self::Data) { data.update(); }
+}
@@ -3,7 +3,15 @@
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
-pub use crate::keyboard::KeyboardSystem;
+pub use crate::{
+    action::ActionSystem,
+    backend::GamepadBackendSystem,
+    gamepad::GamepadSystem,
+    keyboard::KeyboardSystem,
+    mouse::MouseSystem,
+    recording::{PlaybackSystem, RecordingSystem},
+    touch::{GestureSystem, TouchSystem},
+};
 
 /// Input [`System`].
 ///
@@ -27,3 +35,34 @@ impl System for InputSystem {
     #[allow(unused_variables)]
     fn run(&mut self, data: Self::Data) {}
 }
+
+/// Input [`SystemSet`] labels.
+///
+/// [`SystemSet`]s:
+/// * [`InputSystemSet::Playback`]
+/// * [`InputSystemSet::Devices`]
+/// * [`InputSystemSet::Backends`]
+/// * [`InputSystemSet::Actions`]
+/// * [`InputSystemSet::Recording`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, SystemLabel)]
+pub enum InputSystemSet {
+    /// The [`SystemSet`] of the [`PlaybackSystem`], that replays input [`Event`]s.
+    Playback,
+
+    /// The [`SystemSet`] of device [`System`]s, that update input state resources,
+    /// that runs after [`InputSystemSet::Playback`].
+    Devices,
+
+    /// The [`SystemSet`] of the [`GamepadBackendSystem`], that runs after [`InputSystemSet::Devices`].
+    ///
+    /// Backends apply all events of the frame by themselves,
+    /// so device [`System`]s only clear `just` states before them.
+    Backends,
+
+    /// The [`SystemSet`] of the [`ActionSystem`] and the [`GestureSystem`], that runs after [`InputSystemSet::Backends`].
+    Actions,
+
+    /// The [`SystemSet`] of the [`RecordingSystem`], that runs after [`InputSystemSet::Actions`],
+    /// so events of backends and actions are captured too.
+    Recording,
+}
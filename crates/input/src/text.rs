@@ -0,0 +1,83 @@
+//! Text input functionality.
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// Character [`Event`], that is produced by the keyboard layout or the input method.
+///
+/// Unlike [`KeyboardEvent`](crate::keyboard::KeyboardEvent), it carries the produced Unicode character,
+/// so it's correct for non-US layouts, dead keys and key repeat.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let event = ReceivedCharacter::new('ß');
+/// assert_eq!(event.character(), 'ß');
+///
+/// App::builder().with_event(event).build().unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CopyGetters, Event, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct ReceivedCharacter {
+    /// The received character.
+    character: char,
+}
+
+impl ReceivedCharacter {
+    /// Returns a [`ReceivedCharacter`] of `character`.
+    #[inline(always)]
+    pub const fn new(character: char) -> Self { Self { character } }
+
+    /// Returns `true`, if the character is a control one, like `Backspace` or `Enter`,
+    /// that text fields handle as an edit, rather than insert.
+    #[inline]
+    pub fn is_control(&self) -> bool { self.character.is_control() }
+}
+
+/// Input method editor [`Event`], that composes text, like CJK input or emoji pickers.
+///
+/// The IME is enabled per window by the window resource,
+/// the backend sends [`Ime::Enabled`] once it's ready.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_event(Ime::Preedit {
+///         value: "にほ".to_string(),
+///         cursor: Some((6, 6)),
+///     })
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Event, Serialize, Deserialize)]
+pub enum Ime {
+    /// The IME was enabled.
+    Enabled,
+
+    /// The composed text was changed, but isn't committed yet.
+    ///
+    /// An empty `value` clears the composition.
+    Preedit {
+        /// The composed text.
+        value: String,
+
+        /// The byte range of the cursor in `value`, if it's shown.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composed text was committed, and should be inserted.
+    Commit {
+        /// The committed text.
+        value: String,
+    },
+
+    /// The IME was disabled.
+    #[default]
+    Disabled,
+}
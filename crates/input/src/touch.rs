@@ -0,0 +1,756 @@
+//! Touch and pen functionality.
+
+use std::{
+    collections::{btree_map::Entry, BTreeMap},
+    f32::consts::{PI, TAU},
+    time::{Duration, Instant},
+};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::{button::init_resource, gamepad::collect_events};
+
+/// Touch [`System`], that updates [`Touches`] and [`Pen`] resources.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::default(), TouchSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct TouchSystem {}
+
+impl System for TouchSystem {
+    type Data = (TouchUpdate, PenUpdate);
+
+    fn run(&mut self, (touches, pen): Self::Data) {
+        touches.update();
+        pen.update();
+    }
+}
+
+/// Phase of the [`TouchInput`] and [`PenInput`].
+#[derive(
+    SmartDefault, Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize,
+)]
+pub enum TouchPhase {
+    /// The finger or pen touched the screen.
+    #[default]
+    Started,
+
+    /// The finger or pen moved.
+    Moved,
+
+    /// The finger or pen was lifted from the screen.
+    Ended,
+
+    /// The touch was cancelled by the system, like when the window lost focus.
+    Cancelled,
+}
+
+/// Touch [`Event`] of the single finger.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_event(
+///         TouchInput::builder()
+///             .with_id(0)
+///             .with_phase(TouchPhase::Started)
+///             .with_position((120.0, 80.0))
+///             .with_force(0.5)
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, CopyGetters, Builder, Event, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+#[builder(
+    crate = "crate::touch",
+    setter(prefix = "with"),
+    derive(Debug, PartialEq)
+)]
+pub struct TouchInput {
+    /// The unique `Id` of the finger, while it touches the screen.
+    id: u64,
+
+    /// The [`TouchPhase`] of the touch.
+    #[builder(default)]
+    phase: TouchPhase,
+
+    /// The position of the touch in the window's client area.
+    position: (f32, f32),
+
+    /// The normalized force of the touch in `0.0..=1.0`, if the screen supports it.
+    #[builder(setter(strip_option), default)]
+    force: Option<f32>,
+}
+
+impl TouchInput {
+    /// Returns a [`TouchInputBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let touch_input_builder = TouchInput::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> TouchInputBuilder { TouchInputBuilder::default() }
+}
+
+/// State of the single finger, that touches the screen.
+#[derive(Clone, Copy, Debug, PartialEq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct Touch {
+    /// The unique `Id` of the finger.
+    id: u64,
+
+    /// The position, that the touch started at.
+    start_position: (f32, f32),
+
+    /// The time, that the touch started at.
+    start_time: Instant,
+
+    /// The position in the previous frame.
+    previous_position: (f32, f32),
+
+    /// The current position.
+    position: (f32, f32),
+
+    /// The normalized force in `0.0..=1.0`, if the screen supports it.
+    force: Option<f32>,
+}
+
+impl Touch {
+    /// Returns a movement since the previous frame.
+    #[inline]
+    pub fn delta(&self) -> (f32, f32) {
+        (
+            self.position.0 - self.previous_position.0,
+            self.position.1 - self.previous_position.1,
+        )
+    }
+
+    /// Returns a distance from the start position.
+    #[inline]
+    pub fn distance(&self) -> f32 {
+        (self.position.0 - self.start_position.0).hypot(self.position.1 - self.start_position.1)
+    }
+}
+
+/// Active touches [`Resource`], that tracks fingers by their `Id`.
+///
+/// [`TouchSystem`] applies all [`TouchInput`]s of the frame to it in order of sending.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let touch = |phase, position| {
+///     TouchInput::builder()
+///         .with_id(7)
+///         .with_phase(phase)
+///         .with_position(position)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut world = World::new();
+/// world.add_event(touch(TouchPhase::Started, (10.0, 10.0)));
+/// TouchSystem::default().run_world(&mut world);
+/// assert!(world.get_resource::<Touches>().unwrap().just_pressed(7));
+///
+/// world.clear_events();
+/// world.add_event(touch(TouchPhase::Moved, (15.0, 10.0)));
+/// TouchSystem::default().run_world(&mut world);
+/// let touches = world.get_resource::<Touches>().unwrap();
+/// assert!(!touches.just_pressed(7));
+/// assert_eq!(touches.get_pressed(7).unwrap().delta(), (5.0, 0.0));
+/// drop(touches);
+///
+/// world.clear_events();
+/// world.add_event(touch(TouchPhase::Ended, (15.0, 10.0)));
+/// TouchSystem::default().run_world(&mut world);
+/// let touches = world.get_resource::<Touches>().unwrap();
+/// assert!(touches.just_released(7));
+/// assert!(touches.is_empty());
+/// ```
+#[derive(SmartDefault, Clone, Debug, Resource)]
+pub struct Touches {
+    pressed: BTreeMap<u64, Touch>,
+    just_pressed: BTreeMap<u64, Touch>,
+    just_released: BTreeMap<u64, Touch>,
+    just_cancelled: BTreeMap<u64, Touch>,
+}
+
+impl Touches {
+    /// Applies the `event` to active touches.
+    ///
+    /// Applying the same event again doesn't change the state.
+    pub fn process(&mut self, event: &TouchInput) {
+        match event.phase {
+            | TouchPhase::Started => {
+                if let Entry::Vacant(entry) = self.pressed.entry(event.id) {
+                    let touch = Touch {
+                        id: event.id,
+                        start_position: event.position,
+                        start_time: Instant::now(),
+                        previous_position: event.position,
+                        position: event.position,
+                        force: event.force,
+                    };
+                    entry.insert(touch);
+                    self.just_pressed.insert(event.id, touch);
+                }
+            },
+            | TouchPhase::Moved => {
+                if let Some(touch) = self.pressed.get_mut(&event.id) {
+                    touch.position = event.position;
+                    touch.force = event.force;
+                }
+            },
+            | TouchPhase::Ended | TouchPhase::Cancelled => {
+                if let Some(mut touch) = self.pressed.remove(&event.id) {
+                    touch.position = event.position;
+                    touch.force = event.force;
+                    match event.phase {
+                        | TouchPhase::Ended => self.just_released.insert(event.id, touch),
+                        | _ => self.just_cancelled.insert(event.id, touch),
+                    };
+                }
+            },
+        }
+    }
+
+    /// Clears `just` states, and starts a new frame for touch deltas.
+    pub fn clear(&mut self) {
+        self.just_pressed.clear();
+        self.just_released.clear();
+        self.just_cancelled.clear();
+        for touch in self.pressed.values_mut() {
+            touch.previous_position = touch.position;
+        }
+    }
+
+    /// Returns a pressed [`Touch`] by `id`, if it's present.
+    #[inline]
+    pub fn get_pressed(&self, id: u64) -> Option<&Touch> { self.pressed.get(&id) }
+
+    /// Returns `true`, if the touch by `id` was started in this frame.
+    #[inline]
+    pub fn just_pressed(&self, id: u64) -> bool { self.just_pressed.contains_key(&id) }
+
+    /// Returns `true`, if the touch by `id` was ended in this frame.
+    #[inline]
+    pub fn just_released(&self, id: u64) -> bool { self.just_released.contains_key(&id) }
+
+    /// Returns `true`, if the touch by `id` was cancelled in this frame.
+    #[inline]
+    pub fn just_cancelled(&self, id: u64) -> bool { self.just_cancelled.contains_key(&id) }
+
+    /// Returns `true`, if any touch was started in this frame.
+    #[inline]
+    pub fn any_just_pressed(&self) -> bool { !self.just_pressed.is_empty() }
+
+    /// Returns an iterator over pressed [`Touch`]es in `Id` order.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Touch> + '_ { self.pressed.values() }
+
+    /// Returns an iterator over [`Touch`]es, that were started in this frame.
+    #[inline]
+    pub fn iter_just_pressed(&self) -> impl Iterator<Item = &Touch> + '_ {
+        self.just_pressed.values()
+    }
+
+    /// Returns an iterator over [`Touch`]es, that were ended in this frame.
+    #[inline]
+    pub fn iter_just_released(&self) -> impl Iterator<Item = &Touch> + '_ {
+        self.just_released.values()
+    }
+
+    /// Returns an iterator over [`Touch`]es, that were cancelled in this frame.
+    #[inline]
+    pub fn iter_just_cancelled(&self) -> impl Iterator<Item = &Touch> + '_ {
+        self.just_cancelled.values()
+    }
+
+    /// Returns a position of the first pressed [`Touch`], if it's present.
+    #[inline]
+    pub fn first_pressed_position(&self) -> Option<(f32, f32)> {
+        self.pressed.values().next().map(|touch| touch.position)
+    }
+
+    /// Returns a number of pressed [`Touch`]es.
+    #[inline]
+    pub fn len(&self) -> usize { self.pressed.len() }
+
+    /// Returns `true`, if no [`Touch`] is pressed.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.pressed.is_empty() }
+}
+
+/// [`SystemData`] of [`TouchInput`]s, that are sent in the current frame.
+///
+/// [`TouchUpdate::update`] applies all [`TouchInput`]s in order of sending to the [`Touches`] resource,
+/// so many fingers, that touch the screen in one frame, are all kept.
+/// If the resource isn't present, it's added.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let touch = |id, position| {
+///     TouchInput::builder()
+///         .with_id(id)
+///         .with_phase(TouchPhase::Started)
+///         .with_position(position)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut world = World::new();
+/// world
+///     .add_event(touch(0, (10.0, 10.0)))
+///     .add_event(touch(1, (50.0, 10.0)));
+///
+/// TouchSystem::default().run_world(&mut world);
+/// let touches = world.get_resource::<Touches>().unwrap();
+/// assert_eq!(touches.len(), 2);
+/// assert!(touches.just_pressed(0) && touches.just_pressed(1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct TouchUpdate {
+    events: Vec<TouchInput>,
+
+    commands: Commands,
+}
+
+impl TouchUpdate {
+    /// Returns [`TouchInput`]s in order of sending.
+    #[inline]
+    pub fn events(&self) -> &[TouchInput] { &self.events }
+
+    /// Clears `just` states of the [`Touches`] resource and applies [`TouchInput`]s to it.
+    pub fn update(self) {
+        let events = self.events;
+        self.commands.add(move |world| {
+            init_resource::<Touches>(world);
+            if let Some(mut touches) = world.get_resource_mut::<Touches>() {
+                touches.clear();
+                for event in &events {
+                    touches.process(event);
+                }
+            }
+        });
+    }
+}
+
+impl SystemData for TouchUpdate {
+    fn access(access: &mut SystemAccess) { access.write_resource::<Touches>(); }
+
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            events: collect_events(world),
+            commands: world.commands(),
+        }
+    }
+}
+
+/// Settings of touch gesture recognizers.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+pub struct GestureSettings {
+    /// The distance in pixels, that a finger can move, and still be a tap.
+    #[default(10.0)]
+    tap_slop: f32,
+
+    /// The duration, that a finger can touch, and still be a tap.
+    #[default(Duration::from_millis(300))]
+    tap_timeout: Duration,
+}
+
+impl GestureSettings {
+    /// Returns a [`GestureSettings`] with `tap_slop`.
+    #[inline]
+    pub fn with_tap_slop(mut self, tap_slop: f32) -> Self {
+        self.tap_slop = tap_slop;
+        self
+    }
+
+    /// Returns a [`GestureSettings`] with `tap_timeout`.
+    #[inline]
+    pub fn with_tap_timeout(mut self, tap_timeout: Duration) -> Self {
+        self.tap_timeout = tap_timeout;
+        self
+    }
+}
+
+/// Touch gestures [`Resource`], that are recognized from [`Touches`] in this frame.
+///
+/// * Tap - a finger was lifted quickly, without moving.
+/// * Pan - fingers moved, by the movement of their center.
+/// * Pinch - two fingers moved apart or together, by the ratio of their distances.
+/// * Rotate - two fingers rotated, by the angle in radians, counter-clockwise is positive.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let touch = |id, phase, position| {
+///     TouchInput::builder()
+///         .with_id(id)
+///         .with_phase(phase)
+///         .with_position(position)
+///         .build()
+///         .unwrap()
+/// };
+///
+/// let mut touches = Touches::default();
+/// touches.process(&touch(0, TouchPhase::Started, (0.0, 0.0)));
+/// touches.process(&touch(1, TouchPhase::Started, (10.0, 0.0)));
+/// touches.clear();
+/// touches.process(&touch(0, TouchPhase::Moved, (-5.0, 0.0)));
+/// touches.process(&touch(1, TouchPhase::Moved, (15.0, 0.0)));
+///
+/// let mut gestures = Gestures::default();
+/// gestures.update(&touches);
+///
+/// assert_eq!(gestures.pinch(), Some(2.0));
+/// assert_eq!(gestures.pan(), None);
+/// assert_eq!(gestures.tap(), None);
+/// ```
+#[derive(SmartDefault, Clone, Debug, CopyGetters, Resource)]
+#[getset(get_copy = "pub")]
+pub struct Gestures {
+    /// The [`GestureSettings`] of recognizers.
+    settings: GestureSettings,
+
+    /// The position of the tap, if it's recognized.
+    tap: Option<(f32, f32)>,
+
+    /// The movement of the fingers center, if it's recognized.
+    pan: Option<(f32, f32)>,
+
+    /// The scale of the distance between two fingers, if it's recognized.
+    pinch: Option<f32>,
+
+    /// The rotation angle of two fingers in radians, if it's recognized.
+    rotation: Option<f32>,
+
+    /// The center of fingers, if any finger is pressed.
+    center: Option<(f32, f32)>,
+}
+
+impl Gestures {
+    /// Returns a [`Gestures`] with `settings`.
+    #[inline]
+    pub fn with_settings(mut self, settings: GestureSettings) -> Self {
+        self.settings = settings;
+        self
+    }
+
+    /// Recognizes gestures from `touches` of this frame.
+    pub fn update(&mut self, touches: &Touches) {
+        self.tap = touches
+            .iter_just_released()
+            .find(|touch| {
+                touch.distance() <= self.settings.tap_slop
+                    && touch.start_time.elapsed() <= self.settings.tap_timeout
+            })
+            .map(|touch| touch.position);
+
+        let fingers = touches.iter().collect::<Vec<_>>();
+        self.center = center(fingers.iter().map(|touch| touch.position));
+        self.pan = match (
+            self.center,
+            center(fingers.iter().map(|touch| touch.previous_position)),
+        ) {
+            | (Some(center), Some(previous)) => {
+                let moved = fingers
+                    .iter()
+                    .any(|touch| touch.distance() > self.settings.tap_slop);
+                let delta = (center.0 - previous.0, center.1 - previous.1);
+                (moved && delta != (0.0, 0.0)).then_some(delta)
+            },
+            | _ => None,
+        };
+
+        (self.pinch, self.rotation) = match fingers[..] {
+            | [first, second, ..] => {
+                let vector = |a: (f32, f32), b: (f32, f32)| (b.0 - a.0, b.1 - a.1);
+                let current = vector(first.position, second.position);
+                let previous = vector(first.previous_position, second.previous_position);
+                let (length, previous_length) =
+                    (current.0.hypot(current.1), previous.0.hypot(previous.1));
+                let pinch = (previous_length > 0.0 && length != previous_length)
+                    .then(|| length / previous_length);
+                let mut angle = previous.1.atan2(previous.0) - current.1.atan2(current.0);
+                if angle > PI {
+                    angle -= TAU;
+                } else if angle <= -PI {
+                    angle += TAU;
+                }
+                (pinch, (angle != 0.0).then_some(angle))
+            },
+            | _ => (None, None),
+        };
+    }
+}
+
+fn center(positions: impl Iterator<Item = (f32, f32)>) -> Option<(f32, f32)> {
+    let (count, x, y) = positions.fold((0, 0.0, 0.0), |(count, x, y), position| {
+        (count + 1, x + position.0, y + position.1)
+    });
+    (count > 0).then(|| (x / count as f32, y / count as f32))
+}
+
+/// [`SystemData`], that updates the [`Gestures`] resource by the [`Touches`] resource.
+///
+/// The update happens while fetching, because [`SystemData`] can't borrow the [`World`].
+/// If the resource isn't present, it's added.
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct GestureUpdate {}
+
+impl SystemData for GestureUpdate {
+    fn access(access: &mut SystemAccess) {
+        access
+            .read_resource::<Touches>()
+            .write_resource::<Gestures>();
+    }
+
+    fn fetch(world: &mut World) -> Self {
+        init_resource::<Gestures>(world);
+        if let (Some(touches), Some(mut gestures)) = (
+            world.get_resource::<Touches>(),
+            world.get_resource_mut::<Gestures>(),
+        ) {
+            gestures.update(&touches);
+        }
+        Self::default()
+    }
+}
+
+/// Gesture [`System`], that updates the [`Gestures`] resource.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_stage_group(InputStageGroup::default())
+///     .with_system(InputStage::default(), GestureSystem::default())
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug)]
+pub struct GestureSystem {}
+
+impl System for GestureSystem {
+    type Data = GestureUpdate;
+
+    #[allow(unused_variables)]
+    fn run(&mut self, data: Self::Data) {}
+}
+
+/// Pen and tablet [`Event`], that carries pressure and tilt of the stylus.
+///
+/// [`TouchPhase::Moved`] without contact is a hover.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// App::builder()
+///     .with_event(
+///         PenInput::builder()
+///             .with_phase(TouchPhase::Started)
+///             .with_position((300.0, 200.0))
+///             .with_pressure(0.8)
+///             .with_tilt((15.0, -5.0))
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, CopyGetters, Builder, Event, Serialize, Deserialize)]
+#[getset(get_copy = "pub")]
+#[builder(
+    crate = "crate::touch",
+    setter(prefix = "with"),
+    derive(Debug, PartialEq)
+)]
+pub struct PenInput {
+    /// The [`TouchPhase`] of the pen contact.
+    #[builder(default)]
+    phase: TouchPhase,
+
+    /// The position of the pen in the window's client area.
+    position: (f32, f32),
+
+    /// The normalized pressure of the pen in `0.0..=1.0`.
+    #[builder(default)]
+    pressure: f32,
+
+    /// The tilt of the pen along `x` and `y` axes in degrees in `-90.0..=90.0`.
+    #[builder(default)]
+    tilt: (f32, f32),
+
+    /// The pen is turned to it's eraser end.
+    #[builder(default)]
+    eraser: bool,
+}
+
+impl PenInput {
+    /// Returns a [`PenInputBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_input::prelude::*;
+    /// #
+    /// let pen_input_builder = PenInput::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> PenInputBuilder { PenInputBuilder::default() }
+}
+
+/// Pen state [`Resource`], that is updated by [`PenInput`]s.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_input::prelude::*;
+/// #
+/// let mut world = World::new();
+/// world.add_event(
+///     PenInput::builder()
+///         .with_phase(TouchPhase::Started)
+///         .with_position((300.0, 200.0))
+///         .with_pressure(0.8)
+///         .build()
+///         .unwrap(),
+/// );
+///
+/// TouchSystem::default().run_world(&mut world);
+///
+/// let pen = world.get_resource::<Pen>().unwrap();
+/// assert!(pen.just_pressed());
+/// assert_eq!(pen.pressure(), 0.8);
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, CopyGetters, Resource)]
+#[getset(get_copy = "pub")]
+pub struct Pen {
+    /// The pen touches the tablet.
+    pressed: bool,
+
+    /// The pen touched the tablet in this frame.
+    just_pressed: bool,
+
+    /// The pen was lifted from the tablet in this frame.
+    just_released: bool,
+
+    /// The last position of the pen, if it's known.
+    position: Option<(f32, f32)>,
+
+    /// The normalized pressure of the pen in `0.0..=1.0`.
+    pressure: f32,
+
+    /// The tilt of the pen along `x` and `y` axes in degrees.
+    tilt: (f32, f32),
+
+    /// The pen is turned to it's eraser end.
+    eraser: bool,
+}
+
+impl Pen {
+    /// Applies the `event` to the pen state.
+    ///
+    /// Applying the same event again doesn't change the state.
+    pub fn process(&mut self, event: &PenInput) {
+        self.position = Some(event.position);
+        self.pressure = event.pressure;
+        self.tilt = event.tilt;
+        self.eraser = event.eraser;
+        match event.phase {
+            | TouchPhase::Started if !self.pressed => {
+                self.pressed = true;
+                self.just_pressed = true;
+            },
+            | TouchPhase::Ended | TouchPhase::Cancelled if self.pressed => {
+                self.pressed = false;
+                self.just_released = true;
+                self.pressure = 0.0;
+            },
+            | _ => {},
+        }
+    }
+
+    /// Clears `just` states.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.just_pressed = false;
+        self.just_released = false;
+    }
+}
+
+/// [`SystemData`] of [`PenInput`]s, that are sent in the current frame.
+///
+/// [`PenUpdate::update`] applies all [`PenInput`]s in order of sending to the [`Pen`] resource,
+/// so a press and release in one frame are both kept.
+/// If the resource isn't present, it's added.
+#[derive(Clone, Debug)]
+pub struct PenUpdate {
+    events: Vec<PenInput>,
+
+    commands: Commands,
+}
+
+impl PenUpdate {
+    /// Returns [`PenInput`]s in order of sending.
+    #[inline]
+    pub fn events(&self) -> &[PenInput] { &self.events }
+
+    /// Clears `just` states of the [`Pen`] resource and applies [`PenInput`]s to it.
+    pub fn update(self) {
+        let events = self.events;
+        self.commands.add(move |world| {
+            init_resource::<Pen>(world);
+            if let Some(mut pen) = world.get_resource_mut::<Pen>() {
+                pen.clear();
+                for event in &events {
+                    pen.process(event);
+                }
+            }
+        });
+    }
+}
+
+impl SystemData for PenUpdate {
+    fn access(access: &mut SystemAccess) { access.write_resource::<Pen>(); }
+
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            events: collect_events(world),
+            commands: world.commands(),
+        }
+    }
+}
@@ -115,6 +115,13 @@ pub mod render {
     pub use qinetic_render::*;
 }
 
+#[cfg(feature = "qinetic_scene")]
+pub mod scene {
+    //! Scene functionality.
+
+    pub use qinetic_scene::*;
+}
+
 #[cfg(feature = "qinetic_ui")]
 pub mod ui {
     //! User-interface functionality.
@@ -165,6 +172,9 @@ pub mod prelude {
     #[cfg(feature = "qinetic_render")]
     pub use super::render::prelude::*;
     #[doc(hidden)]
+    #[cfg(feature = "qinetic_scene")]
+    pub use super::scene::prelude::*;
+    #[doc(hidden)]
     #[cfg(feature = "qinetic_ui")]
     pub use super::ui::prelude::*;
     #[doc(hidden)]
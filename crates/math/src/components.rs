@@ -12,12 +12,24 @@ use crate::vector::Vector3;
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_math::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Transform::default())
+/// # use qinetic_ecs::prelude::*;
+/// #
+/// let app = App::builder()
+///     .with_component::<Transform>()
 ///     .build()
 ///     .unwrap();
+///
+/// let mut transform = Transform::default();
+/// transform.set_path("position.x", 10.0_f32).unwrap();
+///
+/// assert_eq!(transform.position.x, 10.0);
+/// # assert!(app
+/// #     .world()
+/// #     .get_resource::<TypeRegistry>()
+/// #     .unwrap()
+/// #     .contains::<Transform>());
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Component, Reflect)]
 pub struct Transform {
     /// Position of the [`Entity`] in the [`World`].
     pub position: Vector3<f32>,
@@ -26,7 +26,5 @@ use crate::components::Transform;
 pub struct MathPlugin {}
 
 impl Plugin for MathPlugin {
-    fn build(&mut self, app_builder: &mut AppBuilder) {
-        app_builder.with_component(Transform::default());
-    }
+    fn build(&mut self, app_builder: &mut AppBuilder) { app_builder.with_component::<Transform>(); }
 }
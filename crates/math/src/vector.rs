@@ -6,6 +6,7 @@ use std::{
 };
 
 use num_traits::{clamp, clamp_max as clamp_min, clamp_min as clamp_max, Signed};
+use qinetic_ecs::prelude::Reflect;
 use qinetic_utils::prelude::*;
 
 use crate::digit::{Digit, DigitFloat, DigitNum};
@@ -13,7 +14,7 @@ use crate::digit::{Digit, DigitFloat, DigitNum};
 macro_rules! impl_vector {
     ($(#[$attr:meta])* => $VectorN:ident { $($field:ident),+ }, $n:expr) => {
         $(#[$attr])*
-        #[derive(SmartDefault, Clone, Copy, Debug, PartialEq, PartialOrd, Neg)]
+        #[derive(SmartDefault, Clone, Copy, Debug, PartialEq, PartialOrd, Neg, Reflect)]
         pub struct $VectorN<T: Digit> {
             $(pub $field: T),+
         }
@@ -0,0 +1,28 @@
+//! Physically based render [`Bundle`]s functionality.
+
+use qinetic_ecs::prelude::*;
+use qinetic_math::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::components::PointLight;
+
+/// Point light [`Bundle`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_pbr::prelude::*;
+/// #
+/// let mut world = World::default();
+/// let entity_id = world.spawn(PointLightBundle::default());
+/// #
+/// # assert!(world.has_component::<PointLight>(entity_id));
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Bundle)]
+pub struct PointLightBundle {
+    /// The [`PointLight`] of the [`Bundle`].
+    pub point_light: PointLight,
+
+    /// The [`Transform`] of the [`Bundle`].
+    pub transform: Transform,
+}
@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_pbr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(PointLight::default())
+///     .with_component::<PointLight>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct PointLight {}
 
 /// Spot light [`Component`].
@@ -26,11 +26,11 @@ pub struct PointLight {}
 /// # use qinetic_pbr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(SpotLight::default())
+///     .with_component::<SpotLight>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct SpotLight {}
 
 /// Area light [`Component`].
@@ -41,11 +41,11 @@ pub struct SpotLight {}
 /// # use qinetic_pbr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(AreaLight::default())
+///     .with_component::<AreaLight>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct AreaLight {}
 
 /// Directional light [`Component`].
@@ -56,9 +56,9 @@ pub struct AreaLight {}
 /// # use qinetic_pbr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(DirectionalLight::default())
+///     .with_component::<DirectionalLight>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct DirectionalLight {}
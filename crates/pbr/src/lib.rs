@@ -25,6 +25,7 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod bundles;
 pub mod components;
 pub mod plugins;
 pub mod resources;
@@ -36,6 +37,7 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
+        bundles::PointLightBundle,
         components::{AreaLight, DirectionalLight, PointLight, SpotLight},
         plugins::PbrPlugin,
         resources::{PbrResource, PbrResourceBuilder, PbrResourceBuilderError},
@@ -45,11 +45,11 @@ pub struct PbrPlugin {}
 impl Plugin for PbrPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(PointLight::default())
-            .with_component(AreaLight::default())
-            .with_component(SpotLight::default())
-            .with_component(DirectionalLight::default())
-            .with_resource(PbrResource::default())
+            .with_component::<PointLight>()
+            .with_component::<AreaLight>()
+            .with_component::<SpotLight>()
+            .with_component::<DirectionalLight>()
+            .with_reflect_resource(PbrResource::default())
             .with_stage_group(PbrStageGroup::default())
             .with_system(PbrStage::default(), PbrSystem::default());
     }
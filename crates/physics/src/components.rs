@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_physics::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(BoxCollider::default())
+///     .with_component::<BoxCollider>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct BoxCollider {}
 
 /// Capsule collider [`Component`].
@@ -26,11 +26,11 @@ pub struct BoxCollider {}
 /// # use qinetic_physics::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(CapsuleCollider::default())
+///     .with_component::<CapsuleCollider>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct CapsuleCollider {}
 
 /// Mesh collider [`Component`].
@@ -41,11 +41,11 @@ pub struct CapsuleCollider {}
 /// # use qinetic_physics::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(MeshCollider::default())
+///     .with_component::<MeshCollider>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct MeshCollider {}
 
 /// Sphere collider [`Component`].
@@ -56,11 +56,11 @@ pub struct MeshCollider {}
 /// # use qinetic_physics::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(SphereCollider::default())
+///     .with_component::<SphereCollider>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct SphereCollider {}
 
 /// Rigidbody [`Component`].
@@ -71,11 +71,11 @@ pub struct SphereCollider {}
 /// # use qinetic_physics::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(Rigidbody::default())
+///     .with_component::<Rigidbody>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Rigidbody {}
 
 /// Softbody [`Component`].
@@ -85,12 +85,9 @@ pub struct Rigidbody {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_physics::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Softbody::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Softbody>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Softbody {}
 
 /// Cloth [`Component`].
@@ -100,10 +97,7 @@ pub struct Softbody {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_physics::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Cloth::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Cloth>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Cloth {}
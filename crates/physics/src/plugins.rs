@@ -56,14 +56,14 @@ pub struct PhysicsPlugin {}
 impl Plugin for PhysicsPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(BoxCollider::default())
-            .with_component(CapsuleCollider::default())
-            .with_component(MeshCollider::default())
-            .with_component(SphereCollider::default())
-            .with_component(Rigidbody::default())
-            .with_component(Softbody::default())
-            .with_component(Cloth::default())
-            .with_resource(PhysicsResource::default())
+            .with_component::<BoxCollider>()
+            .with_component::<CapsuleCollider>()
+            .with_component::<MeshCollider>()
+            .with_component::<SphereCollider>()
+            .with_component::<Rigidbody>()
+            .with_component::<Softbody>()
+            .with_component::<Cloth>()
+            .with_reflect_resource(PhysicsResource::default())
             .with_stage_group(PhysicsStageGroup::default())
             .with_system(PhysicsStage::default(), PhysicsSystem::default());
     }
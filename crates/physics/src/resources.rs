@@ -15,7 +15,7 @@ use qinetic_utils::prelude::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource)]
+#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -0,0 +1,28 @@
+//! Render [`Bundle`]s functionality.
+
+use qinetic_ecs::prelude::*;
+use qinetic_math::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::components::Sprite;
+
+/// Sprite [`Bundle`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_render::prelude::*;
+/// #
+/// let mut world = World::default();
+/// let entity_id = world.spawn(SpriteBundle::default());
+/// #
+/// # assert!(world.has_component::<Sprite>(entity_id));
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Bundle)]
+pub struct SpriteBundle {
+    /// The [`Sprite`] of the [`Bundle`].
+    pub sprite: Sprite,
+
+    /// The [`Transform`] of the [`Bundle`].
+    pub transform: Transform,
+}
@@ -2,6 +2,8 @@
 
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+#[allow(unused_imports)]
+use qinetic_window::window::{Window, WindowId};
 
 /// Mesh [`Component`].
 ///
@@ -10,25 +12,48 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_render::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Mesh::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Mesh>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Mesh {}
 
-/// Camera [`Component`].
+/// Camera [`Component`], that renders to the target [`Window`].
 ///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_render::prelude::*;
+/// # use qinetic_window::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Camera::default())
-///     .build()
-///     .unwrap();
+/// let camera = Camera::default().with_window(WindowId::new(1));
+/// assert_eq!(camera.window(), WindowId::new(1));
+///
+/// App::builder().with_component::<Camera>().build().unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, CopyGetters, Component, Reflect)]
+#[getset(get_copy = "pub")]
+pub struct Camera {
+    /// The [`WindowId`] of the target [`Window`], the primary one by default.
+    window: WindowId,
+}
+
+impl Camera {
+    /// Returns a [`Camera`] with the target `window`.
+    #[inline]
+    pub fn with_window(mut self, window: WindowId) -> Self {
+        self.window = window;
+        self
+    }
+}
+
+/// Sprite [`Component`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_render::prelude::*;
+/// #
+/// App::builder().with_component::<Sprite>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
-pub struct Camera {}
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
+pub struct Sprite {}
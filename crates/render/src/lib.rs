@@ -25,6 +25,7 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod bundles;
 pub mod components;
 pub mod plugins;
 pub mod resources;
@@ -36,7 +37,8 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
-        components::{Camera, Mesh},
+        bundles::SpriteBundle,
+        components::{Camera, Mesh, Sprite},
         plugins::RenderPlugin,
         resources::{RenderApi, RenderResource, RenderResourceBuilder, RenderResourceBuilderError},
         stages::{RenderStage, RenderStageGroup},
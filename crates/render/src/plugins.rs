@@ -6,7 +6,7 @@ use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 use crate::{
-    components::{Camera, Mesh},
+    components::{Camera, Mesh, Sprite},
     resources::RenderResource,
     stages::{RenderStage, RenderStageGroup},
     systems::RenderSystem,
@@ -17,6 +17,7 @@ use crate::{
 /// [`Component`]s:
 /// * [`Mesh`]
 /// * [`Camera`]
+/// * [`Sprite`]
 ///
 /// [`Stage`]s:
 /// * [`RenderStageGroup`]
@@ -43,9 +44,10 @@ pub struct RenderPlugin {}
 impl Plugin for RenderPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(Mesh::default())
-            .with_component(Camera::default())
-            .with_resource(RenderResource::default())
+            .with_component::<Mesh>()
+            .with_component::<Camera>()
+            .with_component::<Sprite>()
+            .with_reflect_resource(RenderResource::default())
             .with_stage_group(RenderStageGroup::default())
             .with_system(RenderStage::default(), RenderSystem::default());
     }
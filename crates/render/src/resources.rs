@@ -15,7 +15,7 @@ use qinetic_utils::prelude::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource)]
+#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -66,7 +66,7 @@ impl RenderResource {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum RenderApi {
     /// Platform specific `api`.
     #[default]
@@ -0,0 +1,82 @@
+//! Error functionality.
+
+use qinetic_ecs::prelude::*;
+use thiserror::Error;
+
+/// Error of the [`DynamicScene`](crate::scene::DynamicScene) serialization, extraction and writing.
+#[derive(Debug, Error)]
+pub enum SceneError {
+    /// The RON text is invalid.
+    #[error("Failed to parse RON scene, it's invalid: {0}.")]
+    RonParse(#[from] ron::error::SpannedError),
+
+    /// The RON text can't be written.
+    #[error("Failed to write RON scene: {0}.")]
+    Ron(#[from] ron::Error),
+
+    /// The JSON text is invalid or can't be written.
+    #[error("Failed to read or write JSON scene: {0}.")]
+    Json(#[from] serde_json::Error),
+
+    /// The file can't be read.
+    #[error("Failed to read or write scene: {0}.")]
+    Io(#[from] std::io::Error),
+
+    /// The binary data is invalid.
+    #[error("Failed to read binary scene, it's invalid: {reason}.")]
+    InvalidData {
+        /// The reason of the failure.
+        reason: &'static str,
+    },
+
+    /// The checksum of the binary data doesn't match the written one.
+    #[error(
+        "Failed to read binary scene, it's checksum {found:#010x} doesn't match {expected:#010x}."
+    )]
+    ChecksumMismatch {
+        /// The written checksum.
+        expected: u32,
+
+        /// The checksum of the read data.
+        found: u32,
+    },
+
+    /// The scene was written by newer format version, than supported.
+    #[error("Failed to read scene of version {version}, it's newer than {current}.")]
+    UnsupportedVersion {
+        /// The version of the scene.
+        version: u32,
+
+        /// The supported version.
+        current: u32,
+    },
+
+    /// The type is not registered in [`TypeRegistry`].
+    #[error("Failed to reflect `{name}`, it's not registered.")]
+    UnregisteredType {
+        /// The name of the type.
+        name: String,
+    },
+
+    /// The type is registered in [`TypeRegistry`], but not as [`Component`].
+    #[error("Failed to reflect `{name}`, it's not registered as component.")]
+    NotComponent {
+        /// The name of the type.
+        name: String,
+    },
+
+    /// The type is registered in [`TypeRegistry`], but not as [`Resource`].
+    #[error("Failed to reflect `{name}`, it's not registered as resource.")]
+    NotResource {
+        /// The name of the type.
+        name: String,
+    },
+
+    /// The value can't be reflected.
+    #[error(transparent)]
+    Reflect(#[from] ReflectError),
+
+    /// The [`World`] can't be accessed.
+    #[error(transparent)]
+    World(#[from] WorldError),
+}
@@ -0,0 +1,54 @@
+//! [![](https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_logo.svg)](https://github.com/vl-mr-freeman/qinetic)
+//!
+//! Scene functionality for Qinetic.
+//!
+//! # Examples
+//!
+//! Here is a simple scene round trip:
+//! ```
+//! use qinetic_ecs::prelude::*;
+//! use qinetic_scene::prelude::*;
+//!
+//! #[derive(Default, Component, Reflect)]
+//! struct Health(u32);
+//!
+//! fn main() {
+//!     let mut world = World::default();
+//!     let mut type_registry = TypeRegistry::default();
+//!     type_registry.register_component::<Health>();
+//!     world.add_resource(type_registry);
+//!     world.spawn(Health(100));
+//!
+//!     let text = DynamicScene::from_world(&world)
+//!         .unwrap()
+//!         .serialize(SceneFormat::Ron)
+//!         .unwrap();
+//!
+//!     DynamicScene::deserialize(&text, SceneFormat::Ron)
+//!         .unwrap()
+//!         .write_to_world(&mut world)
+//!         .unwrap();
+//! }
+//! ```
+
+#![doc(
+    html_logo_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg",
+    html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
+)]
+
+pub mod error;
+pub mod loader;
+pub mod save;
+pub mod scene;
+
+pub mod prelude {
+    //! Main scene functionality.
+
+    #[doc(hidden)]
+    pub use crate::{
+        error::SceneError,
+        loader::SceneLoader,
+        save::{SaveHeader, SaveLoader, SaveOptions, SaveProgress},
+        scene::{DynamicEntity, DynamicScene, DynamicSceneBuilder, SceneFormat},
+    };
+}
@@ -0,0 +1,53 @@
+//! Scene loading.
+
+use qinetic_asset::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::scene::{DynamicScene, SceneFormat};
+
+impl Asset for DynamicScene {}
+
+/// [`AssetLoader`] of [`DynamicScene`] in RON (`.scn.ron`), JSON (`.scn.json`) or binary save (`.scn`) format.
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// # use qinetic_scene::prelude::*;
+/// #
+/// let server = AssetServer::new("assets");
+/// server.add_loader(SceneLoader);
+///
+/// let handle = server.load::<DynamicScene>("levels/start.scn.ron");
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct SceneLoader;
+
+impl AssetLoader for SceneLoader {
+    type Asset = DynamicScene;
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { &["scn.ron", "scn.json", "scn"] }
+
+    fn load(&self, bytes: Vec<u8>, context: &mut LoadContext) -> Result<DynamicScene, AssetError> {
+        let format = context
+            .path()
+            .extension()
+            .and_then(|extension| SceneFormat::from_extension(extension.to_str()?));
+        let scene = match format {
+            | Some(format) => {
+                let text = String::from_utf8(bytes).map_err(|error| {
+                    AssetError::InvalidData {
+                        reason: error.to_string(),
+                    }
+                })?;
+                DynamicScene::deserialize(&text, format)
+            },
+            | None => DynamicScene::from_binary(&bytes),
+        };
+        scene.map_err(|error| {
+            AssetError::InvalidData {
+                reason: error.to_string(),
+            }
+        })
+    }
+}
@@ -0,0 +1,828 @@
+//! Save functionality.
+
+use std::{
+    collections::{BTreeMap, HashMap, VecDeque},
+    fs,
+    io::{Read, Write},
+    path::PathBuf,
+    sync::mpsc::{self, Receiver, Sender, TryRecvError},
+    thread,
+};
+
+use flate2::{read::DeflateDecoder, write::DeflateEncoder, Compression};
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::{
+    error::SceneError,
+    scene::{DynamicEntity, DynamicScene},
+};
+
+/// The magic bytes, that every binary save starts with.
+pub const SAVE_MAGIC: [u8; 4] = *b"QSAV";
+
+/// The version of binary save format, that is written and supported for reading.
+pub const SAVE_VERSION: u32 = 1;
+
+/// The flag of compressed payload.
+const FLAG_COMPRESSION: u32 = 1 << 0;
+
+/// The flag of payload with checksum.
+const FLAG_CHECKSUM: u32 = 1 << 1;
+
+/// The count of [`DynamicEntity`]s, that [`SaveLoader`] sends at once.
+const BATCH_SIZE: usize = 256;
+
+/// Options of binary save writing.
+///
+/// # Examples
+/// ```
+/// # use qinetic_scene::prelude::*;
+/// #
+/// let options = SaveOptions::default().with_compression(true);
+///
+/// assert!(options.checksum());
+/// assert!(options.compression());
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct SaveOptions {
+    /// Write CRC-32 checksum of the payload, that is verified on reading.
+    #[default(true)]
+    checksum: bool,
+
+    /// Compress the payload by deflate.
+    compression: bool,
+}
+
+impl SaveOptions {
+    /// Returns a [`SaveOptions`] with `checksum`.
+    #[inline]
+    pub fn with_checksum(mut self, checksum: bool) -> Self {
+        self.checksum = checksum;
+        self
+    }
+
+    /// Returns a [`SaveOptions`] with `compression`.
+    #[inline]
+    pub fn with_compression(mut self, compression: bool) -> Self {
+        self.compression = compression;
+        self
+    }
+}
+
+/// Header of binary save, that precedes the payload.
+///
+/// All numbers are little-endian:
+/// * magic: `[u8; 4]`, [`SAVE_MAGIC`]
+/// * version: `u32`
+/// * flags: `u32`
+/// * checksum: `u32`, CRC-32 of the stored payload or `0`
+/// * length: `u64`, length of the stored payload
+///
+/// The compressed payload starts with `u64` length of the decompressed payload,
+/// so decompression stops at it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, CopyGetters)]
+#[getset(get_copy = "pub")]
+pub struct SaveHeader {
+    /// The version of the format.
+    version: u32,
+
+    /// The flags of the payload.
+    #[getset(skip)]
+    flags: u32,
+
+    /// The CRC-32 of the stored payload, if it's written.
+    checksum: Option<u32>,
+
+    /// The length of the stored payload.
+    length: u64,
+}
+
+impl SaveHeader {
+    /// The size of [`SaveHeader`] in bytes.
+    pub const SIZE: usize = 24;
+
+    /// Returns a [`SaveHeader`] read from the start of `bytes`.
+    pub fn read(bytes: &[u8]) -> Result<Self, SceneError> {
+        if bytes.len() < Self::SIZE || bytes[..4] != SAVE_MAGIC {
+            return Err(SceneError::InvalidData {
+                reason: "missing save header",
+            });
+        }
+
+        let u32_at = |at: usize| u32::from_le_bytes(bytes[at..at + 4].try_into().unwrap());
+        let version = u32_at(4);
+        if version > SAVE_VERSION {
+            return Err(SceneError::UnsupportedVersion {
+                version,
+                current: SAVE_VERSION,
+            });
+        }
+
+        let flags = u32_at(8);
+        Ok(Self {
+            version,
+            flags,
+            checksum: (flags & FLAG_CHECKSUM != 0).then(|| u32_at(12)),
+            length: u64::from_le_bytes(bytes[16..24].try_into().unwrap()),
+        })
+    }
+
+    /// Returns `true`, if the payload is compressed.
+    #[inline]
+    pub fn is_compressed(&self) -> bool { self.flags & FLAG_COMPRESSION != 0 }
+
+    fn write(&self, bytes: &mut Vec<u8>) {
+        bytes.extend_from_slice(&SAVE_MAGIC);
+        bytes.extend_from_slice(&self.version.to_le_bytes());
+        bytes.extend_from_slice(&self.flags.to_le_bytes());
+        bytes.extend_from_slice(&self.checksum.unwrap_or_default().to_le_bytes());
+        bytes.extend_from_slice(&self.length.to_le_bytes());
+    }
+}
+
+impl DynamicScene {
+    /// Returns a binary save of the [`DynamicScene`] with `options`.
+    ///
+    /// Names of types and fields are written once, so the save is much smaller, than text formats.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// # use qinetic_scene::prelude::*;
+    /// #
+    /// #[derive(Default, Component, Reflect)]
+    /// struct Health(u32);
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register_component::<Health>();
+    ///
+    /// let mut world = World::default();
+    /// world.add_resource(type_registry);
+    /// world.spawn(Health(100));
+    ///
+    /// let scene = DynamicScene::from_world(&world).unwrap();
+    /// let bytes = scene
+    ///     .to_binary(SaveOptions::default().with_compression(true))
+    ///     .unwrap();
+    ///
+    /// assert_eq!(DynamicScene::from_binary(&bytes).unwrap(), scene);
+    ///
+    /// // The payload, that decompresses to more bytes, than declared.
+    /// let mut bytes = scene
+    ///     .to_binary(
+    ///         SaveOptions::default()
+    ///             .with_compression(true)
+    ///             .with_checksum(false),
+    ///     )
+    ///     .unwrap();
+    /// bytes[SaveHeader::SIZE] -= 1;
+    ///
+    /// assert!(matches!(
+    ///     DynamicScene::from_binary(&bytes),
+    ///     Err(SceneError::InvalidData { .. })
+    /// ));
+    /// ```
+    pub fn to_binary(&self, options: SaveOptions) -> Result<Vec<u8>, SceneError> {
+        let mut payload = Encoder::default().encode(self);
+        let mut flags = 0;
+
+        if options.compression {
+            let mut encoder = DeflateEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(&payload)?;
+            let mut compressed = (payload.len() as u64).to_le_bytes().to_vec();
+            compressed.extend_from_slice(&encoder.finish()?);
+            payload = compressed;
+            flags |= FLAG_COMPRESSION;
+        }
+
+        let checksum = options.checksum.then(|| crc32fast::hash(&payload));
+        if checksum.is_some() {
+            flags |= FLAG_CHECKSUM;
+        }
+
+        let mut bytes = Vec::with_capacity(SaveHeader::SIZE + payload.len());
+        SaveHeader {
+            version: SAVE_VERSION,
+            flags,
+            checksum,
+            length: payload.len() as u64,
+        }
+        .write(&mut bytes);
+        bytes.extend_from_slice(&payload);
+        Ok(bytes)
+    }
+
+    /// Returns a [`DynamicScene`] read from binary save `bytes`.
+    ///
+    /// Values are migrated by [`TypeRegistration`] on writing to the [`World`],
+    /// so saves of older schema versions still load.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// # use qinetic_scene::prelude::*;
+    /// #
+    /// #[derive(Default, Component, Reflect)]
+    /// #[reflect(name = "game::Health")]
+    /// struct Health {
+    ///     current: u32,
+    ///     max: u32,
+    /// }
+    ///
+    /// // The save, that was written before `hp` was renamed to `current` and `max` was added.
+    /// let old = r#"(version: 1, entities: [(id: 0, components: {"game::Health": {"hp": 50}})])"#;
+    /// let mut bytes = DynamicScene::deserialize(old, SceneFormat::Ron)
+    ///     .unwrap()
+    ///     .to_binary(SaveOptions::default())
+    ///     .unwrap();
+    ///
+    /// let mut world = World::default();
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry
+    ///     .register_component::<Health>()
+    ///     .with_version(1)
+    ///     .with_migration(0, |value| {
+    ///         if let Some(hp) = value.remove("hp") {
+    ///             value.insert("current", hp);
+    ///         }
+    ///     });
+    /// world.add_resource(type_registry);
+    ///
+    /// let entity_map = DynamicScene::from_binary(&bytes)
+    ///     .unwrap()
+    ///     .write_to_world(&mut world)
+    ///     .unwrap();
+    /// let (_, entity_id) = entity_map.iter().next().unwrap();
+    ///
+    /// assert_eq!(
+    ///     world.get_component::<Health>(entity_id).unwrap().current,
+    ///     50
+    /// );
+    ///
+    /// let last = bytes.len() - 1;
+    /// bytes[last] ^= 0xFF;
+    ///
+    /// assert!(matches!(
+    ///     DynamicScene::from_binary(&bytes),
+    ///     Err(SceneError::ChecksumMismatch { .. })
+    /// ));
+    /// ```
+    pub fn from_binary(bytes: &[u8]) -> Result<Self, SceneError> {
+        let payload = payload(bytes)?;
+        let mut decoder = Decoder::new(&payload)?;
+        let (mut scene, count) = decoder.decode_scene()?;
+        for id in decoder.entity_ids(count)? {
+            scene.entities.push(decoder.decode_entity(id)?);
+        }
+        Ok(scene)
+    }
+}
+
+/// Returns a verified and decompressed payload of binary save `bytes`.
+fn payload(bytes: &[u8]) -> Result<Vec<u8>, SceneError> {
+    let header = SaveHeader::read(bytes)?;
+    let payload = usize::try_from(header.length)
+        .ok()
+        .and_then(|length| bytes.get(SaveHeader::SIZE..SaveHeader::SIZE.checked_add(length)?))
+        .ok_or(SceneError::InvalidData {
+            reason: "truncated payload",
+        })?;
+
+    if let Some(expected) = header.checksum {
+        let found = crc32fast::hash(payload);
+        if found != expected {
+            return Err(SceneError::ChecksumMismatch { expected, found });
+        }
+    }
+
+    if header.is_compressed() {
+        let (length, payload) =
+            payload
+                .split_first_chunk::<8>()
+                .ok_or(SceneError::InvalidData {
+                    reason: "truncated payload",
+                })?;
+        let length = u64::from_le_bytes(*length);
+
+        let mut decompressed = Vec::new();
+        DeflateDecoder::new(payload)
+            .take(length.saturating_add(1))
+            .read_to_end(&mut decompressed)?;
+        if decompressed.len() as u64 != length {
+            return Err(SceneError::InvalidData {
+                reason: "decompressed length mismatch",
+            });
+        }
+        Ok(decompressed)
+    } else {
+        Ok(payload.to_vec())
+    }
+}
+
+/// Progress of [`SaveLoader`].
+#[derive(Clone, Debug)]
+pub enum SaveProgress {
+    /// The save is loading.
+    Loading {
+        /// The count of written `Entity`s.
+        loaded: usize,
+
+        /// The count of all `Entity`s, if it's known.
+        total: Option<usize>,
+    },
+
+    /// The save is loaded, with [`EntityMap`] from ids of [`DynamicEntity`]s to new `Entity`s.
+    Done(EntityMap),
+}
+
+/// Message from the background thread of [`SaveLoader`].
+enum SaveMessage {
+    /// The [`DynamicScene`] without [`DynamicEntity`]s and ids of all [`DynamicEntity`]s.
+    Scene(DynamicScene, Vec<usize>),
+
+    /// The next [`DynamicEntity`]s.
+    Entities(Vec<DynamicEntity>),
+}
+
+/// Loader of binary save, that reads and decodes it on a background thread.
+///
+/// `Entity`s are written to the [`World`] incrementally by [`SaveLoader::poll`],
+/// so large saves don't block a frame.
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_scene::prelude::*;
+/// #
+/// #[derive(Default, Component, Reflect)]
+/// #[reflect(name = "game::Health")]
+/// struct Health(u32);
+///
+/// #[derive(Default, Resource, Reflect)]
+/// #[reflect(name = "game::Score")]
+/// struct Score(u32);
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register_component::<Health>();
+/// type_registry.register_resource::<Score>();
+///
+/// let mut world = World::default();
+/// world.add_resource(type_registry).add_resource(Score(10));
+/// let entity_id = world.spawn(Health(100));
+///
+/// let bytes = DynamicScene::builder(&world)
+///     .with_entity(entity_id)
+///     .with_resource::<Score>()
+///     .build()
+///     .unwrap()
+///     .to_binary(SaveOptions::default().with_compression(true))
+///     .unwrap();
+///
+/// let mut loader = SaveLoader::from_bytes(bytes);
+/// let entity_map = loop {
+///     if let SaveProgress::Done(entity_map) = loader.poll(&mut world, 64).unwrap() {
+///         break entity_map;
+///     }
+/// };
+///
+/// let loaded = entity_map.get(entity_id).unwrap();
+/// assert_eq!(world.get_component::<Health>(loaded).unwrap().0, 100);
+///
+/// world.get_resource_mut::<Score>().unwrap().0 = 20;
+/// assert!(matches!(
+///     loader.poll(&mut world, 64),
+///     Ok(SaveProgress::Done(_))
+/// ));
+/// assert_eq!(world.get_resource::<Score>().unwrap().0, 20);
+/// ```
+pub struct SaveLoader {
+    receiver: Receiver<Result<SaveMessage, SceneError>>,
+
+    scene: Option<DynamicScene>,
+
+    entity_map: EntityMap,
+
+    pending: VecDeque<DynamicEntity>,
+
+    loaded: usize,
+
+    total: Option<usize>,
+
+    done: bool,
+}
+
+impl SaveLoader {
+    /// Returns a [`SaveLoader`] of binary save `bytes`.
+    pub fn from_bytes(bytes: Vec<u8>) -> Self { Self::spawn(move || Ok(bytes)) }
+
+    /// Returns a [`SaveLoader`] of binary save file by `path`.
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        Self::spawn(move || fs::read(path))
+    }
+
+    fn spawn(read: impl FnOnce() -> std::io::Result<Vec<u8>> + Send + 'static) -> Self {
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            if let Err(error) = Self::load(read, &sender) {
+                sender.send(Err(error)).ok();
+            }
+        });
+
+        Self {
+            receiver,
+            scene: None,
+            entity_map: EntityMap::default(),
+            pending: VecDeque::new(),
+            loaded: 0,
+            total: None,
+            done: false,
+        }
+    }
+
+    fn load(
+        read: impl FnOnce() -> std::io::Result<Vec<u8>>,
+        sender: &Sender<Result<SaveMessage, SceneError>>,
+    ) -> Result<(), SceneError> {
+        let payload = payload(&read()?)?;
+        let mut decoder = Decoder::new(&payload)?;
+        let (scene, count) = decoder.decode_scene()?;
+        let ids = decoder.entity_ids(count)?;
+        if sender
+            .send(Ok(SaveMessage::Scene(scene, ids.clone())))
+            .is_err()
+        {
+            return Ok(());
+        }
+
+        let mut batch = Vec::with_capacity(BATCH_SIZE);
+        for id in ids {
+            batch.push(decoder.decode_entity(id)?);
+            if batch.len() == BATCH_SIZE {
+                let batch = std::mem::replace(&mut batch, Vec::with_capacity(BATCH_SIZE));
+                if sender.send(Ok(SaveMessage::Entities(batch))).is_err() {
+                    return Ok(());
+                }
+            }
+        }
+        if !batch.is_empty() {
+            sender.send(Ok(SaveMessage::Entities(batch))).ok();
+        }
+        Ok(())
+    }
+
+    /// Writes up to `budget` decoded `Entity`s to `world` and returns a [`SaveProgress`].
+    ///
+    /// [`Resource`]s are written last, when all `Entity`s are written.
+    /// When the save is loaded, returns [`SaveProgress::Done`] again without writing to `world`.
+    pub fn poll(&mut self, world: &mut World, budget: usize) -> Result<SaveProgress, SceneError> {
+        if self.done {
+            return Ok(SaveProgress::Done(self.entity_map.clone()));
+        }
+
+        let mut finished = false;
+        loop {
+            match self.receiver.try_recv() {
+                | Ok(Ok(SaveMessage::Scene(scene, ids))) => {
+                    self.total = Some(ids.len());
+                    self.entity_map = DynamicScene::reserve_entities(world, ids.into_iter());
+                    self.scene = Some(scene);
+                },
+                | Ok(Ok(SaveMessage::Entities(entities))) => self.pending.extend(entities),
+                | Ok(Err(error)) => return Err(error),
+                | Err(TryRecvError::Empty) => break,
+                | Err(TryRecvError::Disconnected) => {
+                    finished = true;
+                    break;
+                },
+            }
+        }
+
+        let Some(scene) = &self.scene else {
+            return match finished {
+                | true => {
+                    Err(SceneError::InvalidData {
+                        reason: "loader stopped before the scene",
+                    })
+                },
+                | false => {
+                    Ok(SaveProgress::Loading {
+                        loaded: 0,
+                        total: None,
+                    })
+                },
+            };
+        };
+
+        let type_registry = world.try_remove_resource::<TypeRegistry>()?;
+        let result = (|| {
+            for _ in 0..budget {
+                let Some(entity) = self.pending.pop_front() else {
+                    break;
+                };
+                scene.write_entity(world, &type_registry, &self.entity_map, &entity)?;
+                self.loaded += 1;
+            }
+
+            if finished && self.pending.is_empty() && Some(self.loaded) == self.total {
+                scene.write_resources(world, &type_registry, &self.entity_map)?;
+                self.done = true;
+                return Ok(SaveProgress::Done(self.entity_map.clone()));
+            }
+            Ok(SaveProgress::Loading {
+                loaded: self.loaded,
+                total: self.total,
+            })
+        })();
+        world.add_resource(type_registry);
+        result
+    }
+}
+
+/// Tags of encoded [`ReflectValue`]s.
+mod tag {
+    pub const UNIT: u8 = 0;
+    pub const FALSE: u8 = 1;
+    pub const TRUE: u8 = 2;
+    pub const INT: u8 = 3;
+    pub const UINT: u8 = 4;
+    pub const FLOAT: u8 = 5;
+    pub const STRING: u8 = 6;
+    pub const SEQ: u8 = 7;
+    pub const MAP: u8 = 8;
+}
+
+/// Encoder of the payload.
+///
+/// The payload is:
+/// * names: count, then length and UTF-8 bytes of each
+/// * schemas: count, then name index and version of each
+/// * resources: count, then name index and value of each
+/// * entities: count, then id of each
+/// * components: for each entity count, then name index and value of each
+///
+/// Integers are LEB128 varints, signed ones are zigzag encoded.
+#[derive(Default)]
+struct Encoder {
+    names: Vec<String>,
+
+    indices: HashMap<String, usize>,
+
+    body: Vec<u8>,
+}
+
+impl Encoder {
+    fn encode(mut self, scene: &DynamicScene) -> Vec<u8> {
+        self.uint(scene.schemas.len() as u128);
+        for (name, version) in &scene.schemas {
+            self.name(name);
+            self.uint(*version as u128);
+        }
+
+        self.uint(scene.resources.len() as u128);
+        for (name, value) in &scene.resources {
+            self.name(name);
+            self.value(value);
+        }
+
+        self.uint(scene.entities.len() as u128);
+        for entity in &scene.entities {
+            self.uint(entity.id as u128);
+        }
+        for entity in &scene.entities {
+            self.uint(entity.components.len() as u128);
+            for (name, value) in &entity.components {
+                self.name(name);
+                self.value(value);
+            }
+        }
+
+        let body = std::mem::take(&mut self.body);
+        self.uint(self.names.len() as u128);
+        for name in std::mem::take(&mut self.names) {
+            self.uint(name.len() as u128);
+            self.body.extend_from_slice(name.as_bytes());
+        }
+        self.body.extend_from_slice(&body);
+        self.body
+    }
+
+    fn uint(&mut self, mut value: u128) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.body.push(byte);
+                break;
+            }
+            self.body.push(byte | 0x80);
+        }
+    }
+
+    fn name(&mut self, name: &str) {
+        let index = match self.indices.get(name) {
+            | Some(index) => *index,
+            | None => {
+                self.names.push(name.into());
+                self.indices.insert(name.into(), self.names.len() - 1);
+                self.names.len() - 1
+            },
+        };
+        self.uint(index as u128);
+    }
+
+    fn value(&mut self, value: &ReflectValue) {
+        match value {
+            | ReflectValue::Unit => self.body.push(tag::UNIT),
+            | ReflectValue::Bool(false) => self.body.push(tag::FALSE),
+            | ReflectValue::Bool(true) => self.body.push(tag::TRUE),
+            | ReflectValue::Int(v) => {
+                self.body.push(tag::INT);
+                self.uint(((v << 1) ^ (v >> 127)) as u128);
+            },
+            | ReflectValue::UInt(v) => {
+                self.body.push(tag::UINT);
+                self.uint(*v);
+            },
+            | ReflectValue::Float(v) => {
+                self.body.push(tag::FLOAT);
+                self.body.extend_from_slice(&v.to_le_bytes());
+            },
+            | ReflectValue::String(v) => {
+                self.body.push(tag::STRING);
+                self.uint(v.len() as u128);
+                self.body.extend_from_slice(v.as_bytes());
+            },
+            | ReflectValue::Seq(values) => {
+                self.body.push(tag::SEQ);
+                self.uint(values.len() as u128);
+                for value in values {
+                    self.value(value);
+                }
+            },
+            | ReflectValue::Map(entries) => {
+                self.body.push(tag::MAP);
+                self.uint(entries.len() as u128);
+                for (name, value) in entries {
+                    self.name(name);
+                    self.value(value);
+                }
+            },
+        }
+    }
+}
+
+/// Decoder of the payload, see [`Encoder`].
+struct Decoder<'a> {
+    bytes: &'a [u8],
+
+    names: Vec<String>,
+}
+
+impl<'a> Decoder<'a> {
+    fn new(bytes: &'a [u8]) -> Result<Self, SceneError> {
+        let mut decoder = Self {
+            bytes,
+            names: Vec::new(),
+        };
+
+        let count = decoder.len()?;
+        for _ in 0..count {
+            let name = decoder.string()?;
+            decoder.names.push(name);
+        }
+        Ok(decoder)
+    }
+
+    /// Decodes a [`DynamicScene`] without [`DynamicEntity`]s and returns it with count of [`DynamicEntity`]s.
+    fn decode_scene(&mut self) -> Result<(DynamicScene, usize), SceneError> {
+        let mut scene = DynamicScene::default();
+
+        for _ in 0..self.len()? {
+            let name = self.name()?;
+            let version = u32::try_from(self.uint()?).map_err(|_| {
+                SceneError::InvalidData {
+                    reason: "invalid schema version",
+                }
+            })?;
+            scene.schemas.insert(name, version);
+        }
+
+        for _ in 0..self.len()? {
+            let name = self.name()?;
+            let value = self.value()?;
+            scene.resources.insert(name, value);
+        }
+
+        let count = self.len()?;
+        Ok((scene, count))
+    }
+
+    /// Decodes ids of `count` [`DynamicEntity`]s.
+    fn entity_ids(&mut self, count: usize) -> Result<Vec<usize>, SceneError> {
+        (0..count).map(|_| self.len()).collect()
+    }
+
+    /// Decodes [`Component`]s of the next [`DynamicEntity`] by `id`, ids should be already decoded.
+    fn decode_entity(&mut self, id: usize) -> Result<DynamicEntity, SceneError> {
+        let mut components = BTreeMap::new();
+        for _ in 0..self.len()? {
+            let name = self.name()?;
+            let value = self.value()?;
+            components.insert(name, value);
+        }
+        Ok(DynamicEntity { id, components })
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8], SceneError> {
+        if self.bytes.len() < len {
+            return Err(SceneError::InvalidData {
+                reason: "truncated payload",
+            });
+        }
+        let (bytes, rest) = self.bytes.split_at(len);
+        self.bytes = rest;
+        Ok(bytes)
+    }
+
+    fn uint(&mut self) -> Result<u128, SceneError> {
+        let mut value = 0_u128;
+        for shift in (0..128).step_by(7) {
+            let byte = self.bytes(1)?[0];
+            value |= ((byte & 0x7F) as u128) << shift;
+            if byte & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(SceneError::InvalidData {
+            reason: "invalid varint",
+        })
+    }
+
+    fn len(&mut self) -> Result<usize, SceneError> {
+        usize::try_from(self.uint()?).map_err(|_| {
+            SceneError::InvalidData {
+                reason: "invalid length",
+            }
+        })
+    }
+
+    fn string(&mut self) -> Result<String, SceneError> {
+        let len = self.len()?;
+        String::from_utf8(self.bytes(len)?.to_vec()).map_err(|_| {
+            SceneError::InvalidData {
+                reason: "invalid UTF-8",
+            }
+        })
+    }
+
+    fn name(&mut self) -> Result<String, SceneError> {
+        let index = self.len()?;
+        self.names
+            .get(index)
+            .cloned()
+            .ok_or(SceneError::InvalidData {
+                reason: "invalid name index",
+            })
+    }
+
+    fn value(&mut self) -> Result<ReflectValue, SceneError> {
+        Ok(match self.bytes(1)?[0] {
+            | tag::UNIT => ReflectValue::Unit,
+            | tag::FALSE => ReflectValue::Bool(false),
+            | tag::TRUE => ReflectValue::Bool(true),
+            | tag::INT => {
+                let v = self.uint()?;
+                ReflectValue::Int(((v >> 1) as i128) ^ -((v & 1) as i128))
+            },
+            | tag::UINT => ReflectValue::UInt(self.uint()?),
+            | tag::FLOAT => {
+                ReflectValue::Float(f64::from_le_bytes(self.bytes(8)?.try_into().unwrap()))
+            },
+            | tag::STRING => ReflectValue::String(self.string()?),
+            | tag::SEQ => {
+                let len = self.len()?;
+                let mut values = Vec::with_capacity(len.min(self.bytes.len()));
+                for _ in 0..len {
+                    values.push(self.value()?);
+                }
+                ReflectValue::Seq(values)
+            },
+            | tag::MAP => {
+                let len = self.len()?;
+                let mut entries = Vec::with_capacity(len.min(self.bytes.len()));
+                for _ in 0..len {
+                    entries.push((self.name()?, self.value()?));
+                }
+                ReflectValue::Map(entries)
+            },
+            | _ => {
+                return Err(SceneError::InvalidData {
+                    reason: "invalid value tag",
+                })
+            },
+        })
+    }
+}
@@ -0,0 +1,475 @@
+//! Scene functionality.
+
+use std::{any::type_name, collections::BTreeMap};
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::error::SceneError;
+
+/// The version of [`DynamicScene`] format, that is written and supported for reading.
+pub const SCENE_VERSION: u32 = 1;
+
+/// Text format of [`DynamicScene`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SceneFormat {
+    /// The [RON](https://github.com/ron-rs/ron) format.
+    #[default]
+    Ron,
+
+    /// The JSON format.
+    Json,
+}
+
+impl SceneFormat {
+    /// Returns a [`SceneFormat`] by file `extension`, if it's supported.
+    pub fn from_extension(extension: &str) -> Option<Self> {
+        match extension {
+            | "ron" => Some(Self::Ron),
+            | "json" => Some(Self::Json),
+            | _ => None,
+        }
+    }
+}
+
+/// Value read from [`DynamicScene`] with the reflection of it's type, that adds it to the [`World`].
+type ReadValue<'a, T> = (&'a T, Box<dyn Reflect>);
+
+/// Snapshot of `Entity`s with [`Reflect`] [`Component`]s and [`Reflect`] [`Resource`]s of the [`World`].
+///
+/// Types are written by names, that are registered in [`TypeRegistry`] of the [`World`].
+/// Schema versions of the types are written too, so values are migrated on writing to the [`World`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_scene::prelude::*;
+/// #
+/// #[derive(Default, Component, Reflect)]
+/// struct Health(u32);
+///
+/// #[derive(Default, Component, Reflect)]
+/// struct Target(EntityId);
+///
+/// #[derive(Default, Resource, Reflect)]
+/// struct Score(u32);
+///
+/// let mut type_registry = TypeRegistry::default();
+/// type_registry.register_component::<Health>();
+/// type_registry.register_component::<Target>();
+/// type_registry.register_resource::<Score>();
+///
+/// let mut world = World::default();
+/// world.add_resource(type_registry).add_resource(Score(10));
+///
+/// let enemy = world.spawn(Health(50));
+/// let player = world.spawn((Health(100), Target(enemy)));
+///
+/// let text = DynamicScene::builder(&world)
+///     .with_entities([enemy, player])
+///     .with_resource::<Score>()
+///     .build()
+///     .unwrap()
+///     .serialize(SceneFormat::Json)
+///     .unwrap();
+///
+/// let mut other = World::default();
+/// other.add_resource(world.try_remove_resource::<TypeRegistry>().unwrap());
+/// other.add_entity();
+///
+/// let entity_map = DynamicScene::deserialize(&text, SceneFormat::Json)
+///     .unwrap()
+///     .write_to_world(&mut other)
+///     .unwrap();
+///
+/// let player = entity_map.get(player).unwrap();
+/// let enemy = entity_map.get(enemy).unwrap();
+///
+/// assert_eq!(other.get_component::<Health>(player).unwrap().0, 100);
+/// assert_eq!(other.get_component::<Target>(player).unwrap().0, enemy);
+/// assert_eq!(other.get_resource::<Score>().unwrap().0, 10);
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, CopyGetters, Serialize, Deserialize)]
+pub struct DynamicScene {
+    /// The version of the format.
+    #[default(SCENE_VERSION)]
+    #[getset(get_copy = "pub")]
+    pub(crate) version: u32,
+
+    /// Schema versions of the written types by their names.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    pub(crate) schemas: BTreeMap<String, u32>,
+
+    /// [`Resource`] values by their type names.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    pub(crate) resources: BTreeMap<String, ReflectValue>,
+
+    /// The [`DynamicEntity`]s.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    pub(crate) entities: Vec<DynamicEntity>,
+}
+
+/// `Entity` of the [`DynamicScene`].
+#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, CopyGetters, Serialize, Deserialize)]
+pub struct DynamicEntity {
+    /// The id of the `Entity`, that is referred by [`EntityId`]s within the [`DynamicScene`].
+    #[getset(get_copy = "pub")]
+    pub(crate) id: usize,
+
+    /// [`Component`] values by their type names.
+    #[serde(default)]
+    #[getset(get = "pub")]
+    pub(crate) components: BTreeMap<String, ReflectValue>,
+}
+
+/// Builder of [`DynamicScene`], that extracts chosen `Entity`s and [`Resource`]s of the [`World`].
+pub struct DynamicSceneBuilder<'a> {
+    world: &'a World,
+
+    entities: Vec<EntityId>,
+
+    resources: Vec<&'static str>,
+}
+
+impl DynamicScene {
+    /// Returns a [`DynamicSceneBuilder`] of `world`.
+    #[inline]
+    pub fn builder(world: &World) -> DynamicSceneBuilder<'_> {
+        DynamicSceneBuilder {
+            world,
+            entities: Vec::new(),
+            resources: Vec::new(),
+        }
+    }
+
+    /// Returns a [`DynamicScene`] with all `Entity`s of `world`.
+    #[inline]
+    pub fn from_world(world: &World) -> Result<Self, SceneError> {
+        Self::builder(world).with_entities(world.entities()).build()
+    }
+
+    /// Returns a [`DynamicScene`] read from `text` in `format`.
+    pub fn deserialize(text: &str, format: SceneFormat) -> Result<Self, SceneError> {
+        let scene: Self = match format {
+            | SceneFormat::Ron => ron::from_str(text)?,
+            | SceneFormat::Json => serde_json::from_str(text)?,
+        };
+
+        if scene.version > SCENE_VERSION {
+            return Err(SceneError::UnsupportedVersion {
+                version: scene.version,
+                current: SCENE_VERSION,
+            });
+        }
+        Ok(scene)
+    }
+
+    /// Returns a text of the [`DynamicScene`] in `format`.
+    pub fn serialize(&self, format: SceneFormat) -> Result<String, SceneError> {
+        Ok(match format {
+            | SceneFormat::Ron => ron::ser::to_string_pretty(self, Default::default())?,
+            | SceneFormat::Json => serde_json::to_string_pretty(self)?,
+        })
+    }
+
+    /// Adds `Entity`s and [`Resource`]s of the [`DynamicScene`] to `world`.
+    ///
+    /// New `Entity`s are added for all [`DynamicEntity`]s,
+    /// [`EntityId`]s within [`Component`]s and [`Resource`]s are mapped to them.
+    /// [`Resource`]s, that were already present, are replaced.
+    /// Returns a [`EntityMap`] from ids of [`DynamicEntity`]s to new `Entity`s.
+    ///
+    /// All values are read before `world` is changed, so on error `world` is left as it was.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// # use qinetic_scene::prelude::*;
+    /// #
+    /// #[derive(Default, Component, Reflect)]
+    /// #[reflect(name = "game::Health")]
+    /// struct Health(u32);
+    ///
+    /// let mut type_registry = TypeRegistry::default();
+    /// type_registry.register_component::<Health>();
+    ///
+    /// let mut world = World::default();
+    /// world.add_resource(type_registry);
+    ///
+    /// let text = r#"{
+    ///     "version": 1,
+    ///     "entities": [
+    ///         { "id": 0, "components": { "game::Health": [100] } },
+    ///         { "id": 1, "components": { "game::Mana": [50] } }
+    ///     ]
+    /// }"#;
+    ///
+    /// let result = DynamicScene::deserialize(text, SceneFormat::Json)
+    ///     .unwrap()
+    ///     .write_to_world(&mut world);
+    ///
+    /// assert!(matches!(result, Err(SceneError::UnregisteredType { .. })));
+    /// assert_eq!(world.entities().count(), 0);
+    /// ```
+    pub fn write_to_world(&self, world: &mut World) -> Result<EntityMap, SceneError> {
+        let type_registry = world.try_remove_resource::<TypeRegistry>()?;
+        let result = self.write_to_world_with(world, &type_registry);
+        world.add_resource(type_registry);
+        result
+    }
+
+    /// Adds `Entity`s and [`Resource`]s of the [`DynamicScene`] to `world` with `type_registry`.
+    ///
+    /// See [`DynamicScene::write_to_world`].
+    pub fn write_to_world_with(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+    ) -> Result<EntityMap, SceneError> {
+        let entity_map =
+            Self::reserve_entities(world, self.entities.iter().map(|entity| entity.id));
+
+        let mut components = Vec::new();
+        let mut read = || {
+            for entity in &self.entities {
+                let entity_id = Self::entity_id(&entity_map, entity)?;
+                for (component, value) in self.read_entity(type_registry, &entity_map, entity)? {
+                    components.push((entity_id, component, value));
+                }
+            }
+            self.read_resources(type_registry, &entity_map)
+        };
+        let resources = match read() {
+            | Ok(resources) => resources,
+            | Err(error) => {
+                Self::remove_entities(world, &entity_map);
+                return Err(error);
+            },
+        };
+
+        for (entity_id, component, value) in components {
+            if let Err(error) = component.insert(world, entity_id, value) {
+                Self::remove_entities(world, &entity_map);
+                return Err(error.into());
+            }
+        }
+        for (resource, value) in resources {
+            resource.insert(world, value);
+        }
+        Ok(entity_map)
+    }
+
+    /// Adds new `Entity`s to `world` for `ids` of [`DynamicEntity`]s and returns a [`EntityMap`] to them.
+    pub(crate) fn reserve_entities(
+        world: &mut World,
+        ids: impl Iterator<Item = usize>,
+    ) -> EntityMap {
+        let mut entity_map = EntityMap::default();
+        for id in ids {
+            entity_map.insert(EntityId::new(id, 0), world.add_entity());
+        }
+        entity_map
+    }
+
+    /// Removes `Entity`s of `entity_map` from `world`.
+    pub(crate) fn remove_entities(world: &mut World, entity_map: &EntityMap) {
+        for (_, entity_id) in entity_map.iter() {
+            world.remove_entity(entity_id);
+        }
+    }
+
+    /// Adds [`Component`]s of `entity` to the mapped `Entity` of `world`.
+    ///
+    /// All [`Component`]s are read before they're added, so on error none of them is added.
+    pub(crate) fn write_entity(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+        entity_map: &EntityMap,
+        entity: &DynamicEntity,
+    ) -> Result<(), SceneError> {
+        let entity_id = Self::entity_id(entity_map, entity)?;
+        for (component, value) in self.read_entity(type_registry, entity_map, entity)? {
+            component.insert(world, entity_id, value)?;
+        }
+        Ok(())
+    }
+
+    /// Adds [`Resource`]s of the [`DynamicScene`] to `world`.
+    ///
+    /// All [`Resource`]s are read before they're added, so on error none of them is added.
+    pub(crate) fn write_resources(
+        &self,
+        world: &mut World,
+        type_registry: &TypeRegistry,
+        entity_map: &EntityMap,
+    ) -> Result<(), SceneError> {
+        for (resource, value) in self.read_resources(type_registry, entity_map)? {
+            resource.insert(world, value);
+        }
+        Ok(())
+    }
+
+    /// Returns the mapped `Entity` of `entity`.
+    fn entity_id(entity_map: &EntityMap, entity: &DynamicEntity) -> Result<EntityId, SceneError> {
+        let id = EntityId::new(entity.id, 0);
+        Ok(entity_map.get(id).ok_or(WorldError::StaleEntity {
+            name: type_name::<EntityId>(),
+            entity_id: id,
+        })?)
+    }
+
+    /// Returns values of [`Component`]s of `entity` with their [`ReflectComponent`]s.
+    fn read_entity<'a>(
+        &self,
+        type_registry: &'a TypeRegistry,
+        entity_map: &EntityMap,
+        entity: &DynamicEntity,
+    ) -> Result<Vec<ReadValue<'a, ReflectComponent>>, SceneError> {
+        let mut values = Vec::with_capacity(entity.components.len());
+        for (name, value) in &entity.components {
+            let registration = self.registration(type_registry, name)?;
+            let component = registration
+                .component()
+                .as_ref()
+                .ok_or_else(|| SceneError::NotComponent { name: name.clone() })?;
+
+            let mut value_box = component.default_value();
+            self.apply(registration, name, value, entity_map, &mut *value_box)?;
+            values.push((component, value_box));
+        }
+        Ok(values)
+    }
+
+    /// Returns values of [`Resource`]s of the [`DynamicScene`] with their [`ReflectResource`]s.
+    fn read_resources<'a>(
+        &self,
+        type_registry: &'a TypeRegistry,
+        entity_map: &EntityMap,
+    ) -> Result<Vec<ReadValue<'a, ReflectResource>>, SceneError> {
+        let mut values = Vec::with_capacity(self.resources.len());
+        for (name, value) in &self.resources {
+            let registration = self.registration(type_registry, name)?;
+            let resource = registration
+                .resource()
+                .as_ref()
+                .ok_or_else(|| SceneError::NotResource { name: name.clone() })?;
+
+            let mut value_box = resource.default_value();
+            self.apply(registration, name, value, entity_map, &mut *value_box)?;
+            values.push((resource, value_box));
+        }
+        Ok(values)
+    }
+
+    fn registration<'a>(
+        &self,
+        type_registry: &'a TypeRegistry,
+        name: &str,
+    ) -> Result<&'a TypeRegistration, SceneError> {
+        type_registry
+            .get_by_name(name)
+            .ok_or_else(|| SceneError::UnregisteredType { name: name.into() })
+    }
+
+    /// Migrates `value` of type by `name` and applies it to `target`, mapping it's [`EntityId`]s.
+    fn apply(
+        &self,
+        registration: &TypeRegistration,
+        name: &str,
+        value: &ReflectValue,
+        entity_map: &EntityMap,
+        target: &mut dyn Reflect,
+    ) -> Result<(), SceneError> {
+        let mut value = value.clone();
+        let version = self.schemas.get(name).copied().unwrap_or_default();
+        registration.migrate(version, &mut value)?;
+
+        target.apply_value(&value)?;
+        entity_map.map_entities(target);
+        Ok(())
+    }
+}
+
+impl<'a> DynamicSceneBuilder<'a> {
+    /// Returns a [`DynamicSceneBuilder`] with added `Entity` by [`EntityId`].
+    #[inline]
+    pub fn with_entity(mut self, entity_id: EntityId) -> Self {
+        self.entities.push(entity_id);
+        self
+    }
+
+    /// Returns a [`DynamicSceneBuilder`] with added `Entity`s by [`EntityId`]s.
+    #[inline]
+    pub fn with_entities(mut self, entity_ids: impl IntoIterator<Item = EntityId>) -> Self {
+        self.entities.extend(entity_ids);
+        self
+    }
+
+    /// Returns a [`DynamicSceneBuilder`] with added [`Resource`] by `T`.
+    #[inline]
+    pub fn with_resource<T: Resource + Reflect>(mut self) -> Self {
+        self.resources.push(T::info().name());
+        self
+    }
+
+    /// Returns a [`DynamicScene`] with values of all [`Reflect`] [`Component`]s of chosen `Entity`s and chosen [`Resource`]s.
+    pub fn build(self) -> Result<DynamicScene, SceneError> {
+        let type_registry = self.world.try_get_resource::<TypeRegistry>()?;
+        let mut scene = DynamicScene::default();
+
+        for entity_id in self.entities {
+            if !self.world.has_entity(entity_id) {
+                return Err(WorldError::StaleEntity {
+                    name: type_name::<EntityId>(),
+                    entity_id,
+                }
+                .into());
+            }
+
+            let mut components = BTreeMap::new();
+            for registration in type_registry.iter() {
+                let Some(component) = registration.component() else {
+                    continue;
+                };
+
+                let name = registration.info().name();
+                match component.reflect(self.world, entity_id, |value| {
+                    components.insert(name.to_string(), value.to_value());
+                }) {
+                    | Ok(()) => {
+                        scene.schemas.insert(name.into(), registration.version());
+                    },
+                    | Err(WorldError::MissingComponent { .. }) => {},
+                    | Err(error) => return Err(error.into()),
+                }
+            }
+
+            scene.entities.push(DynamicEntity {
+                id: entity_id.id(),
+                components,
+            });
+        }
+
+        for name in self.resources {
+            let registration = type_registry
+                .get_by_name(name)
+                .ok_or_else(|| SceneError::UnregisteredType { name: name.into() })?;
+            let resource = registration
+                .resource()
+                .as_ref()
+                .ok_or_else(|| SceneError::NotResource { name: name.into() })?;
+
+            resource.reflect(self.world, |value| {
+                scene.resources.insert(name.into(), value.to_value());
+            })?;
+            scene.schemas.insert(name.into(), registration.version());
+        }
+
+        Ok(scene)
+    }
+}
@@ -12,11 +12,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_ui::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(RectTransform::default())
+///     .with_component::<RectTransform>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Component, Reflect)]
 pub struct RectTransform {
     /// width, height in `ui`.
     pub ui_scale: Vector2<f32>,
@@ -47,12 +47,9 @@ pub struct RectTransform {
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_ui::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Button::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Button>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Button {}
 
 /// Image [`Component`].
@@ -62,12 +59,9 @@ pub struct Button {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_ui::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Image::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Image>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Image {}
 
 /// Text [`Component`].
@@ -77,12 +71,9 @@ pub struct Image {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_ui::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Text::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Text>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Text {}
 
 /// Slider [`Component`].
@@ -92,12 +83,9 @@ pub struct Text {}
 /// # use qinetic_app::prelude::*;
 /// # use qinetic_ui::prelude::*;
 /// #
-/// App::builder()
-///     .with_component(Slider::default())
-///     .build()
-///     .unwrap();
+/// App::builder().with_component::<Slider>().build().unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Slider {}
 
 /// Scrollbar [`Component`].
@@ -108,9 +96,9 @@ pub struct Slider {}
 /// # use qinetic_ui::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(Scrollbar::default())
+///     .with_component::<Scrollbar>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct Scrollbar {}
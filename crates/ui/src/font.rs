@@ -1,5 +1,7 @@
 //! Font loading.
 
+use qinetic_asset::prelude::*;
+use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 /// A conteiner of font data.
@@ -15,7 +17,7 @@ use qinetic_utils::prelude::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, Builder)]
+#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, Builder, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::font",
@@ -44,3 +46,47 @@ impl Font {
     #[inline]
     pub fn builder() -> FontBuilder { FontBuilder::default() }
 }
+
+/// Font [`Asset`], that contains bytes of TrueType or OpenType font file.
+#[derive(Clone, Debug, PartialEq, Eq, Getters)]
+#[getset(get = "pub")]
+pub struct FontData {
+    /// The bytes of the font file.
+    bytes: Vec<u8>,
+}
+
+impl Asset for FontData {}
+
+/// [`AssetLoader`] of [`FontData`], that is registered by the [`UiPlugin`](crate::plugins::UiPlugin).
+///
+/// # Examples
+/// ```
+/// # use qinetic_asset::prelude::*;
+/// # use qinetic_ui::prelude::*;
+/// #
+/// let server = AssetServer::new("assets");
+/// server.add_loader(FontLoader);
+///
+/// let font = Font::default();
+/// let handle = server.load::<FontData>(font.path());
+/// ```
+#[derive(SmartDefault, Clone, Copy, Debug)]
+pub struct FontLoader;
+
+impl AssetLoader for FontLoader {
+    type Asset = FontData;
+
+    #[inline]
+    fn extensions(&self) -> &[&str] { &["ttf", "otf", "ttc"] }
+
+    fn load(&self, bytes: Vec<u8>, _context: &mut LoadContext) -> Result<FontData, AssetError> {
+        match bytes.get(..4) {
+            | Some([0, 1, 0, 0] | b"true" | b"OTTO" | b"ttcf") => Ok(FontData { bytes }),
+            | _ => {
+                Err(AssetError::InvalidData {
+                    reason: "unknown font signature".into(),
+                })
+            },
+        }
+    }
+}
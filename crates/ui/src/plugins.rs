@@ -1,12 +1,14 @@
 //! User-interface [`Plugin`]s functionality.
 
 use qinetic_app::prelude::*;
+use qinetic_asset::prelude::*;
 #[allow(unused_imports)]
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 use crate::{
     components::{Button, Image, RectTransform, Scrollbar, Slider, Text},
+    font::FontLoader,
     resources::UiResource,
     stages::{UiStage, UiStageGroup},
     systems::UiSystem,
@@ -28,6 +30,9 @@ use crate::{
 /// [`Resource`]:
 /// * [`UiResource`]
 ///
+/// [`AssetLoader`]s:
+/// * [`FontLoader`]
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
@@ -44,14 +49,15 @@ pub struct UiPlugin {}
 impl Plugin for UiPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(RectTransform::default())
-            .with_component(Button::default())
-            .with_component(Image::default())
-            .with_component(Text::default())
-            .with_component(Slider::default())
-            .with_component(Scrollbar::default())
-            .with_resource(UiResource::default())
+            .with_component::<RectTransform>()
+            .with_component::<Button>()
+            .with_component::<Image>()
+            .with_component::<Text>()
+            .with_component::<Slider>()
+            .with_component::<Scrollbar>()
+            .with_reflect_resource(UiResource::default())
             .with_stage_group(UiStageGroup::default())
-            .with_system(UiStage::default(), UiSystem::default());
+            .with_system(UiStage::default(), UiSystem::default())
+            .with_asset_loader(FontLoader);
     }
 }
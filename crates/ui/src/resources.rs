@@ -17,7 +17,7 @@ use crate::{font::Font, theme::Theme};
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource)]
+#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -1,5 +1,6 @@
 //! Theme creation and loading.
 
+use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
 /// A container of theme data.
@@ -14,7 +15,7 @@ use qinetic_utils::prelude::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Getters, Builder)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Getters, Builder, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::theme",
@@ -11,11 +11,11 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_vr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(VrController::default())
+///     .with_component::<VrController>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct VrController {}
 
 /// Virtual reality tracker [`Component`].
@@ -26,9 +26,9 @@ pub struct VrController {}
 /// # use qinetic_vr::prelude::*;
 /// #
 /// App::builder()
-///     .with_component(VrTracker::default())
+///     .with_component::<VrTracker>()
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Component, Reflect)]
 pub struct VrTracker {}
@@ -39,8 +39,8 @@ pub struct VrPlugin {}
 impl Plugin for VrPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_component(VrController::default())
-            .with_component(VrTracker::default())
+            .with_component::<VrController>()
+            .with_component::<VrTracker>()
             .with_stage_group(VrStageGroup::default())
             .with_system(VrStage::default(), VrSystem::default());
     }
@@ -0,0 +1,210 @@
+//! Cursor functionality.
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+
+use crate::error::WindowError;
+#[allow(unused_imports)]
+use crate::window::*;
+
+/// Cursor of the [`Window`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_window::prelude::*;
+/// #
+/// App::builder()
+///     .with_resource(
+///         WindowResource::builder()
+///             .with_cursor(
+///                 Cursor::builder()
+///                     .with_visible(false)
+///                     .with_grab_mode(CursorGrabMode::Locked)
+///                     .build()
+///                     .unwrap(),
+///             )
+///             .build()
+///             .unwrap(),
+///     )
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Getters, Builder, Reflect)]
+#[getset(get = "pub")]
+#[builder(
+    crate = "crate::cursor",
+    setter(prefix = "with"),
+    default,
+    derive(Debug, PartialEq, Eq)
+)]
+pub struct Cursor {
+    /// The visibility of the cursor over the [`Window`]'s client area.
+    #[default = true]
+    visible: bool,
+
+    /// The grab mode of the cursor.
+    grab_mode: CursorGrabMode,
+
+    /// The icon of the cursor.
+    icon: CursorIcon,
+}
+
+impl Cursor {
+    /// Returns a [`CursorBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_window::prelude::*;
+    /// #
+    /// let cursor_builder = Cursor::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> CursorBuilder { CursorBuilder::default() }
+
+    /// Shows or hides the cursor.
+    #[inline]
+    pub fn set_visible(&mut self, visible: bool) { self.visible = visible; }
+
+    /// Sets the `grab_mode` of the cursor.
+    #[inline]
+    pub fn set_grab_mode(&mut self, grab_mode: CursorGrabMode) { self.grab_mode = grab_mode; }
+
+    /// Sets the `icon` of the cursor.
+    #[inline]
+    pub fn set_icon(&mut self, icon: CursorIcon) { self.icon = icon; }
+}
+
+/// Defines how the cursor is grabbed by the [`Window`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum CursorGrabMode {
+    /// The cursor moves freely.
+    #[default]
+    None,
+
+    /// The cursor is confined to the [`Window`]'s client area.
+    Confined,
+
+    /// The cursor is locked in place, and only relative motion is reported.
+    ///
+    /// Read `RawMouseMotionEvent` of `qinetic_input` for the motion, like first-person camera controls.
+    Locked,
+}
+
+/// Icon of the [`Cursor`].
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum CursorIcon {
+    /// The platform-dependent default cursor.
+    #[default]
+    Default,
+
+    /// The simple crosshair.
+    Crosshair,
+
+    /// The hand, that indicates a link.
+    Hand,
+
+    /// The arrow.
+    Arrow,
+
+    /// The four-way arrow, that indicates something to be moved.
+    Move,
+
+    /// The text caret.
+    Text,
+
+    /// The busy cursor.
+    Wait,
+
+    /// The arrow with the question mark.
+    Help,
+
+    /// The busy cursor, while the app is still interactive.
+    Progress,
+
+    /// The action isn't allowed.
+    NotAllowed,
+
+    /// Something can be grabbed.
+    Grab,
+
+    /// Something is grabbed.
+    Grabbing,
+
+    /// The horizontal resize arrow.
+    EwResize,
+
+    /// The vertical resize arrow.
+    NsResize,
+
+    /// The diagonal resize arrow from the top-right corner.
+    NeswResize,
+
+    /// The diagonal resize arrow from the top-left corner.
+    NwseResize,
+
+    /// The custom icon from the image.
+    ///
+    /// If the backend doesn't support it, the default icon is shown,
+    /// and [`WindowEvent::Failed`](crate::events::WindowEvent::Failed) with [`WindowError::UnsupportedCursor`] is sent.
+    Custom(CustomCursor),
+}
+
+/// Custom [`CursorIcon`] from the image in RGBA8 pixels.
+///
+/// # Examples
+/// ```
+/// # use qinetic_window::prelude::*;
+/// #
+/// let custom_cursor = CustomCursor::from_rgba(vec![255; 16 * 16 * 4], 16, 16, (8, 8)).unwrap();
+/// assert_eq!(*custom_cursor.width(), 16);
+///
+/// assert!(CustomCursor::from_rgba(vec![255; 3], 16, 16, (8, 8)).is_err());
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Getters, Reflect)]
+#[getset(get = "pub")]
+pub struct CustomCursor {
+    /// The pixels in RGBA8 format, row by row from the top-left corner.
+    rgba: Vec<u8>,
+
+    /// The width of the image in pixels.
+    width: u32,
+
+    /// The height of the image in pixels.
+    height: u32,
+
+    /// The position of the click point in the image.
+    hotspot: (u32, u32),
+}
+
+impl CustomCursor {
+    /// Returns a [`CustomCursor`] from `rgba` pixels of the `width` x `height` image.
+    ///
+    /// The `rgba` length must be `width * height * 4`, and the `hotspot` must be inside the image.
+    pub fn from_rgba(
+        rgba: Vec<u8>,
+        width: u32,
+        height: u32,
+        hotspot: (u32, u32),
+    ) -> Result<Self, WindowError> {
+        if width == 0 || height == 0 || rgba.len() != width as usize * height as usize * 4 {
+            return Err(WindowError::InvalidCursor {
+                reason: format!(
+                    "{} bytes don't match {width}x{height} RGBA8 image",
+                    rgba.len()
+                ),
+            });
+        }
+        if hotspot.0 >= width || hotspot.1 >= height {
+            return Err(WindowError::InvalidCursor {
+                reason: format!("hotspot {hotspot:?} is outside {width}x{height} image"),
+            });
+        }
+        Ok(Self {
+            rgba,
+            width,
+            height,
+            hotspot,
+        })
+    }
+}
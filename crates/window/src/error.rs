@@ -0,0 +1,27 @@
+//! Error functionality.
+
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Error of the window functionality.
+#[derive(Clone, Debug, PartialEq, Eq, Error, Serialize, Deserialize)]
+pub enum WindowError {
+    /// The [`CustomCursor`](crate::cursor::CustomCursor) image can't be used.
+    #[error("Failed to create custom cursor, it's invalid: {reason}.")]
+    InvalidCursor {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The backend can't create the [`Window`](crate::window::Window).
+    #[error("Failed to create window, it's rejected by the backend: {reason}.")]
+    CreationFailed {
+        /// The reason of the failure.
+        reason: String,
+    },
+
+    /// The backend can't show the [`CustomCursor`](crate::cursor::CustomCursor),
+    /// so the default icon is shown.
+    #[error("Failed to set custom cursor, it's not supported by the backend.")]
+    UnsupportedCursor,
+}
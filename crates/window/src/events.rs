@@ -1,10 +1,14 @@
 //! Window [`Event`]s functionality.
 
+use std::path::PathBuf;
+
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
 
+use crate::error::WindowError;
 #[allow(unused_imports)]
-use crate::window::*;
+use crate::window::{Window, WindowId};
 
 /// Window [`Event`].
 ///
@@ -18,23 +22,156 @@ use crate::window::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Event)]
+#[derive(SmartDefault, Clone, Debug, PartialEq, Event, Serialize, Deserialize)]
 pub enum WindowEvent {
     /// The [`Event`] that sent whenever a [`Window`] is created.
     #[default]
-    Created,
+    Created {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
 
     /// The [`Event`] that sent whenever a [`Window`] is destroyed.
-    Destroyed,
+    Destroyed {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
+
+    /// The [`Event`] that sent whenever the close button of a [`Window`] is pressed.
+    ///
+    /// The [`Window`] is closed in the next update,
+    /// unless the request is cancelled by [`Windows::cancel_close`](crate::resources::Windows::cancel_close),
+    /// like to prompt about unsaved changes.
+    CloseRequested {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
 
     /// The [`Event`] that sent whenever a [`Window`] is resized.
-    Resized,
+    Resized {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
 
-    /// The [`Event`] that sent whenever a [`Window`] is focused.
-    Focused,
+        /// The new logical width of the [`Window`]'s client area.
+        width: u32,
+
+        /// The new logical height of the [`Window`]'s client area.
+        height: u32,
+    },
 
     /// The [`Event`] that sent whenever a [`Window`] is moved.
-    Moved,
+    Moved {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The new physical `x` position of the [`Window`].
+        x: i32,
+
+        /// The new physical `y` position of the [`Window`].
+        y: i32,
+    },
+
+    /// The [`Event`] that sent whenever a [`Window`] gains or loses focus.
+    Focused {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The [`Window`] is focused.
+        focused: bool,
+    },
+
+    /// The [`Event`] that sent whenever the scale factor of a [`Window`] changes,
+    /// like when it's moved to another monitor.
+    ScaleFactorChanged {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The new ratio of physical pixels to logical ones.
+        scale_factor: f64,
+    },
+
+    /// The [`Event`] that sent whenever a [`Window`] is completely hidden or shown again.
+    Occluded {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The [`Window`] is completely hidden.
+        occluded: bool,
+    },
+
+    /// The [`Event`] that sent whenever the system theme of a [`Window`] changes.
+    ThemeChanged {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The new [`WindowTheme`].
+        theme: WindowTheme,
+    },
+
+    /// The [`Event`] that sent whenever a file is dropped into a [`Window`].
+    FileDropped {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The [`Event`] that sent whenever a file is dragged over a [`Window`].
+    FileHovered {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The path of the file.
+        path: PathBuf,
+    },
+
+    /// The [`Event`] that sent whenever a file is dragged out of a [`Window`] without dropping.
+    FileHoverCancelled {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
+
+    /// The [`Event`] that sent whenever the backend fails to create or update a [`Window`].
+    Failed {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+
+        /// The [`WindowError`] of the failure.
+        error: WindowError,
+    },
+}
+
+impl WindowEvent {
+    /// Returns a [`WindowId`] of the originating [`Window`].
+    pub fn window(&self) -> WindowId {
+        match *self {
+            | Self::Created { window }
+            | Self::Destroyed { window }
+            | Self::CloseRequested { window }
+            | Self::Resized { window, .. }
+            | Self::Moved { window, .. }
+            | Self::Focused { window, .. }
+            | Self::ScaleFactorChanged { window, .. }
+            | Self::Occluded { window, .. }
+            | Self::ThemeChanged { window, .. }
+            | Self::FileDropped { window, .. }
+            | Self::FileHovered { window, .. }
+            | Self::FileHoverCancelled { window }
+            | Self::Failed { window, .. } => window,
+        }
+    }
+}
+
+/// System theme of the [`Window`].
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum WindowTheme {
+    /// The light theme.
+    #[default]
+    Light,
+
+    /// The dark theme.
+    Dark,
 }
 
 /// Cursor [`Event`].
@@ -49,15 +186,33 @@ pub enum WindowEvent {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Event)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Event, Serialize, Deserialize)]
 pub enum CursorEvent {
     /// The [`Event`] that sent whenever a cursor is moved in [`Window`].
-    Moved,
+    Moved {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
 
     /// The [`Event`] that sent whenever a cursor is entered to [`Window`].
     #[default]
-    Entered,
+    Entered {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
 
     /// The [`Event`] that sent whenever a cursor is leaves from [`Window`].
-    Left,
+    Left {
+        /// The [`WindowId`] of the [`Window`].
+        window: WindowId,
+    },
+}
+
+impl CursorEvent {
+    /// Returns a [`WindowId`] of the [`Window`] under the cursor.
+    pub fn window(&self) -> WindowId {
+        match *self {
+            | Self::Moved { window } | Self::Entered { window } | Self::Left { window } => window,
+        }
+    }
 }
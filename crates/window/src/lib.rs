@@ -23,8 +23,11 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+pub mod cursor;
+pub mod error;
 pub mod event_loop;
 pub mod events;
+pub mod monitor;
 pub mod plugins;
 pub mod resources;
 pub mod stages;
@@ -36,8 +39,11 @@ pub mod prelude {
 
     #[doc(hidden)]
     pub use crate::{
+        cursor::*,
+        error::WindowError,
         event_loop::*,
-        events::{CursorEvent, WindowEvent},
+        events::{CursorEvent, WindowEvent, WindowTheme},
+        monitor::*,
         plugins::WindowPlugin,
         resources::{
             WindowMode,
@@ -47,9 +53,10 @@ pub mod prelude {
             WindowResourceBuilder,
             WindowResourceBuilderError,
             WindowSizeConstraints,
+            Windows,
         },
         stages::{WindowStage, WindowStageGroup},
-        systems::WindowSystem,
+        systems::{WindowSystem, WindowUpdate},
         window::*,
     };
 }
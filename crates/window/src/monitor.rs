@@ -0,0 +1,238 @@
+//! Monitor functionality.
+
+use qinetic_ecs::prelude::*;
+use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[allow(unused_imports)]
+use crate::{resources::WindowMonitor, window::Window};
+
+/// Video mode of the [`Monitor`], that is used by the exclusive fullscreen.
+///
+/// # Examples
+/// ```
+/// # use qinetic_window::prelude::*;
+/// #
+/// let video_mode = VideoMode::new(1920, 1080, 60_000, 32);
+/// assert_eq!(video_mode.refresh_rate(), 60.0);
+/// ```
+#[derive(
+    SmartDefault,
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    CopyGetters,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
+#[getset(get_copy = "pub")]
+pub struct VideoMode {
+    /// The physical width of the resolution.
+    width: u32,
+
+    /// The physical height of the resolution.
+    height: u32,
+
+    /// The refresh rate in millihertz.
+    refresh_rate_millihertz: u32,
+
+    /// The bit depth of the color.
+    bit_depth: u16,
+}
+
+impl VideoMode {
+    /// Returns a [`VideoMode`] of `width` x `height` resolution with `refresh_rate_millihertz` and `bit_depth`.
+    #[inline(always)]
+    pub const fn new(
+        width: u32,
+        height: u32,
+        refresh_rate_millihertz: u32,
+        bit_depth: u16,
+    ) -> Self {
+        Self {
+            width,
+            height,
+            refresh_rate_millihertz,
+            bit_depth,
+        }
+    }
+
+    /// Returns a refresh rate in hertz.
+    #[inline]
+    pub fn refresh_rate(&self) -> f32 { self.refresh_rate_millihertz as f32 / 1000.0 }
+
+    /// Returns a resolution as `width` and `height`.
+    #[inline]
+    pub fn resolution(&self) -> (u32, u32) { (self.width, self.height) }
+}
+
+/// Defines which [`VideoMode`] the exclusive fullscreen [`Window`] uses.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
+pub enum VideoModeSelection {
+    /// The mode with the highest resolution, refresh rate and bit depth of the monitor.
+    #[default]
+    Best,
+
+    /// The specified mode, or the closest one, that the monitor supports.
+    Specific(VideoMode),
+}
+
+/// Display, that is connected to the system.
+///
+/// # Examples
+/// ```
+/// # use qinetic_window::prelude::*;
+/// #
+/// let monitor = Monitor::builder()
+///     .with_name("DP-1")
+///     .with_physical_size((2560, 1440))
+///     .with_video_modes(vec![
+///         VideoMode::new(2560, 1440, 144_000, 32),
+///         VideoMode::new(2560, 1440, 60_000, 32),
+///         VideoMode::new(1920, 1080, 60_000, 32),
+///     ])
+///     .build()
+///     .unwrap();
+///
+/// assert_eq!(monitor.resolutions(), vec![(2560, 1440), (1920, 1080)]);
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Getters, Builder)]
+#[getset(get = "pub")]
+#[builder(
+    crate = "crate::monitor",
+    setter(prefix = "with"),
+    default,
+    derive(Debug, PartialEq)
+)]
+pub struct Monitor {
+    /// The name of the monitor, if it's known.
+    #[builder(setter(into, strip_option))]
+    name: Option<String>,
+
+    /// The physical size of the monitor's desktop area.
+    physical_size: (u32, u32),
+
+    /// The physical position of the monitor's top-left corner on the desktop.
+    position: (i32, i32),
+
+    /// The ratio of physical pixels to logical ones.
+    #[default = 1.0]
+    scale_factor: f64,
+
+    /// The supported [`VideoMode`]s.
+    video_modes: Vec<VideoMode>,
+
+    /// The monitor is the primary one.
+    primary: bool,
+}
+
+impl Monitor {
+    /// Returns a [`MonitorBuilder`] with `default` configuration.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_window::prelude::*;
+    /// #
+    /// let monitor_builder = Monitor::builder();
+    /// ```
+    #[inline]
+    pub fn builder() -> MonitorBuilder { MonitorBuilder::default() }
+
+    /// Returns unique resolutions of supported [`VideoMode`]s from the highest one.
+    pub fn resolutions(&self) -> Vec<(u32, u32)> {
+        let mut resolutions = self
+            .video_modes
+            .iter()
+            .map(VideoMode::resolution)
+            .collect::<Vec<_>>();
+        resolutions.sort_by(|a, b| b.cmp(a));
+        resolutions.dedup();
+        resolutions
+    }
+
+    /// Returns a [`VideoMode`] by the `selection`, if the monitor has any.
+    pub fn video_mode(&self, selection: VideoModeSelection) -> Option<VideoMode> {
+        let key = |mode: &&VideoMode| {
+            (
+                mode.width as u64 * mode.height as u64,
+                mode.refresh_rate_millihertz,
+                mode.bit_depth,
+            )
+        };
+        match selection {
+            | VideoModeSelection::Best => self.video_modes.iter().max_by_key(key).copied(),
+            | VideoModeSelection::Specific(video_mode) => {
+                self.video_modes
+                    .iter()
+                    .min_by_key(|mode| {
+                        (
+                            mode.width.abs_diff(video_mode.width) as u64
+                                + mode.height.abs_diff(video_mode.height) as u64,
+                            mode.refresh_rate_millihertz
+                                .abs_diff(video_mode.refresh_rate_millihertz),
+                            mode.bit_depth.abs_diff(video_mode.bit_depth),
+                        )
+                    })
+                    .copied()
+            },
+        }
+    }
+}
+
+/// Monitors [`Resource`], that lists connected displays.
+///
+/// The backend updates it, when monitors are changed.
+/// Indices of the monitors match the [`WindowMonitor::Index`].
+///
+/// # Examples
+/// ```
+/// # use qinetic_window::prelude::*;
+/// #
+/// let monitors = Monitors::new(vec![
+///     Monitor::builder().with_name("HDMI-1").build().unwrap(),
+///     Monitor::builder()
+///         .with_name("DP-1")
+///         .with_primary(true)
+///         .build()
+///         .unwrap(),
+/// ]);
+///
+/// assert_eq!(monitors.len(), 2);
+/// assert_eq!(monitors.primary().unwrap().name().as_deref(), Some("DP-1"));
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Resource)]
+pub struct Monitors {
+    monitors: Vec<Monitor>,
+}
+
+impl Monitors {
+    /// Returns a [`Monitors`] of `monitors`.
+    #[inline]
+    pub fn new(monitors: Vec<Monitor>) -> Self { Self { monitors } }
+
+    /// Returns a [`Monitor`] by `index`, if it's present.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&Monitor> { self.monitors.get(index) }
+
+    /// Returns the primary [`Monitor`], if it's known.
+    #[inline]
+    pub fn primary(&self) -> Option<&Monitor> {
+        self.monitors.iter().find(|monitor| monitor.primary)
+    }
+
+    /// Returns an iterator over [`Monitor`]s.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = &Monitor> + '_ { self.monitors.iter() }
+
+    /// Returns a number of [`Monitor`]s.
+    #[inline]
+    pub fn len(&self) -> usize { self.monitors.len() }
+
+    /// Returns `true`, if no [`Monitor`] is known.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.monitors.is_empty() }
+}
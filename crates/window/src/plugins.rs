@@ -7,18 +7,25 @@ use qinetic_utils::prelude::*;
 
 use crate::{
     events::*,
-    resources::WindowResource,
+    monitor::Monitors,
+    resources::{WindowResource, Windows},
     stages::{WindowStage, WindowStageGroup},
     systems::WindowSystem,
+    window::Window,
 };
 
 /// Window [`Plugin`].
 ///
+/// [`Component`]s:
+/// * [`Window`]
+///
 /// [`Stage`]s:
 /// * [`WindowStageGroup`]
 ///
 /// [`Resource`]s:
 /// * [`WindowResource`]
+/// * [`Windows`]
+/// * [`Monitors`]
 ///
 /// [`Event`]s:
 /// * [`WindowEvent`]
@@ -43,15 +50,12 @@ pub struct WindowPlugin {}
 impl Plugin for WindowPlugin {
     fn build(&mut self, app_builder: &mut AppBuilder) {
         app_builder
-            .with_event(WindowEvent::Created)
-            .with_event(WindowEvent::Destroyed)
-            .with_event(WindowEvent::Resized)
-            .with_event(WindowEvent::Focused)
-            .with_event(WindowEvent::Moved)
-            .with_event(CursorEvent::Moved)
-            .with_event(CursorEvent::Entered)
-            .with_event(CursorEvent::Left)
-            .with_resource(WindowResource::default())
+            .with_event(WindowEvent::default())
+            .with_event(CursorEvent::default())
+            .with_component::<Window>()
+            .with_reflect_resource(WindowResource::default())
+            .with_resource(Windows::default())
+            .with_resource(Monitors::default())
             .with_stage_group(WindowStageGroup::default())
             .with_system(WindowStage::default(), WindowSystem::default());
     }
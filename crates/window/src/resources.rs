@@ -1,12 +1,20 @@
 //! Window [`Resource`]s functionality.
 
+use std::collections::BTreeMap;
+
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
-#[allow(unused_imports)]
-use crate::window::*;
+use crate::{
+    cursor::{Cursor, CursorGrabMode, CursorIcon},
+    events::WindowEvent,
+    monitor::VideoModeSelection,
+    window::{Window, WindowId},
+};
 
-/// Window [`Resource`].
+/// Window [`Resource`], that describes the primary [`Window`].
+///
+/// The primary [`Window`] is created from it by [`Windows`], and follows it's changes.
 ///
 /// # Examples
 /// ```
@@ -18,7 +26,7 @@ use crate::window::*;
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource)]
+#[derive(SmartDefault, Clone, Debug, Getters, Builder, Resource, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -58,6 +66,17 @@ pub struct WindowResource {
 
     /// The mode of the [`Window`].
     mode: WindowMode,
+
+    /// The input method editor of the [`Window`], that composes text, like CJK input.
+    ///
+    /// Text fields enable it, while they're focused.
+    ime_enabled: bool,
+
+    /// The position of the IME candidate box, relative to the [`Window`]'s client area.
+    ime_position: (i32, i32),
+
+    /// The [`Cursor`] over the [`Window`]'s client area.
+    cursor: Cursor,
 }
 
 impl WindowResource {
@@ -71,6 +90,280 @@ impl WindowResource {
     /// ```
     #[inline]
     pub fn builder() -> WindowResourceBuilder { WindowResourceBuilder::default() }
+
+    /// Enables or disables the input method editor of the [`Window`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_window::prelude::*;
+    /// #
+    /// let mut window_resource = WindowResource::default();
+    /// window_resource.set_ime_enabled(true);
+    /// window_resource.set_ime_position((120, 48));
+    ///
+    /// assert!(*window_resource.ime_enabled());
+    /// ```
+    #[inline]
+    pub fn set_ime_enabled(&mut self, ime_enabled: bool) { self.ime_enabled = ime_enabled; }
+
+    /// Sets the `position` of the IME candidate box.
+    #[inline]
+    pub fn set_ime_position(&mut self, position: (i32, i32)) { self.ime_position = position; }
+
+    /// Shows or hides the [`Cursor`].
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_window::prelude::*;
+    /// #
+    /// let mut window_resource = WindowResource::default();
+    /// window_resource.set_cursor_visible(false);
+    /// window_resource.set_cursor_grab_mode(CursorGrabMode::Locked);
+    ///
+    /// assert!(!*window_resource.cursor().visible());
+    /// assert_eq!(
+    ///     *window_resource.cursor().grab_mode(),
+    ///     CursorGrabMode::Locked
+    /// );
+    /// ```
+    #[inline]
+    pub fn set_cursor_visible(&mut self, visible: bool) { self.cursor.set_visible(visible); }
+
+    /// Sets the `grab_mode` of the [`Cursor`].
+    #[inline]
+    pub fn set_cursor_grab_mode(&mut self, grab_mode: CursorGrabMode) {
+        self.cursor.set_grab_mode(grab_mode);
+    }
+
+    /// Sets the `icon` of the [`Cursor`].
+    #[inline]
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) { self.cursor.set_icon(icon); }
+}
+
+impl From<&WindowResource> for Window {
+    fn from(resource: &WindowResource) -> Self {
+        Window::builder()
+            .with_width(resource.width)
+            .with_height(resource.height)
+            .with_size_constraints(resource.size_constraints)
+            .with_position(resource.position)
+            .with_monitor(resource.monitor)
+            .with_title(resource.title.clone())
+            .with_resizable(resource.resizable)
+            .with_decorations(resource.decorations)
+            .with_mode(resource.mode)
+            .with_ime_enabled(resource.ime_enabled)
+            .with_ime_position(resource.ime_position)
+            .with_cursor(resource.cursor.clone())
+            .build()
+            .unwrap_or_default()
+    }
+}
+
+/// Windows [`Resource`], that creates and closes [`Window`]s at runtime.
+///
+/// Each [`Window`] is an `Entity` with the [`Window`] component,
+/// that is spawned and removed by the [`WindowSystem`](crate::systems::WindowSystem).
+///
+/// # Examples
+/// ```
+/// # use qinetic_ecs::prelude::*;
+/// # use qinetic_window::prelude::*;
+/// #
+/// let mut world = World::new();
+/// world.add_resource(WindowResource::default());
+/// world.add_resource(Windows::default());
+///
+/// let inspector = world
+///     .get_resource_mut::<Windows>()
+///     .unwrap()
+///     .create(Window::builder().with_title("Inspector").build().unwrap());
+/// WindowSystem::default().run_world(&mut world);
+///
+/// let entity_id = world
+///     .get_resource::<Windows>()
+///     .unwrap()
+///     .entity(inspector)
+///     .unwrap();
+/// assert_eq!(
+///     world.get_component::<Window>(entity_id).unwrap().title(),
+///     "Inspector"
+/// );
+/// assert!(world
+///     .get_resource::<Windows>()
+///     .unwrap()
+///     .contains(WindowId::PRIMARY));
+///
+/// world
+///     .get_resource_mut::<Windows>()
+///     .unwrap()
+///     .close(inspector);
+/// WindowSystem::default().run_world(&mut world);
+///
+/// assert!(!world.has_entity(entity_id));
+/// assert_eq!(
+///     world
+///         .get_events::<WindowEvent>()
+///         .unwrap()
+///         .iter()
+///         .collect::<Vec<_>>(),
+///     vec![
+///         &WindowEvent::Created {
+///             window: WindowId::PRIMARY
+///         },
+///         &WindowEvent::Created { window: inspector },
+///         &WindowEvent::Destroyed { window: inspector },
+///     ]
+/// );
+/// ```
+#[derive(SmartDefault, Clone, Debug, Resource)]
+pub struct Windows {
+    entities: BTreeMap<WindowId, EntityId>,
+    pending: Vec<Window>,
+    closing: Vec<WindowId>,
+    close_requests: Vec<WindowId>,
+    confirmed_close_requests: Vec<WindowId>,
+
+    #[default(1)]
+    next: u32,
+
+    primary: Option<Window>,
+    primary_closed: bool,
+}
+
+impl Windows {
+    /// Creates a [`Window`] in the next update and returns it's [`WindowId`].
+    pub fn create(&mut self, mut window: Window) -> WindowId {
+        let id = WindowId::new(self.next);
+        self.next += 1;
+        window.set_id(id);
+        self.pending.push(window);
+        id
+    }
+
+    /// Closes a [`Window`] by `id` in the next update, if it's present.
+    #[inline]
+    pub fn close(&mut self, id: WindowId) { self.closing.push(id); }
+
+    /// Requests to close a [`Window`] by `id`, like when it's close button is pressed.
+    ///
+    /// The backend sends [`WindowEvent::CloseRequested`] with it,
+    /// and the [`Window`] is closed in the next update, unless the request is cancelled.
+    ///
+    /// # Examples
+    /// ```
+    /// # use qinetic_ecs::prelude::*;
+    /// # use qinetic_window::prelude::*;
+    /// #
+    /// let mut world = World::new();
+    /// world.add_resource(WindowResource::default());
+    /// WindowSystem::default().run_world(&mut world);
+    ///
+    /// world
+    ///     .get_resource_mut::<Windows>()
+    ///     .unwrap()
+    ///     .request_close(WindowId::PRIMARY);
+    /// WindowSystem::default().run_world(&mut world);
+    /// world
+    ///     .get_resource_mut::<Windows>()
+    ///     .unwrap()
+    ///     .cancel_close(WindowId::PRIMARY);
+    /// WindowSystem::default().run_world(&mut world);
+    ///
+    /// assert!(world
+    ///     .get_resource::<Windows>()
+    ///     .unwrap()
+    ///     .contains(WindowId::PRIMARY));
+    /// ```
+    pub fn request_close(&mut self, id: WindowId) {
+        if !self.close_requests.contains(&id) {
+            self.close_requests.push(id);
+        }
+    }
+
+    /// Cancels the close request of a [`Window`] by `id`, if it's present.
+    #[inline]
+    pub fn cancel_close(&mut self, id: WindowId) {
+        self.close_requests.retain(|request| *request != id);
+        self.confirmed_close_requests
+            .retain(|request| *request != id);
+    }
+
+    /// Returns `true`, if the close of a [`Window`] by `id` was requested, and isn't cancelled yet.
+    #[inline]
+    pub fn is_close_requested(&self, id: WindowId) -> bool {
+        self.close_requests.contains(&id) || self.confirmed_close_requests.contains(&id)
+    }
+
+    /// Returns an [`EntityId`] of the [`Window`] by `id`, if it's present.
+    #[inline]
+    pub fn entity(&self, id: WindowId) -> Option<EntityId> { self.entities.get(&id).copied() }
+
+    /// Returns `true`, if the [`Window`] by `id` present.
+    #[inline]
+    pub fn contains(&self, id: WindowId) -> bool { self.entities.contains_key(&id) }
+
+    /// Returns an iterator over [`WindowId`]s and [`EntityId`]s of present [`Window`]s.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (WindowId, EntityId)> + '_ {
+        self.entities
+            .iter()
+            .map(|(id, entity_id)| (*id, *entity_id))
+    }
+
+    /// Returns a number of present [`Window`]s.
+    #[inline]
+    pub fn len(&self) -> usize { self.entities.len() }
+
+    /// Returns `true`, if no [`Window`] is present.
+    #[inline]
+    pub fn is_empty(&self) -> bool { self.entities.is_empty() }
+
+    /// Spawns pending [`Window`]s, removes closed ones, and syncs the primary one with the [`WindowResource`].
+    ///
+    /// Close requests are applied an update later, so [`System`]s have an update to cancel them.
+    pub(crate) fn update(&mut self, world: &mut World) {
+        let confirmed = std::mem::take(&mut self.close_requests);
+        self.closing.append(&mut std::mem::replace(
+            &mut self.confirmed_close_requests,
+            confirmed,
+        ));
+
+        let primary = world.get_resource::<WindowResource>().map(|resource| {
+            let mut window = Window::from(&*resource);
+            window.set_id(WindowId::PRIMARY);
+            window
+        });
+        if let Some(window) = primary.filter(|window| self.primary.as_ref() != Some(window)) {
+            self.primary = Some(window.clone());
+            match self.entity(WindowId::PRIMARY) {
+                | Some(entity_id) => {
+                    // The entity is removed not by `Windows`, so the window is closed.
+                    if world.try_add_component(entity_id, window).is_err() {
+                        self.closing.push(WindowId::PRIMARY);
+                    }
+                },
+                | None if !self.primary_closed => self.pending.insert(0, window),
+                | None => {},
+            }
+        }
+
+        for window in std::mem::take(&mut self.pending) {
+            let id = *window.id();
+            self.entities.insert(id, world.spawn(window));
+            world.add_event(WindowEvent::Created { window: id });
+        }
+
+        for id in std::mem::take(&mut self.closing) {
+            if let Some(entity_id) = self.entities.remove(&id) {
+                world.remove_entity(entity_id);
+                world.add_event(WindowEvent::Destroyed { window: id });
+            }
+            if id.is_primary() {
+                self.primary_closed = true;
+            }
+        }
+    }
 }
 
 /// Defines on which `mode` show [`Window`] on creation.
@@ -90,7 +383,7 @@ impl WindowResource {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum WindowMode {
     /// Show [`Window`] on auto mode.
     #[default]
@@ -102,8 +395,13 @@ pub enum WindowMode {
     /// Show [`Window`] on maximize mode.
     Maximized,
 
-    /// Show [`Window`] on fullscreen mode.
+    /// Show [`Window`] on borderless fullscreen mode, that keeps the monitor's video mode.
     Fullscreen,
+
+    /// Show [`Window`] on exclusive fullscreen mode with the selected [`VideoMode`](crate::monitor::VideoMode).
+    ///
+    /// Supported modes of the monitor are listed by [`Monitors`](crate::monitor::Monitors).
+    ExclusiveFullscreen(VideoModeSelection),
 }
 
 /// Defines on which `position` show [`Window`] on creation.
@@ -123,7 +421,7 @@ pub enum WindowMode {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum WindowPosition {
     /// Show [`Window`] on auto position.
     #[default]
@@ -138,6 +436,8 @@ pub enum WindowPosition {
 
 /// Defines on which `monitor` show [`Window`] on creation.
 ///
+/// The [`WindowMonitor::Index`] is the index in [`Monitors`](crate::monitor::Monitors).
+///
 /// # Examples
 /// ```
 /// # use qinetic_app::prelude::*;
@@ -153,7 +453,7 @@ pub enum WindowPosition {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Reflect)]
 pub enum WindowMonitor {
     /// Show [`Window`] on current monitor.
     #[default]
@@ -183,7 +483,7 @@ pub enum WindowMonitor {
 ///     .build()
 ///     .unwrap();
 /// ```
-#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Getters, Builder)]
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq, Getters, Builder, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::resources",
@@ -3,7 +3,12 @@
 use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
 
-/// Window [`System`].
+use crate::{
+    resources::{WindowResource, Windows},
+    window::Window,
+};
+
+/// Window [`System`], that creates and closes [`Window`](crate::window::Window)s by the [`Windows`] resource.
 ///
 /// # Examples
 /// ```
@@ -20,8 +25,42 @@ use qinetic_utils::prelude::*;
 pub struct WindowSystem {}
 
 impl System for WindowSystem {
-    type Data = ();
+    type Data = WindowUpdate;
+
+    fn run(&mut self, data: Self::Data) { data.update(); }
+}
+
+/// [`SystemData`], that updates [`Window`](crate::window::Window) entities by the [`Windows`] resource.
+///
+/// If the resource isn't present, it's added.
+#[derive(Clone, Debug)]
+pub struct WindowUpdate {
+    commands: Commands,
+}
+
+impl WindowUpdate {
+    /// Spawns pending [`Window`](crate::window::Window)s and removes closed ones.
+    pub fn update(self) {
+        self.commands.add(|world| {
+            let mut windows = world.try_remove_resource::<Windows>().unwrap_or_default();
+            windows.update(world);
+            world.add_resource(windows);
+        });
+    }
+}
+
+impl SystemData for WindowUpdate {
+    fn access(access: &mut SystemAccess) {
+        access
+            .read_resource::<WindowResource>()
+            .write_resource::<Windows>()
+            .write_component::<Window>();
+    }
 
-    #[allow(unused_variables)]
-    fn run(&mut self, data: Self::Data) {}
+    #[inline]
+    fn fetch(world: &mut World) -> Self {
+        Self {
+            commands: world.commands(),
+        }
+    }
 }
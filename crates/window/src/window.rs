@@ -1,9 +1,78 @@
 //! Window creating functionality.
 
+use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use serde::{Deserialize, Serialize};
 
-/// Cross-platform window representation.
-#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Getters, Builder)]
+use crate::{
+    cursor::{Cursor, CursorGrabMode, CursorIcon},
+    resources::{WindowMode, WindowMonitor, WindowPosition, WindowSizeConstraints},
+};
+
+/// Identificator of the [`Window`], that is stable for the whole window's lifetime.
+///
+/// The [`WindowId::PRIMARY`] belongs to the window of the
+/// [`WindowResource`](crate::resources::WindowResource).
+#[derive(
+    SmartDefault,
+    Clone,
+    Copy,
+    Debug,
+    Hash,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Reflect,
+    Serialize,
+    Deserialize,
+)]
+pub struct WindowId(u32);
+
+impl WindowId {
+    /// The [`WindowId`] of the primary [`Window`].
+    pub const PRIMARY: Self = Self(0);
+
+    /// Returns a [`WindowId`] of `id`.
+    #[inline(always)]
+    pub const fn new(id: u32) -> Self { Self(id) }
+
+    /// Returns a raw `id`.
+    #[inline(always)]
+    pub const fn id(&self) -> u32 { self.0 }
+
+    /// Returns `true`, if it's the [`WindowId::PRIMARY`].
+    #[inline(always)]
+    pub const fn is_primary(&self) -> bool { self.0 == Self::PRIMARY.0 }
+}
+
+/// Cross-platform window [`Component`].
+///
+/// Windows are created and closed by the [`Windows`](crate::resources::Windows) resource,
+/// that spawns an `Entity` with the [`Window`] per window.
+///
+/// # Examples
+/// ```
+/// # use qinetic_app::prelude::*;
+/// # use qinetic_window::prelude::*;
+/// #
+/// let window = Window::builder()
+///     .with_title("Inspector")
+///     .with_width(400)
+///     .with_height(600)
+///     .build()
+///     .unwrap();
+///
+/// let mut windows = Windows::default();
+/// windows.create(window);
+///
+/// App::builder()
+///     .with_component::<Window>()
+///     .with_resource(windows)
+///     .build()
+///     .unwrap();
+/// ```
+#[derive(SmartDefault, Clone, Debug, PartialEq, Eq, Getters, Builder, Component, Reflect)]
 #[getset(get = "pub")]
 #[builder(
     crate = "crate::window",
@@ -11,7 +80,52 @@ use qinetic_utils::prelude::*;
     default,
     derive(Debug, PartialEq, Eq)
 )]
-pub struct Window {}
+pub struct Window {
+    /// The [`WindowId`] of the [`Window`], that is assigned on creation.
+    #[builder(setter(skip))]
+    id: WindowId,
+
+    /// The logical width of the [`Window`]'s client area.
+    #[default = 1280]
+    width: u32,
+
+    /// The logical height of the [`Window`]'s client area.
+    #[default = 720]
+    height: u32,
+
+    /// The size constraints of the [`Window`].
+    size_constraints: WindowSizeConstraints,
+
+    /// The position of the [`Window`].
+    position: WindowPosition,
+
+    /// The monitor to place the [`Window`].
+    monitor: WindowMonitor,
+
+    /// The title of the [`Window`].
+    #[builder(setter(into))]
+    #[default = "Qinetic App"]
+    title: String,
+
+    /// The resizable possibility of the [`Window`].
+    resizable: bool,
+
+    /// The decorations of the [`Window`]'s clent area.
+    #[default = true]
+    decorations: bool,
+
+    /// The mode of the [`Window`].
+    mode: WindowMode,
+
+    /// The input method editor of the [`Window`], that composes text, like CJK input.
+    ime_enabled: bool,
+
+    /// The position of the IME candidate box, relative to the [`Window`]'s client area.
+    ime_position: (i32, i32),
+
+    /// The [`Cursor`] over the [`Window`]'s client area.
+    cursor: Cursor,
+}
 
 impl Window {
     /// Returns a [`WindowBuilder`] with `default` configuration.
@@ -23,4 +137,48 @@ impl Window {
     /// let window_builder = Window::builder();
     /// ```
     pub fn builder() -> WindowBuilder { WindowBuilder::default() }
+
+    #[inline]
+    pub(crate) fn set_id(&mut self, id: WindowId) { self.id = id; }
+
+    /// Sets the `title` of the [`Window`].
+    #[inline]
+    pub fn set_title(&mut self, title: impl Into<String>) { self.title = title.into(); }
+
+    /// Sets the logical `width` and `height` of the [`Window`]'s client area.
+    #[inline]
+    pub fn set_size(&mut self, width: u32, height: u32) {
+        self.width = width;
+        self.height = height;
+    }
+
+    /// Sets the `position` of the [`Window`].
+    #[inline]
+    pub fn set_position(&mut self, position: WindowPosition) { self.position = position; }
+
+    /// Sets the `mode` of the [`Window`].
+    #[inline]
+    pub fn set_mode(&mut self, mode: WindowMode) { self.mode = mode; }
+
+    /// Enables or disables the input method editor of the [`Window`].
+    #[inline]
+    pub fn set_ime_enabled(&mut self, ime_enabled: bool) { self.ime_enabled = ime_enabled; }
+
+    /// Sets the `position` of the IME candidate box.
+    #[inline]
+    pub fn set_ime_position(&mut self, position: (i32, i32)) { self.ime_position = position; }
+
+    /// Shows or hides the [`Cursor`].
+    #[inline]
+    pub fn set_cursor_visible(&mut self, visible: bool) { self.cursor.set_visible(visible); }
+
+    /// Sets the `grab_mode` of the [`Cursor`].
+    #[inline]
+    pub fn set_cursor_grab_mode(&mut self, grab_mode: CursorGrabMode) {
+        self.cursor.set_grab_mode(grab_mode);
+    }
+
+    /// Sets the `icon` of the [`Cursor`].
+    #[inline]
+    pub fn set_cursor_icon(&mut self, icon: CursorIcon) { self.cursor.set_icon(icon); }
 }
@@ -0,0 +1,216 @@
+//! Winit types conversion functionality.
+
+use qinetic_window::prelude::*;
+use winit::{monitor as winit_monitor, window as winit_window};
+
+/// Returns a winit cursor icon of the `icon`.
+///
+/// Custom icons aren't supported by winit yet, so they fall back to the default one,
+/// and [`WinitWindows::sync`](crate::windows::WinitWindows::sync) reports [`WindowError::UnsupportedCursor`].
+pub(crate) fn convert_cursor_icon(icon: &CursorIcon) -> winit_window::CursorIcon {
+    match icon {
+        | CursorIcon::Default | CursorIcon::Custom(_) => winit_window::CursorIcon::Default,
+        | CursorIcon::Crosshair => winit_window::CursorIcon::Crosshair,
+        | CursorIcon::Hand => winit_window::CursorIcon::Hand,
+        | CursorIcon::Arrow => winit_window::CursorIcon::Arrow,
+        | CursorIcon::Move => winit_window::CursorIcon::Move,
+        | CursorIcon::Text => winit_window::CursorIcon::Text,
+        | CursorIcon::Wait => winit_window::CursorIcon::Wait,
+        | CursorIcon::Help => winit_window::CursorIcon::Help,
+        | CursorIcon::Progress => winit_window::CursorIcon::Progress,
+        | CursorIcon::NotAllowed => winit_window::CursorIcon::NotAllowed,
+        | CursorIcon::Grab => winit_window::CursorIcon::Grab,
+        | CursorIcon::Grabbing => winit_window::CursorIcon::Grabbing,
+        | CursorIcon::EwResize => winit_window::CursorIcon::EwResize,
+        | CursorIcon::NsResize => winit_window::CursorIcon::NsResize,
+        | CursorIcon::NeswResize => winit_window::CursorIcon::NeswResize,
+        | CursorIcon::NwseResize => winit_window::CursorIcon::NwseResize,
+    }
+}
+
+/// Returns a winit cursor grab mode of the `grab_mode`.
+pub(crate) fn convert_cursor_grab_mode(grab_mode: CursorGrabMode) -> winit_window::CursorGrabMode {
+    match grab_mode {
+        | CursorGrabMode::None => winit_window::CursorGrabMode::None,
+        | CursorGrabMode::Confined => winit_window::CursorGrabMode::Confined,
+        | CursorGrabMode::Locked => winit_window::CursorGrabMode::Locked,
+    }
+}
+
+/// Returns a [`WindowTheme`] of the winit `theme`.
+pub(crate) fn convert_theme(theme: winit_window::Theme) -> WindowTheme {
+    match theme {
+        | winit_window::Theme::Light => WindowTheme::Light,
+        | winit_window::Theme::Dark => WindowTheme::Dark,
+    }
+}
+
+/// Returns a [`VideoMode`] of the winit `video_mode`.
+pub(crate) fn convert_video_mode(video_mode: &winit_monitor::VideoMode) -> VideoMode {
+    let size = video_mode.size();
+    VideoMode::new(
+        size.width,
+        size.height,
+        video_mode.refresh_rate_millihertz(),
+        video_mode.bit_depth(),
+    )
+}
+
+/// Returns a [`Monitor`] of the winit `monitor`.
+pub(crate) fn convert_monitor(monitor: &winit_monitor::MonitorHandle, primary: bool) -> Monitor {
+    let (size, position) = (monitor.size(), monitor.position());
+    let mut builder = Monitor::builder();
+    builder
+        .with_physical_size((size.width, size.height))
+        .with_position((position.x, position.y))
+        .with_scale_factor(monitor.scale_factor())
+        .with_video_modes(
+            monitor
+                .video_modes()
+                .map(|video_mode| convert_video_mode(&video_mode))
+                .collect(),
+        )
+        .with_primary(primary);
+    if let Some(name) = monitor.name() {
+        builder.with_name(name);
+    }
+    builder.build().unwrap_or_default()
+}
+
+#[cfg(feature = "input")]
+pub(crate) use self::input::*;
+
+#[cfg(feature = "input")]
+mod input {
+    use qinetic_input::prelude::*;
+    use winit::event as winit_event;
+
+    /// The number of logical pixels in one scrolled line of the touchpad.
+    const PIXELS_PER_LINE: f64 = 20.0;
+
+    /// Returns a [`ButtonState`] of the winit `state`.
+    pub(crate) fn convert_button_state(state: winit_event::ElementState) -> ButtonState {
+        match state {
+            | winit_event::ElementState::Pressed => ButtonState::Pressed,
+            | winit_event::ElementState::Released => ButtonState::Released,
+        }
+    }
+
+    /// Returns a [`KeyboardEvent`] of the winit `input`.
+    pub(crate) fn convert_keyboard_input(input: winit_event::KeyboardInput) -> KeyboardEvent {
+        let mut builder = KeyboardEvent::builder();
+        builder
+            .with_scancode(ScanCode::new(input.scancode))
+            .with_state(convert_button_state(input.state));
+        if let Some(keycode) = input.virtual_keycode {
+            builder.with_keycode(convert_key_code(keycode));
+        }
+        builder
+            .build()
+            .expect("keyboard event has all required fields")
+    }
+
+    /// Returns a [`MouseButtonEvent`] of the winit `button` and `state`.
+    pub(crate) fn convert_mouse_input(
+        button: winit_event::MouseButton,
+        state: winit_event::ElementState,
+    ) -> MouseButtonEvent {
+        let button = match button {
+            | winit_event::MouseButton::Left => MouseButton::Left,
+            | winit_event::MouseButton::Right => MouseButton::Right,
+            | winit_event::MouseButton::Middle => MouseButton::Middle,
+            | winit_event::MouseButton::Other(button) => {
+                MouseButton::Other(u8::try_from(button).unwrap_or(u8::MAX))
+            },
+        };
+        MouseButtonEvent::builder()
+            .with_button(button)
+            .with_state(convert_button_state(state))
+            .build()
+            .expect("mouse button event has all required fields")
+    }
+
+    /// Returns a [`MouseWheelEvent`] of the winit `delta` in lines.
+    pub(crate) fn convert_mouse_wheel(
+        delta: winit_event::MouseScrollDelta,
+        scale_factor: f64,
+    ) -> MouseWheelEvent {
+        let (x, y) = match delta {
+            | winit_event::MouseScrollDelta::LineDelta(x, y) => (x, y),
+            | winit_event::MouseScrollDelta::PixelDelta(position) => {
+                let position = position.to_logical::<f64>(scale_factor);
+                (
+                    (position.x / PIXELS_PER_LINE) as f32,
+                    (position.y / PIXELS_PER_LINE) as f32,
+                )
+            },
+        };
+        MouseWheelEvent::builder()
+            .with_scroll_x(x)
+            .with_scroll_y(y)
+            .build()
+            .expect("mouse wheel event has all required fields")
+    }
+
+    /// Returns a [`TouchInput`] of the winit `touch` in logical pixels.
+    pub(crate) fn convert_touch(touch: winit_event::Touch, scale_factor: f64) -> TouchInput {
+        let position = touch.location.to_logical::<f32>(scale_factor);
+        let mut builder = TouchInput::builder();
+        builder
+            .with_id(touch.id)
+            .with_phase(match touch.phase {
+                | winit_event::TouchPhase::Started => TouchPhase::Started,
+                | winit_event::TouchPhase::Moved => TouchPhase::Moved,
+                | winit_event::TouchPhase::Ended => TouchPhase::Ended,
+                | winit_event::TouchPhase::Cancelled => TouchPhase::Cancelled,
+            })
+            .with_position((position.x, position.y));
+        if let Some(force) = touch.force {
+            builder.with_force(force.normalized() as f32);
+        }
+        builder
+            .build()
+            .expect("touch input has all required fields")
+    }
+
+    /// Returns an [`Ime`] of the winit `ime`.
+    pub(crate) fn convert_ime(ime: winit_event::Ime) -> Ime {
+        match ime {
+            | winit_event::Ime::Enabled => Ime::Enabled,
+            | winit_event::Ime::Preedit(value, cursor) => Ime::Preedit { value, cursor },
+            | winit_event::Ime::Commit(value) => Ime::Commit { value },
+            | winit_event::Ime::Disabled => Ime::Disabled,
+        }
+    }
+
+    macro_rules! convert_key_code {
+        ($key_code: expr, $($winit: ident => $qinetic: ident),* ; $($same: ident),* $(,)?) => {
+            match $key_code {
+                $(| winit_event::VirtualKeyCode::$winit => KeyCode::$qinetic,)*
+                $(| winit_event::VirtualKeyCode::$same => KeyCode::$same,)*
+            }
+        };
+    }
+
+    /// Returns a [`KeyCode`] of the winit `key_code`.
+    pub(crate) fn convert_key_code(key_code: winit_event::VirtualKeyCode) -> KeyCode {
+        convert_key_code!(
+            key_code,
+            OEM102 => Oem102;
+            Key1, Key2, Key3, Key4, Key5, Key6, Key7, Key8, Key9, Key0, A, B, C, D, E, F, G, H, I,
+            J, K, L, M, N, O, P, Q, R, S, T, U, V, W, X, Y, Z, Escape, F1, F2, F3, F4, F5, F6, F7,
+            F8, F9, F10, F11, F12, F13, F14, F15, F16, F17, F18, F19, F20, F21, F22, F23, F24,
+            Snapshot, Scroll, Pause, Insert, Home, Delete, End, PageDown, PageUp, Left, Up, Right,
+            Down, Back, Return, Space, Compose, Caret, Numlock, Numpad0, Numpad1, Numpad2, Numpad3,
+            Numpad4, Numpad5, Numpad6, Numpad7, Numpad8, Numpad9, NumpadAdd, NumpadDivide,
+            NumpadDecimal, NumpadComma, NumpadEnter, NumpadEquals, NumpadMultiply, NumpadSubtract,
+            AbntC1, AbntC2, Apostrophe, Apps, Asterisk, At, Ax, Backslash, Calculator, Capital,
+            Colon, Comma, Convert, Equals, Grave, Kana, Kanji, LAlt, LBracket, LControl, LShift,
+            LWin, Mail, MediaSelect, MediaStop, Minus, Mute, MyComputer, NavigateForward,
+            NavigateBackward, NextTrack, NoConvert, Period, PlayPause, Plus, Power, PrevTrack,
+            RAlt, RBracket, RControl, RShift, RWin, Semicolon, Slash, Sleep, Stop, Sysrq, Tab,
+            Underline, Unlabeled, VolumeDown, VolumeUp, Wake, WebBack, WebFavorites, WebForward,
+            WebHome, WebRefresh, WebSearch, WebStop, Yen, Copy, Paste, Cut,
+        )
+    }
+}
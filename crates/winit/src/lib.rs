@@ -5,7 +5,7 @@
 //! # Examples
 //!
 //! Here is a simple winit application:
-//! ```
+//! ```no_run
 //! use qinetic_app::prelude::*;
 //! use qinetic_winit::prelude::*;
 //!
@@ -23,6 +23,9 @@
     html_favicon_url = "https://raw.githubusercontent.com/vl-mr-freeman/qinetic/master/assets/qinetic_icon.svg"
 )]
 
+mod converters;
+mod windows;
+
 pub mod plugins;
 pub mod runners;
 pub mod stages;
@@ -33,7 +36,7 @@ pub mod prelude {
 
     pub use crate::{
         plugins::WinitPlugin,
-        runners::WinitRunner,
+        runners::{WinitBackend, WinitRunner},
         stages::{WinitStage, WinitStageGroup},
         systems::WinitSystem,
     };
@@ -1,9 +1,50 @@
 //! Winit [`Runner`]s functionality.
 
+use std::collections::VecDeque;
+
 use qinetic_app::prelude::*;
+use qinetic_ecs::prelude::*;
 use qinetic_utils::prelude::*;
+use qinetic_window::prelude::*;
+use winit::{
+    event::{Event as WinitEvent, StartCause, WindowEvent as WinitWindowEvent},
+    event_loop::{ControlFlow, EventLoop, EventLoopBuilder},
+};
+
+use crate::{
+    converters::convert_theme,
+    windows::{available_monitors, WinitWindows},
+};
+
+/// Windowing backend of the [`WinitRunner`] on Linux and BSD.
+///
+/// A backend is forced, only if it's feature is enabled, otherwise it's chosen automatically.
+#[derive(SmartDefault, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WinitBackend {
+    /// Use `Wayland`, if it's available, otherwise `X11`.
+    #[default]
+    Automatic,
+
+    /// Force `X11`, like under `Xvfb` in CI.
+    X11,
 
-/// Winit [`Runner`].
+    /// Force `Wayland`, like under a headless compositor in CI.
+    Wayland,
+}
+
+/// Winit [`Runner`], that owns the event loop.
+///
+/// Each iteration of the loop:
+/// * translates winit events into [`WindowEvent`], [`CursorEvent`] and input [`Event`]s,
+/// * calls [`App::update`], when all events are received,
+/// * creates, updates and closes native windows by [`Window`] entities of the [`Windows`] resource,
+///   and sends [`WindowEvent::Failed`] in the next update, if it fails,
+/// * updates the [`Monitors`] resource, when the loop starts, and when monitors are changed.
+///
+/// The loop exits, when all windows are closed.
+///
+/// All [`Event`]s received since the last update are sent in order of receiving, and motion is summed up.
+/// They're cleared at the end of the update, so each [`Event`] is read in one update only.
 ///
 /// # Examples
 /// ```
@@ -11,14 +52,302 @@ use qinetic_utils::prelude::*;
 /// # use qinetic_winit::prelude::*;
 /// #
 /// App::builder()
-///     .with_runner(WinitRunner::default())
+///     .with_runner(
+///         WinitRunner::default()
+///             .with_backend(WinitBackend::X11)
+///             .with_any_thread(true),
+///     )
 ///     .build()
 ///     .unwrap();
 /// ```
 #[derive(SmartDefault, Clone, Debug)]
-pub struct WinitRunner {}
+pub struct WinitRunner {
+    backend: WinitBackend,
+    any_thread: bool,
+}
+
+impl WinitRunner {
+    /// Returns a [`WinitRunner`] with `backend`.
+    #[inline]
+    pub fn with_backend(mut self, backend: WinitBackend) -> Self {
+        self.backend = backend;
+        self
+    }
+
+    /// Returns a [`WinitRunner`], that can run off the main thread, like in tests.
+    #[inline]
+    pub fn with_any_thread(mut self, any_thread: bool) -> Self {
+        self.any_thread = any_thread;
+        self
+    }
+
+    #[allow(unused_mut)]
+    fn event_loop(&self) -> EventLoop<()> {
+        let mut builder = EventLoopBuilder::new();
+
+        #[cfg(any(
+            target_os = "linux",
+            target_os = "dragonfly",
+            target_os = "freebsd",
+            target_os = "netbsd",
+            target_os = "openbsd"
+        ))]
+        {
+            use winit::platform::unix::EventLoopBuilderExtUnix;
+
+            builder.with_any_thread(self.any_thread);
+            match self.backend {
+                #[cfg(feature = "x11")]
+                | WinitBackend::X11 => {
+                    builder.with_x11();
+                },
+                #[cfg(feature = "wayland")]
+                | WinitBackend::Wayland => {
+                    builder.with_wayland();
+                },
+                | _ => {},
+            }
+        }
+
+        #[cfg(target_os = "windows")]
+        {
+            use winit::platform::windows::EventLoopBuilderExtWindows;
+
+            builder.with_any_thread(self.any_thread);
+        }
+
+        builder.build()
+    }
+}
 
 impl Runner for WinitRunner {
-    #[allow(unused_variables)]
-    fn run(&mut self, app: App) {}
+    fn run(&mut self, mut app: App) {
+        let event_loop = self.event_loop();
+        let mut state = WinitState::default();
+
+        event_loop.run(move |event, target, control_flow| {
+            *control_flow = ControlFlow::Poll;
+            match event {
+                | WinitEvent::WindowEvent { window_id, event } => {
+                    if let Some(window) = state.windows.id(window_id) {
+                        state.window_event(app.world(), window, event);
+                    }
+                },
+                #[cfg(feature = "input")]
+                | WinitEvent::DeviceEvent {
+                    event: winit::event::DeviceEvent::MouseMotion { delta },
+                    ..
+                } => {
+                    state.raw_motion.0 += delta.0 as f32;
+                    state.raw_motion.1 += delta.1 as f32;
+                },
+                | WinitEvent::NewEvents(StartCause::Init) => state.monitors_changed = true,
+                | WinitEvent::MainEventsCleared => {
+                    if std::mem::take(&mut state.monitors_changed) {
+                        if let Some(mut monitors) = app.world().get_resource_mut::<Monitors>() {
+                            *monitors = available_monitors(target);
+                        }
+                    }
+                    state.flush(app.world_mut());
+                    app.update();
+                    for event in state.windows.sync(app.world(), target) {
+                        state.queue.push(event);
+                    }
+                    if state.windows.all_closed() {
+                        *control_flow = ControlFlow::Exit;
+                    }
+                },
+                | _ => {},
+            }
+        });
+    }
+}
+
+/// Sends a queued [`Event`] to the [`World`].
+type SendEvent = Box<dyn FnOnce(&mut World)>;
+
+/// Queue of [`Event`]s, that are sent before the next update in order of receiving.
+#[derive(Default, Derivative)]
+#[derivative(Debug)]
+struct EventQueue {
+    #[derivative(Debug = "ignore")]
+    events: VecDeque<SendEvent>,
+}
+
+impl EventQueue {
+    fn push<T: Event>(&mut self, event: T) {
+        self.push_with(move |world| {
+            world.add_event(event);
+        });
+    }
+
+    /// Queues the `send` of the [`Event`], that can update resources along with it.
+    fn push_with(&mut self, send: impl FnOnce(&mut World) + 'static) {
+        self.events.push_back(Box::new(send));
+    }
+
+    fn flush(&mut self, world: &mut World) {
+        for send in self.events.drain(..) {
+            send(world);
+        }
+    }
+}
+
+#[derive(Default)]
+struct WinitState {
+    windows: WinitWindows,
+    queue: EventQueue,
+    cursor: Option<(WindowId, (f32, f32))>,
+    cursor_moved: bool,
+    cursor_delta: (f32, f32),
+    monitors_changed: bool,
+    #[allow(dead_code)]
+    raw_motion: (f32, f32),
+}
+
+impl WinitState {
+    fn window_event(&mut self, world: &World, window: WindowId, event: WinitWindowEvent) {
+        let scale_factor = self
+            .windows
+            .get(window)
+            .map_or(1.0, |native| native.scale_factor());
+        match event {
+            | WinitWindowEvent::Resized(size) => {
+                let size = size.to_logical::<u32>(scale_factor);
+                self.windows.update_component(world, window, |component| {
+                    component.set_size(size.width, size.height)
+                });
+                self.queue.push(WindowEvent::Resized {
+                    window,
+                    width: size.width,
+                    height: size.height,
+                });
+            },
+            | WinitWindowEvent::Moved(position) => {
+                self.windows.update_component(world, window, |component| {
+                    component.set_position(WindowPosition::At {
+                        x: position.x,
+                        y: position.y,
+                    })
+                });
+                self.queue.push(WindowEvent::Moved {
+                    window,
+                    x: position.x,
+                    y: position.y,
+                });
+            },
+            | WinitWindowEvent::Focused(focused) => {
+                self.queue.push(WindowEvent::Focused { window, focused })
+            },
+            | WinitWindowEvent::CloseRequested => {
+                self.queue.push_with(move |world| {
+                    if let Some(mut windows) = world.get_resource_mut::<Windows>() {
+                        windows.request_close(window);
+                    }
+                    world.add_event(WindowEvent::CloseRequested { window });
+                })
+            },
+            | WinitWindowEvent::ScaleFactorChanged { scale_factor, .. } => {
+                self.monitors_changed = true;
+                self.queue.push(WindowEvent::ScaleFactorChanged {
+                    window,
+                    scale_factor,
+                })
+            },
+            | WinitWindowEvent::Occluded(occluded) => {
+                self.queue.push(WindowEvent::Occluded { window, occluded })
+            },
+            | WinitWindowEvent::ThemeChanged(theme) => {
+                self.queue.push(WindowEvent::ThemeChanged {
+                    window,
+                    theme: convert_theme(theme),
+                })
+            },
+            | WinitWindowEvent::DroppedFile(path) => {
+                self.queue.push(WindowEvent::FileDropped { window, path })
+            },
+            | WinitWindowEvent::HoveredFile(path) => {
+                self.queue.push(WindowEvent::FileHovered { window, path })
+            },
+            | WinitWindowEvent::HoveredFileCancelled => {
+                self.queue.push(WindowEvent::FileHoverCancelled { window })
+            },
+            | WinitWindowEvent::CursorMoved { position, .. } => {
+                let position = position.to_logical::<f32>(scale_factor);
+                let position = (position.x, position.y);
+                if let Some((last_window, last)) = self.cursor {
+                    if last_window == window {
+                        self.cursor_delta.0 += position.0 - last.0;
+                        self.cursor_delta.1 += position.1 - last.1;
+                    }
+                }
+                self.cursor = Some((window, position));
+                self.cursor_moved = true;
+            },
+            | WinitWindowEvent::CursorEntered { .. } => {
+                self.queue.push(CursorEvent::Entered { window })
+            },
+            | WinitWindowEvent::CursorLeft { .. } => self.queue.push(CursorEvent::Left { window }),
+            #[cfg(feature = "input")]
+            | event => self.input_event(event, scale_factor),
+            #[cfg(not(feature = "input"))]
+            | _ => {},
+        }
+    }
+
+    #[cfg(feature = "input")]
+    fn input_event(&mut self, event: WinitWindowEvent, scale_factor: f64) {
+        use qinetic_input::prelude::*;
+
+        use crate::converters::*;
+
+        match event {
+            | WinitWindowEvent::KeyboardInput { input, .. } => {
+                self.queue.push(convert_keyboard_input(input))
+            },
+            | WinitWindowEvent::ReceivedCharacter(character) => {
+                self.queue.push(ReceivedCharacter::new(character))
+            },
+            | WinitWindowEvent::Ime(ime) => self.queue.push(convert_ime(ime)),
+            | WinitWindowEvent::MouseInput { button, state, .. } => {
+                self.queue.push(convert_mouse_input(button, state))
+            },
+            | WinitWindowEvent::MouseWheel { delta, .. } => {
+                self.queue.push(convert_mouse_wheel(delta, scale_factor))
+            },
+            | WinitWindowEvent::Touch(touch) => self.queue.push(convert_touch(touch, scale_factor)),
+            | _ => {},
+        }
+    }
+
+    /// Queues the summed up motion, and sends queued [`Event`]s.
+    fn flush(&mut self, world: &mut World) {
+        if let (true, Some((window, position))) = (self.cursor_moved, self.cursor) {
+            self.queue.push(CursorEvent::Moved { window });
+
+            #[cfg(feature = "input")]
+            self.queue.push(
+                qinetic_input::prelude::MouseMotionEvent::builder()
+                    .with_position(position)
+                    .with_delta(self.cursor_delta)
+                    .build()
+                    .expect("mouse motion event has all required fields"),
+            );
+            #[cfg(not(feature = "input"))]
+            let _ = position;
+        }
+        self.cursor_moved = false;
+        self.cursor_delta = (0.0, 0.0);
+
+        #[cfg(feature = "input")]
+        if self.raw_motion != (0.0, 0.0) {
+            self.queue
+                .push(qinetic_input::prelude::RawMouseMotionEvent::new(
+                    self.raw_motion,
+                ));
+            self.raw_motion = (0.0, 0.0);
+        }
+
+        self.queue.flush(world);
+    }
 }
@@ -0,0 +1,309 @@
+//! Winit windows functionality.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+
+use qinetic_ecs::prelude::*;
+use qinetic_window::prelude::*;
+use winit::{
+    dpi::{LogicalSize, PhysicalPosition},
+    event_loop::EventLoopWindowTarget,
+    monitor::MonitorHandle,
+    window as winit_window,
+};
+
+use crate::converters::{
+    convert_cursor_grab_mode,
+    convert_cursor_icon,
+    convert_monitor,
+    convert_video_mode,
+};
+
+/// Native winit windows of [`Window`] entities, that are synced with their components.
+#[derive(Default)]
+pub(crate) struct WinitWindows {
+    windows: BTreeMap<WindowId, (winit_window::Window, Window)>,
+    ids: HashMap<winit_window::WindowId, WindowId>,
+    failed: BTreeSet<WindowId>,
+    created: bool,
+}
+
+impl WinitWindows {
+    /// Returns a [`WindowId`] of the native window by winit `id`, if it's present.
+    #[inline]
+    pub(crate) fn id(&self, id: winit_window::WindowId) -> Option<WindowId> {
+        self.ids.get(&id).copied()
+    }
+
+    /// Returns a native window by `id`, if it's present.
+    #[inline]
+    pub(crate) fn get(&self, id: WindowId) -> Option<&winit_window::Window> {
+        self.windows.get(&id).map(|(window, _)| window)
+    }
+
+    /// Returns `true`, if any native window was created, and all of them are closed.
+    #[inline]
+    pub(crate) fn all_closed(&self) -> bool { self.created && self.windows.is_empty() }
+
+    /// Updates the [`Window`] component by `id` by the `update` of the native window,
+    /// without syncing it back.
+    pub(crate) fn update_component(
+        &mut self,
+        world: &World,
+        id: WindowId,
+        update: impl Fn(&mut Window),
+    ) {
+        let entity_id = world
+            .get_resource::<Windows>()
+            .and_then(|windows| windows.entity(id));
+        if let (Some((_, cached)), Some(entity_id)) = (self.windows.get_mut(&id), entity_id) {
+            update(cached);
+            if let Some(mut window) = world.get_component_mut::<Window>(entity_id) {
+                update(&mut window);
+            }
+        }
+    }
+
+    /// Creates, updates and closes native windows by [`Window`] entities of the [`Windows`] resource.
+    ///
+    /// Returns [`WindowEvent::Failed`]s of windows, that can't be created, or use unsupported features.
+    pub(crate) fn sync<T>(
+        &mut self,
+        world: &World,
+        target: &EventLoopWindowTarget<T>,
+    ) -> Vec<WindowEvent> {
+        let mut failures = Vec::new();
+        let Some(windows) = world.get_resource::<Windows>() else {
+            return failures;
+        };
+
+        let closed = self
+            .windows
+            .keys()
+            .filter(|id| !windows.contains(**id))
+            .copied()
+            .collect::<Vec<_>>();
+        for id in closed {
+            if let Some((native, _)) = self.windows.remove(&id) {
+                self.ids.remove(&native.id());
+            }
+        }
+
+        for (id, entity_id) in windows.iter() {
+            let Some(window) = world
+                .get_component::<Window>(entity_id)
+                .map(|window| window.clone())
+            else {
+                continue;
+            };
+            match self.windows.get_mut(&id) {
+                | Some((native, cached)) => {
+                    if *cached != window {
+                        if cached.cursor().icon() != window.cursor().icon() {
+                            check_cursor(id, &window, &mut failures);
+                        }
+                        apply(native, cached, &window);
+                        *cached = window;
+                    }
+                },
+                | None if !self.failed.contains(&id) => {
+                    match create(target, &window) {
+                        | Ok(native) => {
+                            check_cursor(id, &window, &mut failures);
+                            self.ids.insert(native.id(), id);
+                            self.windows.insert(id, (native, window));
+                            self.created = true;
+                        },
+                        | Err(error) => {
+                            failures.push(WindowEvent::Failed {
+                                window: id,
+                                error: WindowError::CreationFailed {
+                                    reason: error.to_string(),
+                                },
+                            });
+                            self.failed.insert(id);
+                        },
+                    }
+                },
+                | None => {},
+            }
+        }
+        failures
+    }
+}
+
+/// Adds [`WindowError::UnsupportedCursor`] to `failures`, if the [`Window`] uses a custom cursor icon.
+fn check_cursor(id: WindowId, window: &Window, failures: &mut Vec<WindowEvent>) {
+    if let CursorIcon::Custom(_) = window.cursor().icon() {
+        failures.push(WindowEvent::Failed {
+            window: id,
+            error: WindowError::UnsupportedCursor,
+        });
+    }
+}
+
+fn monitor<T>(target: &EventLoopWindowTarget<T>, monitor: WindowMonitor) -> Option<MonitorHandle> {
+    match monitor {
+        | WindowMonitor::Current | WindowMonitor::Primary => {
+            target
+                .primary_monitor()
+                .or_else(|| target.available_monitors().next())
+        },
+        | WindowMonitor::Index(index) => target.available_monitors().nth(index),
+    }
+}
+
+fn fullscreen(
+    mode: WindowMode,
+    monitor: Option<MonitorHandle>,
+) -> Option<winit_window::Fullscreen> {
+    match (mode, monitor) {
+        | (WindowMode::Fullscreen, monitor) => Some(winit_window::Fullscreen::Borderless(monitor)),
+        | (WindowMode::ExclusiveFullscreen(selection), Some(monitor)) => {
+            let video_mode = convert_monitor(&monitor, false).video_mode(selection);
+            monitor
+                .video_modes()
+                .find(|mode| Some(convert_video_mode(mode)) == video_mode)
+                .map(winit_window::Fullscreen::Exclusive)
+                .or(Some(winit_window::Fullscreen::Borderless(Some(monitor))))
+        },
+        | (WindowMode::ExclusiveFullscreen(_), None) => {
+            Some(winit_window::Fullscreen::Borderless(None))
+        },
+        | _ => None,
+    }
+}
+
+/// Returns [`Monitors`] of the `target` in the order of [`WindowMonitor::Index`].
+pub(crate) fn available_monitors<T>(target: &EventLoopWindowTarget<T>) -> Monitors {
+    let primary = target.primary_monitor();
+    Monitors::new(
+        target
+            .available_monitors()
+            .map(|monitor| {
+                let is_primary = primary.as_ref() == Some(&monitor);
+                convert_monitor(&monitor, is_primary)
+            })
+            .collect(),
+    )
+}
+
+fn size_constraints(
+    constraints: &WindowSizeConstraints,
+) -> (Option<LogicalSize<u32>>, Option<LogicalSize<u32>>) {
+    let min =
+        (constraints.min_width().is_some() || constraints.min_height().is_some()).then(|| {
+            LogicalSize::new(
+                constraints.min_width().unwrap_or(0),
+                constraints.min_height().unwrap_or(0),
+            )
+        });
+    let max =
+        (constraints.max_width().is_some() || constraints.max_height().is_some()).then(|| {
+            LogicalSize::new(
+                constraints.max_width().unwrap_or(u32::MAX),
+                constraints.max_height().unwrap_or(u32::MAX),
+            )
+        });
+    (min, max)
+}
+
+fn create<T>(
+    target: &EventLoopWindowTarget<T>,
+    window: &Window,
+) -> Result<winit_window::Window, winit::error::OsError> {
+    let monitor = monitor(target, *window.monitor());
+    let (min_size, max_size) = size_constraints(window.size_constraints());
+
+    let mut builder = winit_window::WindowBuilder::new()
+        .with_title(window.title())
+        .with_inner_size(LogicalSize::new(*window.width(), *window.height()))
+        .with_resizable(*window.resizable())
+        .with_decorations(*window.decorations())
+        .with_maximized(*window.mode() == WindowMode::Maximized)
+        .with_fullscreen(fullscreen(*window.mode(), monitor.clone()));
+    if let Some(min_size) = min_size {
+        builder = builder.with_min_inner_size(min_size);
+    }
+    if let Some(max_size) = max_size {
+        builder = builder.with_max_inner_size(max_size);
+    }
+    if let WindowPosition::At { x, y } = *window.position() {
+        builder = builder.with_position(PhysicalPosition::new(x, y));
+    }
+
+    let native = builder.build(target)?;
+    if let (WindowPosition::Center, Some(monitor)) = (*window.position(), &monitor) {
+        let (area, size) = (monitor.size(), native.outer_size());
+        native.set_outer_position(PhysicalPosition::new(
+            monitor.position().x + (area.width as i32 - size.width as i32) / 2,
+            monitor.position().y + (area.height as i32 - size.height as i32) / 2,
+        ));
+    }
+    if *window.mode() == WindowMode::Minimized {
+        native.set_minimized(true);
+    }
+    apply_cursor(&native, window.cursor());
+    native.set_ime_allowed(*window.ime_enabled());
+    if *window.ime_enabled() {
+        let (x, y) = *window.ime_position();
+        native.set_ime_position(PhysicalPosition::new(x, y));
+    }
+    Ok(native)
+}
+
+fn apply(native: &winit_window::Window, cached: &Window, window: &Window) {
+    if cached.title() != window.title() {
+        native.set_title(window.title());
+    }
+    if (cached.width(), cached.height()) != (window.width(), window.height()) {
+        native.set_inner_size(LogicalSize::new(*window.width(), *window.height()));
+    }
+    if cached.size_constraints() != window.size_constraints() {
+        let (min_size, max_size) = size_constraints(window.size_constraints());
+        native.set_min_inner_size(min_size);
+        native.set_max_inner_size(max_size);
+    }
+    if cached.position() != window.position() {
+        if let WindowPosition::At { x, y } = *window.position() {
+            native.set_outer_position(PhysicalPosition::new(x, y));
+        }
+    }
+    if cached.resizable() != window.resizable() {
+        native.set_resizable(*window.resizable());
+    }
+    if cached.decorations() != window.decorations() {
+        native.set_decorations(*window.decorations());
+    }
+    if cached.mode() != window.mode() || cached.monitor() != window.monitor() {
+        let monitor = match window.monitor() {
+            | WindowMonitor::Current => native.current_monitor(),
+            | WindowMonitor::Primary => native.primary_monitor(),
+            | WindowMonitor::Index(index) => native.available_monitors().nth(*index),
+        };
+        native.set_fullscreen(fullscreen(*window.mode(), monitor));
+        native.set_maximized(*window.mode() == WindowMode::Maximized);
+        native.set_minimized(*window.mode() == WindowMode::Minimized);
+    }
+    if cached.cursor() != window.cursor() {
+        apply_cursor(native, window.cursor());
+    }
+    if cached.ime_enabled() != window.ime_enabled() {
+        native.set_ime_allowed(*window.ime_enabled());
+    }
+    if *window.ime_enabled() && cached.ime_position() != window.ime_position() {
+        let (x, y) = *window.ime_position();
+        native.set_ime_position(PhysicalPosition::new(x, y));
+    }
+}
+
+fn apply_cursor(native: &winit_window::Window, cursor: &Cursor) {
+    native.set_cursor_visible(*cursor.visible());
+    native.set_cursor_icon(convert_cursor_icon(cursor.icon()));
+    let grab_mode = convert_cursor_grab_mode(*cursor.grab_mode());
+    if native.set_cursor_grab(grab_mode).is_err() && *cursor.grab_mode() == CursorGrabMode::Locked {
+        // X11 and Windows can't lock the cursor, so it's confined, and raw motion is still reported.
+        native
+            .set_cursor_grab(winit_window::CursorGrabMode::Confined)
+            .ok();
+    }
+}
@@ -10,8 +10,8 @@
 //!
 //! fn main() {
 //!     App::builder()
-//!         .with_runner(RunOnce::default())
 //!         .with_plugin_group(DefaultPluginGroup::default())
+//!         .with_runner(RunOnce::default())
 //!         .build()
 //!         .unwrap()
 //!         .run();